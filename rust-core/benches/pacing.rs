@@ -0,0 +1,62 @@
+//! Pacing hot-path benchmarks
+//! 
+//! Run with `cargo bench`; each stage is measured on the 100-, 1k-, and
+//! 10k-word scripts from `bench_corpus()`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use zenpal_core::corpus::bench_corpus;
+use zenpal_core::MeditationPacer;
+
+fn atomization(c: &mut Criterion) {
+    let pacer = MeditationPacer::new();
+    let mut group = c.benchmark_group("atomize");
+    for script in bench_corpus() {
+        group.throughput(Throughput::Bytes(script.text.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(script.name), &script.text, |b, text| {
+            b.iter(|| pacer.atomize(black_box(text)))
+        });
+    }
+    group.finish();
+}
+
+fn distribution(c: &mut Criterion) {
+    let pacer = MeditationPacer::new();
+    let mut group = c.benchmark_group("distribute");
+    for script in bench_corpus() {
+        let atoms = pacer.atomize(&script.text);
+        group.throughput(Throughput::Elements(atoms.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(script.name), &atoms, |b, atoms| {
+            b.iter(|| pacer.distribute_pauses(black_box(atoms), script.target_duration_seconds))
+        });
+    }
+    group.finish();
+}
+
+fn rendering(c: &mut Criterion) {
+    let pacer = MeditationPacer::new();
+    let mut group = c.benchmark_group("render");
+    for script in bench_corpus() {
+        let atoms = pacer.atomize(&script.text);
+        let pauses = pacer.distribute_pauses(&atoms, script.target_duration_seconds);
+        group.throughput(Throughput::Elements(atoms.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(script.name), &atoms, |b, atoms| {
+            b.iter(|| pacer.render_atoms(black_box(atoms), &pauses))
+        });
+    }
+    group.finish();
+}
+
+fn end_to_end(c: &mut Criterion) {
+    let pacer = MeditationPacer::new();
+    let mut group = c.benchmark_group("calculate_pacing");
+    for script in bench_corpus() {
+        group.throughput(Throughput::Bytes(script.text.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(script.name), &script.text, |b, text| {
+            b.iter(|| pacer.calculate_pacing(black_box(text.clone()), script.target_duration_seconds))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, atomization, distribution, rendering, end_to_end);
+criterion_main!(benches);
@@ -0,0 +1,17 @@
+//! ZenPal Core - Native Libraries
+//! 
+//! Builds `zenpal-core` as a staticlib and cdylib. The core manifest only
+//! builds the Rust library so `no_std` firmware can depend on it; the
+//! `extern "C"`, UniFFI, wasm-bindgen, and PyO3 exports all live in the
+//! core and are linked into these outputs from there. Features select the
+//! export layers:
+//! 
+//! ```text
+//! cargo build --release -p zenpal-core-bindings                      # C: libzenpal_core.a/.so
+//! cargo build --release -p zenpal-core-bindings --features serde     # + zenpal_calculate_pacing_json
+//! cargo build --release -p zenpal-core-bindings --features uniffi    # Swift/Kotlin
+//! cargo build --release -p zenpal-core-bindings --features wasm --target wasm32-unknown-unknown
+//! maturin build --release --features python                          # from this directory
+//! ```
+
+pub use engine::*;
@@ -0,0 +1,42 @@
+/*
+ * ZenPal Core - C interface
+ *
+ * Strings are null-terminated UTF-8. Every char * returned by a zenpal_*
+ * function is owned by the caller and must be released with
+ * zenpal_free_string (never free). Functions return NULL when given a
+ * NULL or non-UTF-8 argument.
+ *
+ * Link against libzenpal_core.a or libzenpal_core.so from the bindings
+ * crate (cargo build --release -p zenpal-core-bindings).
+ */
+
+#ifndef ZENPAL_CORE_H
+#define ZENPAL_CORE_H
+
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Format meditation text into SSML with calculated breaks */
+char *zenpal_format_ssml(const char *text, double target_duration_seconds);
+
+/* Full pacing result as JSON; NULL if the library was built without the
+ * `serde` feature */
+char *zenpal_calculate_pacing_json(const char *text, double target_duration_seconds);
+
+/* Target word count for an LLM prompt (saturates at UINT32_MAX) */
+uint32_t zenpal_target_words(double target_duration_seconds);
+
+/* Library version (static; do not free) */
+const char *zenpal_version(void);
+
+/* Release a string returned by this library (NULL is ignored) */
+void zenpal_free_string(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ZENPAL_CORE_H */
@@ -0,0 +1,126 @@
+//! Acronym Expansion
+//! 
+//! Acronyms and app names in capitals ("RSVP", "HRV") are spelled out
+//! letter by letter, which takes far longer than their character count
+//! suggests. Speech estimation counts them as the letter names a voice
+//! reads ("ar ess vee pee"), alongside spoken numbers (see `numbers`).
+//! 
+//! A token is an acronym when it is two to five capital letters. Text with
+//! no lowercase letters at all is shouting or a heading, not acronyms, and
+//! is left alone. The script itself is never rewritten: voices already
+//! spell acronyms out.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::acronyms::expand_acronyms;
+//! 
+//! assert_eq!(expand_acronyms("Breathe in, RSVP style"), "Breathe in, ar ess vee pee style");
+//! assert_eq!(expand_acronyms("BREATHE IN"), "BREATHE IN");
+//! ```
+
+use alloc::borrow::Cow;
+
+use crate::prelude::*;
+
+/// Longest run of capitals read as letters rather than a shouted word
+const MAX_ACRONYM_LETTERS: usize = 5;
+
+/// English name of each letter, `A` to `Z`
+const LETTER_NAMES: [&str; 26] = [
+    "ay", "bee", "see", "dee", "ee", "ef", "jee", "aitch", "eye", "jay", "kay", "el", "em",
+    "en", "oh", "pee", "cue", "ar", "ess", "tee", "you", "vee", "double-you", "ex", "why", "zee",
+];
+
+// ============================================
+// Expansion
+// ============================================
+
+/// `text` with every acronym written out as letter names
+/// 
+/// Borrows `text` when it has no acronyms.
+pub fn expand_acronyms(text: &str) -> Cow<'_, str> {
+    if !text.contains(char::is_lowercase) {
+        return Cow::Borrowed(text);
+    }
+    spell_acronyms(text)
+}
+
+/// Whether a token is read letter by letter
+pub fn is_acronym(token: &str) -> bool {
+    (2..=MAX_ACRONYM_LETTERS).contains(&token.len()) && token.bytes().all(|b| b.is_ascii_uppercase())
+}
+
+/// `text` with every acronym written out, whether or not `text` itself has
+/// lowercase letters (for a word taken from a longer text)
+pub(crate) fn spell_acronyms(text: &str) -> Cow<'_, str> {
+    if !text.split(is_boundary).any(is_acronym) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut out = String::with_capacity(text.len() * 3);
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| !is_boundary(c)) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest.find(is_boundary).unwrap_or(rest.len());
+        let token = &rest[..end];
+        if is_acronym(token) {
+            let names: Vec<&str> = token.bytes().map(|b| LETTER_NAMES[usize::from(b - b'A')]).collect();
+            out.push_str(&names.join(" "));
+        } else {
+            out.push_str(token);
+        }
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Whether `c` separates tokens
+fn is_boundary(c: char) -> bool {
+    !c.is_alphanumeric()
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MeditationPacer;
+
+    #[test]
+    fn test_expand_acronyms() {
+        assert_eq!(expand_acronyms("Open the ZenPal app, check HRV."), "Open the ZenPal app, check aitch ar vee.");
+        assert_eq!(expand_acronyms("I am calm. OK, WWW"), "I am calm. oh kay, double-you double-you double-you");
+        assert_eq!(expand_acronyms("Let go of STRESS"), "Let go of STRESS");
+        assert!(matches!(expand_acronyms("RELAX NOW"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_acronyms_are_estimated_letter_by_letter() {
+        let pacer = MeditationPacer::new();
+        let acronym = pacer.calculate_pacing("Breathe in through your nose, RSVP style.".to_string(), 30.0);
+        let spelled = pacer.calculate_pacing("Breathe in through your nose, ar ess vee pee style.".to_string(), 30.0);
+        assert_eq!(acronym.spoken_chars, spelled.spoken_chars);
+        assert_eq!(acronym.estimated_speech_seconds, spelled.estimated_speech_seconds);
+        assert!(acronym.ssml.contains("RSVP style."));
+    }
+
+    #[test]
+    fn test_is_acronym() {
+        assert!(is_acronym("OK"));
+        assert!(is_acronym("RSVP"));
+        assert!(!is_acronym("I"));
+        assert!(!is_acronym("STRESS"));
+        assert!(!is_acronym("B12"));
+        assert!(!is_acronym("Ok"));
+        assert!(!is_acronym("ÉÉ"));
+    }
+}
@@ -0,0 +1,265 @@
+//! Affirmation Loops
+//! 
+//! Repeats a small set of affirmations to fill a target duration, with
+//! pauses that grow as the session goes on so the listener sinks deeper
+//! between repetitions. Output is a `PacingResult` from the same renderer
+//! as text-based meditations.
+//! 
+//! ## Repetitions and Pauses
+//! 
+//! The loop repeats as many times as fit with the first pause at least
+//! `min_pause_seconds`. Pause `i` is proportional to `growth^i`, scaled so
+//! the session lands on the target duration; nothing follows the last
+//! affirmation.
+//! 
+//! ## Ordering
+//! 
+//! Affirmations can be spoken in the same order every pass, rotated by
+//! one each pass, or reshuffled each pass from a seed. Rotated and shuffled
+//! passes never start with the affirmation that ended the previous pass,
+//! so no affirmation is heard twice in a row.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::affirmations::{AffirmationLoop, AffirmationOrder};
+//! use zenpal_core::MeditationPacer;
+//! 
+//! let session = AffirmationLoop::new(&["I am calm", "I am safe", "I am enough"], 120.0)
+//!     .with_order(AffirmationOrder::Shuffled(7));
+//! let result = session.generate(&MeditationPacer::new());
+//! assert!(result.atom_count > 3);
+//! assert!((result.estimated_total_seconds - 120.0).abs() < 1.0);
+//! ```
+
+use crate::pacing_engine::{splitmix64, MeditationPacer, PacingResult, PunctuationType, SpeechAtom};
+use crate::prelude::*;
+
+/// Pause growth per repetition
+const DEFAULT_GROWTH: f64 = 1.1;
+
+/// Shortest first pause in seconds
+const DEFAULT_MIN_PAUSE_SECONDS: f64 = 3.0;
+
+/// Upper bound on repetitions, for very long sessions
+const MAX_REPETITIONS: usize = 1000;
+
+// ============================================
+// Types
+// ============================================
+
+/// Order of affirmations within each pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AffirmationOrder {
+    /// The given order every pass
+    Sequential,
+    /// Each pass starts one affirmation later
+    Rotated,
+    /// A fresh shuffle every pass, from this seed
+    Shuffled(u64),
+}
+
+/// A set of affirmations repeated to fill a session
+#[derive(Debug, Clone, PartialEq)]
+pub struct AffirmationLoop {
+    /// Affirmations to repeat
+    pub affirmations: Vec<String>,
+    /// Target session length in seconds
+    pub target_duration_seconds: f64,
+    /// Pause growth factor per repetition (1.0 = even pauses)
+    pub growth: f64,
+    /// Shortest first pause in seconds
+    pub min_pause_seconds: f64,
+    /// Order within each pass
+    pub order: AffirmationOrder,
+}
+
+impl AffirmationLoop {
+    /// A loop with gently growing pauses in the given order
+    pub fn new(affirmations: &[&str], target_duration_seconds: f64) -> Self {
+        Self {
+            affirmations: affirmations.iter()
+                .map(|a| a.trim().to_string())
+                .filter(|a| !a.is_empty())
+                .collect(),
+            target_duration_seconds,
+            growth: DEFAULT_GROWTH,
+            min_pause_seconds: DEFAULT_MIN_PAUSE_SECONDS,
+            order: AffirmationOrder::Sequential,
+        }
+    }
+
+    /// Set the pause growth factor
+    pub fn with_growth(mut self, growth: f64) -> Self {
+        self.growth = growth;
+        self
+    }
+
+    /// Set the shortest first pause
+    pub fn with_min_pause(mut self, seconds: f64) -> Self {
+        self.min_pause_seconds = seconds;
+        self
+    }
+
+    /// Set the order within each pass
+    pub fn with_order(mut self, order: AffirmationOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Render the loop with the pacer's speech rate and break formatting
+    /// 
+    /// At least one full pass is spoken, even if it overruns the target.
+    pub fn generate(&self, pacer: &MeditationPacer) -> PacingResult {
+        let atoms: Vec<SpeechAtom> = self.affirmations.iter().map(|a| affirmation_atom(a)).collect();
+        let speech = pacer.speech_seconds(&atoms);
+        let growth = self.growth.max(1.0);
+
+        // Step A: As many repetitions as fit with the minimum first pause
+        let fits = |count: usize| {
+            let spoken: f64 = self.sequence(count).iter().map(|i| speech[*i]).sum();
+            let silence: f64 = pause_weights(count, growth).iter().sum::<f64>() * self.min_pause_seconds.max(0.0);
+            spoken + silence + pacer.framing_pauses() <= self.target_duration_seconds
+        };
+        let mut count = atoms.len();
+        while !atoms.is_empty() && count < MAX_REPETITIONS && fits(count + 1) {
+            count += 1;
+        }
+
+        // Step B: Share the remaining time out along the growth curve
+        let order = self.sequence(count);
+        let spoken: Vec<f64> = order.iter().map(|i| speech[*i]).collect();
+        let silence_budget = (self.target_duration_seconds - spoken.iter().sum::<f64>() - pacer.framing_pauses()).max(0.0);
+        let weights = pause_weights(count, growth);
+        let total_weight: f64 = weights.iter().sum();
+        let pauses: Vec<f64> = weights.iter()
+            .map(|w| if total_weight > 0.0 { silence_budget * w / total_weight } else { 0.0 })
+            .collect();
+
+        let repeated: Vec<SpeechAtom> = order.iter().map(|i| atoms[*i].clone()).collect();
+        pacer.assemble_result(
+            &repeated,
+            &pauses,
+            &spoken,
+            self.target_duration_seconds,
+            silence_budget,
+            silence_budget,
+        )
+    }
+
+    /// Affirmation indices for `count` repetitions
+    fn sequence(&self, count: usize) -> Vec<usize> {
+        let n = self.affirmations.len();
+        if n == 0 {
+            return Vec::new();
+        }
+        let mut out: Vec<usize> = Vec::with_capacity(count + n);
+        let mut pass_number = 0u64;
+
+        while out.len() < count {
+            let mut pass: Vec<usize> = (0..n).collect();
+            match self.order {
+                AffirmationOrder::Sequential => {}
+                AffirmationOrder::Rotated => pass.rotate_left(pass_number as usize % n),
+                AffirmationOrder::Shuffled(seed) => {
+                    for i in (1..n).rev() {
+                        let j = (splitmix64(seed ^ splitmix64(pass_number) ^ i as u64) % (i as u64 + 1)) as usize;
+                        pass.swap(i, j);
+                    }
+                }
+            }
+            if self.order != AffirmationOrder::Sequential && n > 1 && out.last() == pass.first() {
+                pass.swap(0, 1);
+            }
+            out.extend(pass);
+            pass_number += 1;
+        }
+
+        out.truncate(count);
+        out
+    }
+}
+
+// ============================================
+// Helper Functions
+// ============================================
+
+/// Relative pause lengths for `count` repetitions (none after the last)
+fn pause_weights(count: usize, growth: f64) -> Vec<f64> {
+    (0..count)
+        .map(|i| if i + 1 < count { growth.powi(i as i32) } else { 0.0 })
+        .collect()
+}
+
+/// An affirmation as a single speech atom, keeping `!` or `?`
+fn affirmation_atom(text: &str) -> SpeechAtom {
+    let body = text.trim_end_matches(['.', '!', '?', ' ']);
+    let punctuation_char = match text.trim_end().chars().last() {
+        Some(c @ ('!' | '?')) => c.to_string(),
+        _ => ".".to_string(),
+    };
+    SpeechAtom::new(body.to_string(), PunctuationType::SentenceEnd, punctuation_char)
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFFIRMATIONS: [&str; 3] = ["I am calm.", "I am safe!", "I am enough"];
+
+    #[test]
+    fn test_pauses_grow_and_fill_target() {
+        let pacer = MeditationPacer::new();
+        let result = AffirmationLoop::new(&AFFIRMATIONS, 180.0).with_growth(1.2).generate(&pacer);
+
+        assert!(result.atom_count > AFFIRMATIONS.len());
+        assert!((result.estimated_total_seconds - 180.0).abs() < 1.0);
+        let pauses: Vec<f64> = result.timeline.iter().map(|e| e.pause_seconds).collect();
+        let last = pauses.len() - 1;
+        assert!(pauses[..last].windows(2).all(|w| w[1] >= w[0] - 0.1), "{:?}", pauses);
+        assert!(pauses[0] >= DEFAULT_MIN_PAUSE_SECONDS - 0.1);
+        assert_eq!(pauses[last], 0.0);
+        assert!(result.ssml.contains("I am safe!"));
+    }
+
+    #[test]
+    fn test_ordering_never_repeats_back_to_back() {
+        for order in [AffirmationOrder::Rotated, AffirmationOrder::Shuffled(42)] {
+            for n in [2, 3] {
+                let session = AffirmationLoop::new(&AFFIRMATIONS[..n], 60.0).with_order(order);
+                let sequence = session.sequence(20);
+                assert_eq!(sequence.len(), 20);
+                assert!(sequence.windows(2).all(|w| w[0] != w[1]), "{:?}", sequence);
+            }
+        }
+
+        let rotated = AffirmationLoop::new(&AFFIRMATIONS, 60.0).with_order(AffirmationOrder::Rotated);
+        assert_eq!(rotated.sequence(6), vec![0, 1, 2, 1, 2, 0]);
+        let sequential = AffirmationLoop::new(&AFFIRMATIONS, 60.0);
+        assert_eq!(sequential.sequence(5), vec![0, 1, 2, 0, 1]);
+    }
+
+    #[test]
+    fn test_short_targets_and_empty_loops() {
+        let pacer = MeditationPacer::new();
+
+        // One full pass even when it cannot fit
+        let rushed = AffirmationLoop::new(&AFFIRMATIONS, 1.0).generate(&pacer);
+        assert_eq!(rushed.atom_count, AFFIRMATIONS.len());
+        assert!(rushed.timeline.iter().all(|e| e.pause_seconds == 0.0));
+
+        let empty = AffirmationLoop::new(&["", "  "], 60.0);
+        assert!(empty.affirmations.is_empty());
+        assert_eq!(empty.generate(&pacer).atom_count, 0);
+
+        // Shrinking pauses are clamped to even ones
+        let even = AffirmationLoop::new(&AFFIRMATIONS, 120.0).with_growth(0.5).generate(&pacer);
+        let pauses: Vec<f64> = even.timeline.iter().map(|e| e.pause_seconds).collect();
+        let last = pauses.len() - 1;
+        assert!(pauses[..last].windows(2).all(|w| (w[1] - w[0]).abs() < 0.1), "{:?}", pauses);
+    }
+}
@@ -0,0 +1,324 @@
+//! Forced-Alignment Re-Timing
+//! 
+//! After synthesis, TTS providers (e.g. ElevenLabs' timestamped endpoint)
+//! or a forced aligner report when each word was actually spoken. This
+//! module rebuilds a result's timeline from those timestamps and reports
+//! how far each atom drifted from the estimate, so captions, cues, and
+//! playback events follow the real audio.
+//! 
+//! ## Matching
+//! 
+//! Aligned words are matched to the planned words in order, comparing
+//! lowercase alphanumerics only (so `"in."` matches `in`). A planned word
+//! with no match in the next few aligned words is left unmatched. An atom
+//! is placed by its first and last matched words, with the estimated time
+//! of any unmatched words before or after them added back; an atom with
+//! no matched words keeps its estimate, shifted by the drift of the atom
+//! before it.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::{AlignedWord, MeditationPacer};
+//! 
+//! let result = MeditationPacer::new().calculate_pacing("Breathe in. Let go.".to_string(), 10.0);
+//! let words = [
+//!     AlignedWord::new("Breathe", 0.0, 0.5),
+//!     AlignedWord::new("in.", 0.5, 0.8),
+//!     AlignedWord::new("Let", 6.0, 6.2),
+//!     AlignedWord::new("go.", 6.2, 6.6),
+//! ];
+//! let aligned = result.align_to_words(&words);
+//! assert_eq!(aligned.result.timeline[1].start_seconds, 6.0);
+//! assert_eq!(aligned.drift[0].drift_seconds, 0.0);
+//! ```
+
+use crate::pacing_engine::{BreakStats, PacingResult, TimelineEntry, WordTiming};
+use crate::prelude::*;
+
+/// How many aligned words ahead to look for a planned word
+const MATCH_LOOKAHEAD: usize = 3;
+
+// ============================================
+// Types
+// ============================================
+
+/// A word with its measured position in the synthesized audio
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlignedWord {
+    /// The word as transcribed or aligned
+    pub word: String,
+    /// Offset where the word starts, in seconds
+    pub start_seconds: f64,
+    /// Offset where the word ends, in seconds
+    pub end_seconds: f64,
+}
+
+impl AlignedWord {
+    /// Create an aligned word
+    pub fn new(word: &str, start_seconds: f64, end_seconds: f64) -> Self {
+        Self {
+            word: word.to_string(),
+            start_seconds,
+            end_seconds,
+        }
+    }
+}
+
+/// Estimated vs measured position of one atom
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AtomDrift {
+    /// Index of the atom
+    pub atom_index: usize,
+    /// Estimated start, from the pacing plan
+    pub estimated_start_seconds: f64,
+    /// Measured start (the estimate, shifted, if no word matched)
+    pub actual_start_seconds: f64,
+    /// `actual_start_seconds - estimated_start_seconds`
+    pub drift_seconds: f64,
+    /// Estimated speaking time
+    pub estimated_speech_seconds: f64,
+    /// Measured speaking time
+    pub actual_speech_seconds: f64,
+    /// Whether any of the atom's words were matched
+    pub matched: bool,
+}
+
+/// A result re-timed from measured word positions
+#[derive(Debug, Clone)]
+pub struct AlignedPacing {
+    /// The result with timeline, word timings, cues, and totals measured
+    pub result: PacingResult,
+    /// Drift of every atom, in order
+    pub drift: Vec<AtomDrift>,
+}
+
+impl AlignedPacing {
+    /// Largest absolute drift of any atom
+    pub fn max_drift_seconds(&self) -> f64 {
+        self.drift.iter().map(|d| d.drift_seconds.abs()).fold(0.0, f64::max)
+    }
+}
+
+// ============================================
+// Re-Timing
+// ============================================
+
+impl PacingResult {
+    /// Rebuild the timeline from measured word timestamps
+    /// 
+    /// The SSML is unchanged; everything derived from the timeline (word
+    /// timings, pauses, cues, speech and total durations) is measured.
+    pub fn align_to_words(&self, words: &[AlignedWord]) -> AlignedPacing {
+        let matches = match_words(&self.word_timings, words);
+        
+        // Measured speech span of every atom, extended over unmatched
+        // words at either end by their estimated length
+        let mut spans: Vec<Option<(f64, f64)>> = vec![None; self.timeline.len()];
+        for (planned, aligned) in self.word_timings.iter().zip(&matches) {
+            if let Some(aligned) = aligned.map(|i| &words[i]) {
+                let entry = &self.timeline[planned.atom_index];
+                let start = aligned.start_seconds - (planned.start_seconds - entry.start_seconds);
+                let end = aligned.end_seconds
+                    + (entry.speech_end_seconds() - planned.start_seconds - planned.duration_seconds);
+                let span = &mut spans[planned.atom_index];
+                *span = Some(match *span {
+                    Some((first, _)) => (first, end),
+                    None => (start, end),
+                });
+            }
+        }
+        
+        // Atom starts and speech, carrying drift over unmatched atoms
+        let mut drift = Vec::with_capacity(self.timeline.len());
+        let mut carried = 0.0;
+        for (entry, span) in self.timeline.iter().zip(&spans) {
+            let (start, speech) = match span {
+                Some((start, end)) => (*start, end - start),
+                None => (entry.start_seconds + carried, entry.speech_seconds),
+            };
+            carried = start - entry.start_seconds;
+            drift.push(AtomDrift {
+                atom_index: entry.atom_index,
+                estimated_start_seconds: entry.start_seconds,
+                actual_start_seconds: start,
+                drift_seconds: carried,
+                estimated_speech_seconds: entry.speech_seconds,
+                actual_speech_seconds: speech,
+                matched: span.is_some(),
+            });
+        }
+        
+        let mut result = self.clone();
+        result.timeline = self.timeline.iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let speech_end = drift[i].actual_start_seconds + drift[i].actual_speech_seconds;
+                let pause = drift.get(i + 1)
+                    .map_or(0.0, |next| (next.actual_start_seconds - speech_end).max(0.0));
+                TimelineEntry {
+                    start_seconds: drift[i].actual_start_seconds,
+                    speech_seconds: drift[i].actual_speech_seconds,
+                    pause_seconds: pause,
+                    ..entry.clone()
+                }
+            })
+            .collect();
+        result.word_timings = self.word_timings.iter()
+            .zip(&matches)
+            .map(|(planned, aligned)| match aligned.map(|i| &words[i]) {
+                Some(aligned) => WordTiming {
+                    start_seconds: aligned.start_seconds,
+                    duration_seconds: aligned.end_seconds - aligned.start_seconds,
+                    ..planned.clone()
+                },
+                None => WordTiming {
+                    start_seconds: planned.start_seconds + drift[planned.atom_index].drift_seconds,
+                    ..planned.clone()
+                },
+            })
+            .collect();
+        
+        // Cues end where the next atom starts, so they move with it
+        for cue in &mut result.cues {
+            if let Some(next) = drift.get(cue.after_atom + 1) {
+                cue.start_seconds += next.drift_seconds;
+            }
+        }
+        
+        result.estimated_speech_seconds = result.timeline.iter().map(|e| e.speech_seconds).sum();
+        result.total_silence_added = result.intro_pause_seconds + result.timeline.iter().map(|e| e.pause_seconds).sum::<f64>();
+        result.estimated_total_seconds = result.timeline.last().map_or(0.0, TimelineEntry::end_seconds);
+        let pauses: Vec<f64> = result.timeline.iter().map(|e| e.pause_seconds).collect();
+        result.break_stats = BreakStats::from_pauses(&pauses, &result.warnings);
+        
+        AlignedPacing { result, drift }
+    }
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Index of the aligned word matching each planned word, in order
+fn match_words(planned: &[WordTiming], aligned: &[AlignedWord]) -> Vec<Option<usize>> {
+    let aligned_keys: Vec<String> = aligned.iter().map(|w| match_key(&w.word)).collect();
+    let mut next = 0;
+    
+    planned.iter()
+        .map(|word| {
+            let key = match_key(&word.word);
+            let end = (next + MATCH_LOOKAHEAD).min(aligned.len());
+            let found = (next..end).find(|i| aligned_keys[*i] == key)?;
+            next = found + 1;
+            Some(found)
+        })
+        .collect()
+}
+
+/// Lowercase alphanumerics of a word, for matching
+fn match_key(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MeditationPacer;
+
+    #[test]
+    fn test_align_reports_drift() {
+        let pacer = MeditationPacer::new();
+        let result = pacer.calculate_pacing("Breathe in. Hold it. Let go.".to_string(), 30.0);
+        
+        // The real audio runs one second late from the second atom on,
+        // and "Hold" was transcribed differently
+        let words: Vec<AlignedWord> = result.word_timings.iter()
+            .filter(|w| w.word != "Hold")
+            .map(|w| {
+                let late = if w.atom_index >= 1 { 1.0 } else { 0.0 };
+                AlignedWord::new(&w.word, w.start_seconds + late, w.start_seconds + late + w.duration_seconds)
+            })
+            .collect();
+        let aligned = result.align_to_words(&words);
+        
+        assert_eq!(aligned.drift[0].drift_seconds, 0.0);
+        assert!((aligned.drift[1].drift_seconds - 1.0).abs() < 1e-9);
+        assert!((aligned.drift[2].drift_seconds - 1.0).abs() < 1e-9);
+        assert!((aligned.max_drift_seconds() - 1.0).abs() < 1e-9);
+        
+        // The pause before the late atom grew by the drift
+        let before = &result.timeline[0];
+        let after = &aligned.result.timeline[0];
+        assert!((after.pause_seconds - (before.pause_seconds + 1.0)).abs() < 1e-9);
+        
+        // The unmatched word moved with its atom
+        let hold = &aligned.result.word_timings[2];
+        assert_eq!(hold.word, "Hold");
+        assert!((hold.start_seconds - (result.word_timings[2].start_seconds + 1.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_match_words_skips_extras() {
+        let planned = MeditationPacer::new()
+            .calculate_pacing("Breathe in slowly.".to_string(), 10.0)
+            .word_timings;
+        let aligned = [
+            AlignedWord::new("Breathe", 0.0, 0.4),
+            AlignedWord::new("uh", 0.4, 0.5),
+            AlignedWord::new("IN", 0.5, 0.7),
+            AlignedWord::new("slowly.", 0.7, 1.2),
+        ];
+        assert_eq!(match_words(&planned, &aligned), vec![Some(0), Some(2), Some(3)]);
+    }
+
+    #[test]
+    fn test_unmatched_atoms_carry_drift() {
+        let pacer = MeditationPacer::new();
+        let result = pacer.calculate_pacing("Breathe in. Hold it. Let go.".to_string(), 30.0);
+        
+        // Nothing measured: the plan is kept as is
+        let unaligned = result.align_to_words(&[]);
+        assert!(unaligned.drift.iter().all(|d| !d.matched && d.drift_seconds == 0.0));
+        for (before, after) in result.timeline.iter().zip(&unaligned.result.timeline) {
+            assert!((before.start_seconds - after.start_seconds).abs() < 1e-9);
+            assert!((before.pause_seconds - after.pause_seconds).abs() < 1e-9);
+        }
+        
+        // The middle atom was not transcribed; it moves with the first
+        let words: Vec<AlignedWord> = result.word_timings.iter()
+            .filter(|w| w.atom_index != 1)
+            .map(|w| AlignedWord::new(&w.word, w.start_seconds + 0.5, w.start_seconds + 0.5 + w.duration_seconds))
+            .collect();
+        let aligned = result.align_to_words(&words);
+        assert!(!aligned.drift[1].matched);
+        assert!((aligned.drift[1].drift_seconds - 0.5).abs() < 1e-9);
+        assert_eq!(aligned.drift[1].actual_speech_seconds, result.timeline[1].speech_seconds);
+    }
+
+    #[test]
+    fn test_match_words_lookahead() {
+        let planned = MeditationPacer::new()
+            .calculate_pacing("Breathe in.".to_string(), 10.0)
+            .word_timings;
+        let aligned = [
+            AlignedWord::new("Breathe", 0.0, 0.4),
+            AlignedWord::new("uh", 0.4, 0.5),
+            AlignedWord::new("um", 0.5, 0.6),
+            AlignedWord::new("er", 0.6, 0.7),
+            AlignedWord::new("in", 0.7, 0.9),
+        ];
+        // "in" is more than MATCH_LOOKAHEAD words past "Breathe"
+        assert_eq!(match_words(&planned, &aligned), vec![Some(0), None]);
+        assert_eq!(match_key("Don't!"), "dont");
+    }
+}
@@ -0,0 +1,276 @@
+//! Interval Bells
+//! 
+//! Bells (or any chime) can be scheduled at absolute times, at a fraction
+//! of the session (e.g. the midpoint), or at a fixed interval. Each bell is
+//! moved to the nearest pause between atoms and sounds at the end of that
+//! pause: the next atom is anchored to start when the bell ends, and the
+//! pause is lengthened to at least the bell's duration, so speech never
+//! overlaps a bell. The rest of the silence budget is redistributed around
+//! them.
+//! 
+//! Placed bells are reported as `PacingResult::cues`. Bells with an audio
+//! source are also rendered into the SSML as `<audio src="..."/>` in place
+//! of their pause; bells without one stay silent in the SSML for the
+//! player to sound.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::{Bell, MeditationPacer};
+//! 
+//! let text = "Settle in. Breathe in. Breathe out. Rest here. Welcome back.".to_string();
+//! let bells = [Bell::midpoint(4.0).with_audio("bell.mp3")];
+//! let result = MeditationPacer::new().calculate_pacing_with_bells(text, 60.0, &bells);
+//! assert_eq!(result.cues.len(), 1);
+//! assert!(result.ssml.contains("<audio src=\"bell.mp3\"/>"));
+//! ```
+
+use crate::pacing_engine::{Cue, MeditationPacer, PacingOverrides, PacingResult};
+use crate::prelude::*;
+use crate::session::escape_attribute;
+
+// ============================================
+// Types
+// ============================================
+
+/// When a bell sounds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BellTime {
+    /// At an absolute offset in seconds
+    At(f64),
+    /// At this fraction of the target duration (0.5 = midpoint)
+    Fraction(f64),
+    /// Every this many seconds, starting one interval in
+    Every(f64),
+}
+
+/// A bell to place in the session
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bell {
+    /// Name reported on the cue
+    pub name: String,
+    /// When the bell sounds
+    pub time: BellTime,
+    /// How long the bell rings; speech resumes after this
+    pub duration_seconds: f64,
+    /// Audio to render as an SSML `<audio>` tag
+    pub audio_src: Option<String>,
+}
+
+impl Bell {
+    /// A bell at an absolute offset
+    pub fn at(seconds: f64, duration_seconds: f64) -> Self {
+        Self::new(BellTime::At(seconds), duration_seconds)
+    }
+
+    /// A bell at the middle of the session
+    pub fn midpoint(duration_seconds: f64) -> Self {
+        Self::new(BellTime::Fraction(0.5), duration_seconds)
+    }
+
+    /// A bell repeating every `interval_seconds`
+    pub fn every(interval_seconds: f64, duration_seconds: f64) -> Self {
+        Self::new(BellTime::Every(interval_seconds), duration_seconds)
+    }
+
+    /// A bell at any `BellTime`
+    pub fn new(time: BellTime, duration_seconds: f64) -> Self {
+        Self {
+            name: "bell".to_string(),
+            time,
+            duration_seconds,
+            audio_src: None,
+        }
+    }
+
+    /// Rename the bell's cues
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Render the bell as `<audio src="..."/>`
+    pub fn with_audio(mut self, src: &str) -> Self {
+        self.audio_src = Some(src.to_string());
+        self
+    }
+
+    /// Offsets this bell asks for within a session of `target` seconds
+    fn times(&self, target_duration_seconds: f64) -> Vec<f64> {
+        let inside = |t: &f64| *t > 0.0 && *t < target_duration_seconds;
+        match self.time {
+            BellTime::At(seconds) => Some(seconds).filter(inside).into_iter().collect(),
+            BellTime::Fraction(f) => Some(f * target_duration_seconds).filter(inside).into_iter().collect(),
+            BellTime::Every(interval) if interval > 0.0 => (1..)
+                .map(|k| k as f64 * interval)
+                .take_while(|t| *t < target_duration_seconds)
+                .collect(),
+            BellTime::Every(_) => Vec::new(),
+        }
+    }
+}
+
+// ============================================
+// Scheduling
+// ============================================
+
+impl MeditationPacer {
+    /// Pace a script with bells placed in its pauses
+    /// 
+    /// Each bell moves to the pause nearest its requested time; a bell
+    /// whose nearest pause already holds an earlier bell is skipped. If the
+    /// speech before a bell cannot fit ahead of its time, the bell sounds
+    /// late, right after that speech (see `Cue::start_seconds`).
+    pub fn calculate_pacing_with_bells(
+        &self,
+        text: String,
+        target_duration_seconds: f64,
+        bells: &[Bell],
+    ) -> PacingResult {
+        let (atoms, replacements) = self.atomize_filtered(&text);
+        let mut plain = self.pace_atoms(&atoms, target_duration_seconds, &PacingOverrides::new());
+        if atoms.len() < 2 {
+            plain.replacements = replacements;
+            return plain;
+        }
+        
+        // Step A: Resolve bell times and snap each to the nearest pause
+        let mut requested: Vec<(f64, &Bell)> = bells.iter()
+            .flat_map(|bell| bell.times(target_duration_seconds).into_iter().map(move |t| (t, bell)))
+            .collect();
+        requested.sort_by(|a, b| a.0.total_cmp(&b.0));
+        
+        let mut placed: Vec<(usize, &Bell)> = Vec::new();
+        let mut overrides = PacingOverrides::new();
+        for (time, bell) in requested {
+            let boundary = (0..atoms.len() - 1)
+                .min_by(|a, b| {
+                    let distance = |i: &usize| (plain.timeline[*i].speech_end_seconds() - time).abs();
+                    distance(a).total_cmp(&distance(b))
+                })
+                .unwrap_or(0);
+            if placed.last().is_some_and(|(previous, _)| *previous >= boundary) {
+                continue;
+            }
+            
+            overrides = overrides.anchor_at(boundary + 1, time + bell.duration_seconds.max(0.0));
+            placed.push((boundary, bell));
+        }
+        
+        // Step B: Re-pace around the bells, pinning pauses too short to
+        // hold their bell
+        let (mut speech, mut pauses, mut raw_silence_budget, mut final_silence_budget) =
+            self.plan_pauses(&atoms, target_duration_seconds, &overrides);
+        let short: Vec<&(usize, &Bell)> = placed.iter()
+            .filter(|(boundary, bell)| pauses[*boundary] < bell.duration_seconds)
+            .collect();
+        if !short.is_empty() {
+            for (boundary, bell) in short {
+                overrides = overrides.pause_at(*boundary, bell.duration_seconds.max(0.0));
+            }
+            (speech, pauses, raw_silence_budget, final_silence_budget) =
+                self.plan_pauses(&atoms, target_duration_seconds, &overrides);
+        }
+        
+        let mut result = self.assemble_result(
+            &atoms,
+            &pauses,
+            &speech,
+            target_duration_seconds,
+            raw_silence_budget,
+            final_silence_budget,
+        );
+        result.warnings.extend(self.check_silence_ratio(
+            result.total_words,
+            result.estimated_speech_seconds,
+            target_duration_seconds,
+        ));
+        result.replacements = replacements;
+        
+        result.cues = placed.iter()
+            .map(|(boundary, bell)| {
+                let entry = &result.timeline[*boundary];
+                let duration_seconds = bell.duration_seconds.max(0.0);
+                Cue {
+                    name: bell.name.clone(),
+                    after_atom: *boundary,
+                    start_seconds: (entry.end_seconds() - duration_seconds).max(entry.speech_end_seconds()),
+                    duration_seconds,
+                    audio_src: bell.audio_src.clone(),
+                }
+            })
+            .collect();
+        
+        // Step C: Audio bells replace their pause in the SSML
+        if placed.iter().any(|(_, bell)| bell.audio_src.is_some()) {
+            let mut render_pauses = pauses.clone();
+            for (boundary, bell) in &placed {
+                if bell.audio_src.is_some() {
+                    render_pauses[*boundary] = (pauses[*boundary] - bell.duration_seconds).max(0.0);
+                }
+            }
+            let mut fragments = self.render_fragments(&atoms, &render_pauses);
+            for (boundary, bell) in &placed {
+                if let Some(src) = &bell.audio_src {
+                    fragments[*boundary].push_str(&format!("<audio src=\"{}\"/>", escape_attribute(src)));
+                }
+            }
+            result.ssml = fragments.join(" ");
+        }
+        
+        result
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCRIPT: &str = "Settle in. Breathe in. Breathe out. Rest here. Notice the quiet. Welcome back.";
+
+    #[test]
+    fn test_bell_times() {
+        assert_eq!(Bell::every(20.0, 3.0).times(60.0), vec![20.0, 40.0]);
+        assert_eq!(Bell::midpoint(3.0).times(60.0), vec![30.0]);
+        assert!(Bell::at(75.0, 3.0).times(60.0).is_empty());
+        assert!(Bell::every(0.0, 3.0).times(60.0).is_empty());
+    }
+
+    #[test]
+    fn test_speech_never_overlaps_bells() {
+        let pacer = MeditationPacer::new();
+        let bells = [Bell::every(20.0, 4.0)];
+        let result = pacer.calculate_pacing_with_bells(SCRIPT.to_string(), 60.0, &bells);
+        
+        assert_eq!(result.cues.len(), 2);
+        for cue in &result.cues {
+            let before = &result.timeline[cue.after_atom];
+            let after = &result.timeline[cue.after_atom + 1];
+            assert!(cue.start_seconds >= before.speech_end_seconds());
+            assert!(after.start_seconds >= cue.end_seconds() - 1e-9);
+        }
+        
+        // Enough room before each bell, so it lands on its requested time
+        assert!((result.cues[0].start_seconds - 20.0).abs() < 0.1);
+        assert!((result.cues[1].start_seconds - 40.0).abs() < 0.1);
+        assert!(!result.ssml.contains("<audio"));
+    }
+
+    #[test]
+    fn test_audio_bell_replaces_pause() {
+        let pacer = MeditationPacer::new();
+        let bells = [Bell::at(30.0, 4.0).with_audio("chime.mp3")];
+        let result = pacer.calculate_pacing_with_bells(SCRIPT.to_string(), 60.0, &bells);
+        
+        // The bell closes its pause, right before the next atom
+        let cue = &result.cues[0];
+        let next = &result.timeline[cue.after_atom + 1].text;
+        let expected = format!("<audio src=\"chime.mp3\"/> {}", next);
+        assert!(result.ssml.contains(&expected), "{}", result.ssml);
+        assert_eq!(result.ssml.matches("<audio").count(), 1);
+    }
+}
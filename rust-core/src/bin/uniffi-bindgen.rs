@@ -0,0 +1,13 @@
+//! UniFFI binding generator
+//! 
+//! Pinned to the crate's UniFFI version so generated Swift and Kotlin code
+//! always matches the scaffolding. Run against the built library:
+//! 
+//! ```text
+//! cargo run --features uniffi-cli --bin uniffi-bindgen -- \
+//!     generate --library target/release/libzenpal_core.so --language kotlin --out-dir out
+//! ```
+
+fn main() {
+    uniffi::uniffi_bindgen_main()
+}
@@ -0,0 +1,220 @@
+//! zenpal-pace
+//! 
+//! Paces a script file from the command line so content editors can
+//! preview SSML without writing Rust. Available with the `cli` feature:
+//! 
+//! ```text
+//! cargo run --features cli --bin zenpal-pace -- script.txt --duration 600
+//! zenpal-pace script.txt --duration 10m --config sleep.toml --format json
+//! ```
+//! 
+//! Pass `-` as the script to read from stdin. Errors go to stderr with a
+//! non-zero exit code.
+//! 
+//! ## WASI
+//! 
+//! The CLI builds for `wasm32-wasip1` unchanged. File access is limited to
+//! directories preopened by the host; `.cargo/config.toml` runs it under
+//! wasmtime with the current directory preopened, so relative paths work:
+//! 
+//! ```text
+//! cargo run --target wasm32-wasip1 --features cli --bin zenpal-pace -- script.txt -d 10m
+//! wasmtime run --dir scripts zenpal-pace.wasm scripts/body.txt -d 600
+//! ```
+
+use std::fs;
+use std::io::{self, Read};
+use std::process::ExitCode;
+
+use zenpal_core::{MeditationPacer, PacingConfig, PacingResult};
+
+const USAGE: &str = "\
+Usage: zenpal-pace <SCRIPT> --duration <SECONDS> [--config <FILE>] [--format <FORMAT>]
+
+Arguments:
+  <SCRIPT>             Script text file, or `-` for stdin
+
+Options:
+  -d, --duration <D>   Target duration in seconds, or with an `m` suffix in minutes
+  -c, --config <FILE>  Pacing config as .json or .toml
+  -f, --format <F>     ssml (default), json, or report
+  -h, --help           Print this help";
+
+// ============================================
+// Arguments
+// ============================================
+
+/// What to print for a paced script
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Ssml,
+    Json,
+    Report,
+}
+
+/// Parsed command line
+#[derive(Debug, PartialEq)]
+struct Args {
+    script: String,
+    duration_seconds: f64,
+    config: Option<String>,
+    format: Format,
+}
+
+/// Parse arguments (without the program name)
+/// 
+/// `Ok(None)` means help was requested.
+fn parse_args(args: &[String]) -> Result<Option<Args>, String> {
+    let mut script = None;
+    let mut duration_seconds = None;
+    let mut config = None;
+    let mut format = Format::Ssml;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = |flag: &str| {
+            iter.next().ok_or_else(|| format!("{} needs a value", flag))
+        };
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-d" | "--duration" => duration_seconds = Some(parse_duration(value(arg)?)?),
+            "-c" | "--config" => config = Some(value(arg)?.clone()),
+            "-f" | "--format" => format = parse_format(value(arg)?)?,
+            flag if flag.starts_with('-') && flag != "-" => {
+                return Err(format!("unknown option `{}`", flag));
+            }
+            path if script.is_none() => script = Some(path.to_string()),
+            extra => return Err(format!("unexpected argument `{}`", extra)),
+        }
+    }
+
+    Ok(Some(Args {
+        script: script.ok_or("missing <SCRIPT>")?,
+        duration_seconds: duration_seconds.ok_or("missing --duration")?,
+        config,
+        format,
+    }))
+}
+
+/// Seconds from `600`, `600s`, or `10m`
+fn parse_duration(value: &str) -> Result<f64, String> {
+    let (number, scale) = match value.strip_suffix('m') {
+        Some(minutes) => (minutes, 60.0),
+        None => (value.strip_suffix('s').unwrap_or(value), 1.0),
+    };
+    number.parse::<f64>()
+        .ok()
+        .map(|n| n * scale)
+        .filter(|seconds| seconds.is_finite() && *seconds > 0.0)
+        .ok_or_else(|| format!("invalid duration `{}`", value))
+}
+
+fn parse_format(value: &str) -> Result<Format, String> {
+    match value {
+        "ssml" => Ok(Format::Ssml),
+        "json" => Ok(Format::Json),
+        "report" => Ok(Format::Report),
+        other => Err(format!("unknown format `{}` (expected ssml, json, or report)", other)),
+    }
+}
+
+// ============================================
+// Running
+// ============================================
+
+/// Load a config file, picking the parser from its extension
+fn load_config(path: &str) -> Result<PacingConfig, String> {
+    let source = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+    let config = if path.ends_with(".toml") {
+        PacingConfig::from_toml_str(&source)
+    } else {
+        PacingConfig::from_json_str(&source)
+    };
+    config.map_err(|e| format!("{}: {}", path, e))
+}
+
+/// Read the script from a file or stdin
+fn read_script(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text).map_err(|e| format!("stdin: {}", e))?;
+        Ok(text)
+    } else {
+        fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))
+    }
+}
+
+/// Render a result in the requested format
+fn render(result: &PacingResult, format: Format) -> Result<String, String> {
+    match format {
+        Format::Ssml => Ok(result.ssml.clone()),
+        Format::Json => serde_json::to_string_pretty(result).map_err(|e| e.to_string()),
+        Format::Report => Ok(result.report()),
+    }
+}
+
+fn run(args: Args) -> Result<String, String> {
+    let pacer = match &args.config {
+        Some(path) => MeditationPacer::with_config(load_config(path)?),
+        None => MeditationPacer::new(),
+    };
+    let text = read_script(&args.script)?;
+    let result = pacer.try_calculate_pacing(text, args.duration_seconds)
+        .map_err(|e| e.to_string())?;
+    render(&result, args.format)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args = match parse_args(&args) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(args) {
+        Ok(output) => {
+            println!("{}", output.trim_end());
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let parsed = parse_args(&args("script.txt -d 10m --format json")).unwrap().unwrap();
+        assert_eq!(parsed, Args {
+            script: "script.txt".to_string(),
+            duration_seconds: 600.0,
+            config: None,
+            format: Format::Json,
+        });
+
+        assert_eq!(parse_args(&args("- --help")), Ok(None));
+        assert!(parse_args(&args("script.txt")).unwrap_err().contains("--duration"));
+        assert!(parse_args(&args("a.txt b.txt -d 60")).is_err());
+        assert!(parse_args(&args("a.txt -d -5")).is_err());
+    }
+}
@@ -0,0 +1,326 @@
+//! Breathwork Pattern Generators
+//! 
+//! Generates spoken breathing sessions (cue, then a timed pause) that come
+//! out of the same renderer as text-based meditations and return the same
+//! `PacingResult` shape.
+//! 
+//! ## Phase Timing
+//! 
+//! A phase starts when its cue starts. The pause after each cue is the
+//! phase length minus the cue's estimated speech time, so every phase lasts
+//! exactly its configured length by the pacer's speech estimate.
+//! 
+//! Counted sessions (`BreathingPattern::generate_counted`) speak a count on
+//! every second of a phase ("Breathe in. Two. Three. Four.") and pad each
+//! count out to one second.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::breathwork::{BoxBreathing, BreathingPattern};
+//! use zenpal_core::MeditationPacer;
+//! 
+//! let pacer = MeditationPacer::new();
+//! let session = BoxBreathing::new(4.0, 3);
+//! let result = session.generate(&pacer);
+//! assert!(result.ssml.starts_with("Breathe in."));
+//! 
+//! let relaxing = BreathingPattern::four_seven_eight(4);
+//! let counted = relaxing.generate_counted(&pacer);
+//! assert!(counted.ssml.contains("Seven."));
+//! ```
+
+use crate::pacing_engine::{MeditationPacer, PacingResult, PunctuationType, SpeechAtom};
+use crate::prelude::*;
+
+// ============================================
+// Cue Text
+// ============================================
+
+/// Spoken cue for the inhale phase
+const CUE_INHALE: &str = "Breathe in";
+
+/// Spoken cue for a breath-hold phase
+const CUE_HOLD: &str = "Hold";
+
+/// Spoken cue for the exhale phase
+const CUE_EXHALE: &str = "Breathe out";
+
+/// Spoken cue that closes the session
+const CUE_CLOSING: &str = "And relax";
+
+/// Spoken counts for counted phases (index 0 = "One")
+const COUNT_WORDS: [&str; 12] = [
+    "One", "Two", "Three", "Four", "Five", "Six",
+    "Seven", "Eight", "Nine", "Ten", "Eleven", "Twelve",
+];
+
+/// Length of one spoken count in seconds
+const COUNT_SECONDS: f64 = 1.0;
+
+// ============================================
+// Breathing Pattern
+// ============================================
+
+/// A breathing cycle of up to four phases, repeated for a number of rounds
+/// 
+/// Phases with a length of zero are skipped (e.g. `hold2` in 4-7-8).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BreathingPattern {
+    /// Inhale length in seconds
+    pub inhale: f64,
+    /// Hold after the inhale in seconds
+    pub hold: f64,
+    /// Exhale length in seconds
+    pub exhale: f64,
+    /// Hold after the exhale in seconds
+    pub hold2: f64,
+    /// Number of full cycles
+    pub rounds: u32,
+}
+
+impl BreathingPattern {
+    /// Create a breathing pattern
+    pub fn new(inhale: f64, hold: f64, exhale: f64, hold2: f64, rounds: u32) -> Self {
+        Self {
+            inhale,
+            hold,
+            exhale,
+            hold2,
+            rounds,
+        }
+    }
+
+    /// 4-7-8 relaxing breath
+    pub fn four_seven_eight(rounds: u32) -> Self {
+        Self::new(4.0, 7.0, 8.0, 0.0, rounds)
+    }
+
+    /// Length of one cycle in seconds
+    pub fn cycle_seconds(&self) -> f64 {
+        self.phases().iter().map(|(_, seconds)| seconds).sum()
+    }
+
+    /// Total session length: all rounds plus the closing cue
+    pub fn target_duration_seconds(&self, pacer: &MeditationPacer) -> f64 {
+        self.rounds as f64 * self.cycle_seconds() + cue_seconds(pacer, CUE_CLOSING)
+    }
+
+    /// Render with one cue per phase and a single pause filling the phase
+    pub fn generate(&self, pacer: &MeditationPacer) -> PacingResult {
+        let mut steps: Vec<(String, f64)> = Vec::new();
+        for _ in 0..self.rounds {
+            steps.extend(self.phases().iter().map(|(cue, seconds)| (cue.to_string(), *seconds)));
+        }
+        render_phases(pacer, &steps, self.target_duration_seconds(pacer))
+    }
+
+    /// Render with a spoken count on every second of each phase
+    /// 
+    /// The phase cue stands in for "One"; a fractional remainder is added
+    /// to the last count of the phase.
+    pub fn generate_counted(&self, pacer: &MeditationPacer) -> PacingResult {
+        let mut steps: Vec<(String, f64)> = Vec::new();
+        for _ in 0..self.rounds {
+            for (cue, seconds) in self.phases() {
+                steps.extend(counted_steps(cue, seconds));
+            }
+        }
+        render_phases(pacer, &steps, self.target_duration_seconds(pacer))
+    }
+
+    /// Non-empty `(cue, seconds)` phases of one cycle
+    fn phases(&self) -> Vec<(&'static str, f64)> {
+        [
+            (CUE_INHALE, self.inhale),
+            (CUE_HOLD, self.hold),
+            (CUE_EXHALE, self.exhale),
+            (CUE_HOLD, self.hold2),
+        ]
+        .into_iter()
+        .filter(|(_, seconds)| *seconds > 0.0)
+        .collect()
+    }
+}
+
+// ============================================
+// Box Breathing
+// ============================================
+
+/// Box breathing: inhale, hold, exhale, hold - all the same length
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoxBreathing {
+    /// Length of each of the four phases in seconds
+    pub phase_seconds: f64,
+    /// Number of full inhale-hold-exhale-hold rounds
+    pub rounds: u32,
+}
+
+impl BoxBreathing {
+    /// Create a box breathing session
+    pub fn new(phase_seconds: f64, rounds: u32) -> Self {
+        Self {
+            phase_seconds,
+            rounds,
+        }
+    }
+
+    /// The equivalent four-phase breathing pattern
+    pub fn pattern(&self) -> BreathingPattern {
+        let side = self.phase_seconds;
+        BreathingPattern::new(side, side, side, side, self.rounds)
+    }
+
+    /// Total session length: all rounds plus the closing cue
+    pub fn target_duration_seconds(&self, pacer: &MeditationPacer) -> f64 {
+        self.pattern().target_duration_seconds(pacer)
+    }
+
+    /// Render the session with the pacer's speech rate and break formatting
+    pub fn generate(&self, pacer: &MeditationPacer) -> PacingResult {
+        self.pattern().generate(pacer)
+    }
+}
+
+// ============================================
+// Helper Functions
+// ============================================
+
+/// Estimated speech time of a cue under the pacer's duration model
+fn cue_seconds(pacer: &MeditationPacer, cue: &str) -> f64 {
+    pacer.speech_seconds(&[cue_atom(cue)]).iter().sum()
+}
+
+/// Split a phase into one-second counts, the cue standing in for "One"
+fn counted_steps(cue: &str, seconds: f64) -> Vec<(String, f64)> {
+    let counts = ((seconds / COUNT_SECONDS).floor() as usize).max(1);
+    let remainder = seconds - counts as f64 * COUNT_SECONDS;
+    
+    (0..counts)
+        .map(|i| {
+            let text = if i == 0 {
+                cue.to_string()
+            } else {
+                COUNT_WORDS.get(i).map_or_else(|| (i + 1).to_string(), |w| w.to_string())
+            };
+            let length = if i == counts - 1 { COUNT_SECONDS + remainder } else { COUNT_SECONDS };
+            (text, length.max(0.0))
+        })
+        .collect()
+}
+
+/// Render `(cue, phase seconds)` steps followed by the closing cue
+/// 
+/// Phases are rendered as timed: intro and outro pauses and the break
+/// budget do not apply.
+pub(crate) fn render_phases(
+    pacer: &MeditationPacer,
+    steps: &[(String, f64)],
+    target_duration_seconds: f64,
+) -> PacingResult {
+    let pacer = &pacer.without_break_budget().without_framing();
+    let mut atoms = Vec::with_capacity(steps.len() + 1);
+    let mut pauses = Vec::with_capacity(steps.len() + 1);
+    let mut speech = Vec::with_capacity(steps.len() + 1);
+    
+    for (cue, phase_seconds) in steps {
+        let cue_time = cue_seconds(pacer, cue);
+        atoms.push(cue_atom(cue));
+        pauses.push((phase_seconds - cue_time).max(0.0));
+        speech.push(cue_time);
+    }
+    
+    atoms.push(cue_atom(CUE_CLOSING));
+    pauses.push(0.0);
+    speech.push(cue_seconds(pacer, CUE_CLOSING));
+    
+    let speech_seconds: f64 = speech.iter().sum();
+    let silence_budget = (target_duration_seconds - speech_seconds).max(0.0);
+    pacer.assemble_result(
+        &atoms,
+        &pauses,
+        &speech,
+        target_duration_seconds,
+        silence_budget,
+        silence_budget,
+    )
+}
+
+/// A cue sentence as a speech atom
+fn cue_atom(cue: &str) -> SpeechAtom {
+    SpeechAtom::new(cue.to_string(), PunctuationType::SentenceEnd, ".".to_string())
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pacing_engine::{BreakBudget, PacingConfig};
+
+    #[test]
+    fn test_box_breathing_structure() {
+        let pacer = MeditationPacer::new();
+        let result = BoxBreathing::new(4.0, 2).generate(&pacer);
+        
+        // 4 phases per round + closing cue
+        assert_eq!(result.atom_count, 9);
+        assert!(result.ssml.starts_with("Breathe in.<break"));
+        assert!(result.ssml.ends_with("And relax."));
+        assert_eq!(result.ssml.matches("Hold.").count(), 4);
+    }
+
+    #[test]
+    fn test_four_seven_eight_counted() {
+        let pacer = MeditationPacer::new();
+        let pattern = BreathingPattern::four_seven_eight(2);
+        assert_eq!(pattern.cycle_seconds(), 19.0);
+        
+        let result = pattern.generate_counted(&pacer);
+        // One atom per second of each cycle + closing cue
+        assert_eq!(result.atom_count, 2 * 19 + 1);
+        assert!(result.ssml.contains("Hold.<break"));
+        assert!(result.ssml.contains("Eight."));
+        assert!((result.estimated_total_seconds - pattern.target_duration_seconds(&pacer)).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_counted_steps_remainder() {
+        let steps = counted_steps(CUE_INHALE, 3.5);
+        assert_eq!(steps.len(), 3);
+        assert_eq!(steps[0].0, CUE_INHALE);
+        assert_eq!(steps[2], ("Three".to_string(), 1.5));
+    }
+
+    #[test]
+    fn test_box_breathing_exact_duration() {
+        let pacer = MeditationPacer::new();
+        let session = BoxBreathing::new(4.0, 3);
+        let result = session.generate(&pacer);
+        
+        // No safety buffer: the estimate hits the target exactly
+        let expected = 3.0 * 16.0 + cue_seconds(&pacer, CUE_CLOSING);
+        assert!((result.target_duration_seconds - expected).abs() < 1e-9);
+        assert!((result.estimated_total_seconds - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_phases_ignore_framing_and_budget() {
+        let config = PacingConfig {
+            intro_pause_seconds: 5.0,
+            outro_pause_seconds: 3.0,
+            break_budget: BreakBudget { max_total_seconds: Some(10.0), max_tags: Some(2) },
+            ..PacingConfig::default()
+        };
+        let framed = MeditationPacer::with_config(config);
+        let session = BoxBreathing::new(4.0, 2);
+        let result = session.generate(&framed);
+        let plain = session.generate(&MeditationPacer::new());
+        
+        assert_eq!(result.ssml, plain.ssml);
+        assert_eq!(result.intro_pause_seconds, 0.0);
+        assert!((result.estimated_total_seconds - session.target_duration_seconds(&framed)).abs() < 1e-9);
+    }
+}
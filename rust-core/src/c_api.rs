@@ -0,0 +1,162 @@
+//! C ABI
+//! 
+//! `extern "C"` entry points for embedding the engine where neither UniFFI
+//! nor wasm-bindgen applies (Android NDK, Unity, other C consumers). The
+//! interface only uses doubles, integers, and null-terminated UTF-8
+//! strings; the matching header is `include/zenpal_core.h`.
+//! 
+//! Available with the `c-api` feature. The core manifest only builds the
+//! Rust library; the C libraries come from the `bindings` crate, which
+//! turns the feature on:
+//! 
+//! ```text
+//! cargo build --release -p zenpal-core-bindings   # libzenpal_core.a and libzenpal_core.so
+//! ```
+//! 
+//! ## Memory
+//! 
+//! Every `char *` returned by a `zenpal_*` function is owned by the caller
+//! and must be released with `zenpal_free_string` (never `free`). Functions
+//! return `NULL` when an argument is `NULL` or not valid UTF-8.
+
+use alloc::ffi::CString;
+use core::ffi::{c_char, CStr};
+
+use crate::pacing_engine;
+use crate::prelude::*;
+
+/// Library version as a static null-terminated string (do not free)
+const VERSION: &CStr = match CStr::from_bytes_with_nul(concat!(env!("CARGO_PKG_VERSION"), "\0").as_bytes()) {
+    Ok(version) => version,
+    Err(_) => panic!("version contains a NUL byte"),
+};
+
+// ============================================
+// Exports
+// ============================================
+
+/// Format meditation text into SSML with the default config
+/// 
+/// # Safety
+/// 
+/// `text` must be `NULL` or point to a null-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn zenpal_format_ssml(text: *const c_char, target_duration_seconds: f64) -> *mut c_char {
+    match read_str(text) {
+        Some(text) => into_c_string(pacing_engine::format_meditation_ssml(text, target_duration_seconds)),
+        None => core::ptr::null_mut(),
+    }
+}
+
+/// Full pacing result as a JSON string
+/// 
+/// Always exported so the header links either way; returns `NULL` when the
+/// library was built without the `serde` feature.
+/// 
+/// # Safety
+/// 
+/// `text` must be `NULL` or point to a null-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn zenpal_calculate_pacing_json(
+    text: *const c_char,
+    target_duration_seconds: f64,
+) -> *mut c_char {
+    #[cfg(feature = "serde")]
+    if let Some(text) = read_str(text) {
+        return into_c_string(pacing_engine::calculate_pacing_json(text, target_duration_seconds));
+    }
+    #[cfg(not(feature = "serde"))]
+    let _ = (text, target_duration_seconds);
+    core::ptr::null_mut()
+}
+
+/// Target word count for an LLM prompt (saturating at `UINT32_MAX`)
+#[no_mangle]
+pub extern "C" fn zenpal_target_words(target_duration_seconds: f64) -> u32 {
+    u32::try_from(pacing_engine::calculate_target_words_for_prompt(target_duration_seconds)).unwrap_or(u32::MAX)
+}
+
+/// Library version (static; do not free)
+#[no_mangle]
+pub extern "C" fn zenpal_version() -> *const c_char {
+    VERSION.as_ptr()
+}
+
+/// Release a string returned by a `zenpal_*` function
+/// 
+/// # Safety
+/// 
+/// `s` must be `NULL` or a pointer returned by this library that has not
+/// been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn zenpal_free_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Copy a C string argument into an owned `String`
+/// 
+/// # Safety
+/// 
+/// `ptr` must be `NULL` or point to a null-terminated string.
+unsafe fn read_str(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+    CStr::from_ptr(ptr).to_str().ok().map(str::to_string)
+}
+
+/// Hand a string to the caller (interior NULs cannot occur in SSML/JSON
+/// built from valid input, but are stripped to be safe)
+fn into_c_string(s: String) -> *mut c_char {
+    let bytes: Vec<u8> = s.into_bytes().into_iter().filter(|b| *b != 0).collect();
+    CString::new(bytes).map_or(core::ptr::null_mut(), CString::into_raw)
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_ssml_round_trip() {
+        let text = CString::new("Breathe in. Let go.").unwrap();
+        unsafe {
+            let ssml = zenpal_format_ssml(text.as_ptr(), 20.0);
+            assert!(!ssml.is_null());
+            let rust = CStr::from_ptr(ssml).to_str().unwrap().to_string();
+            assert_eq!(rust, pacing_engine::format_meditation_ssml("Breathe in. Let go.".to_string(), 20.0));
+            zenpal_free_string(ssml);
+            
+            assert!(zenpal_format_ssml(core::ptr::null(), 20.0).is_null());
+            zenpal_free_string(core::ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_version_and_words() {
+        let version = unsafe { CStr::from_ptr(zenpal_version()) };
+        assert_eq!(version.to_str().unwrap(), env!("CARGO_PKG_VERSION"));
+        assert_eq!(zenpal_target_words(300.0), 350);
+        assert_eq!(zenpal_target_words(1e12), u32::MAX);
+    }
+
+    #[test]
+    fn test_pacing_json_needs_serde() {
+        let text = CString::new("Breathe in. Let go.").unwrap();
+        unsafe {
+            let json = zenpal_calculate_pacing_json(text.as_ptr(), 20.0);
+            assert_eq!(json.is_null(), cfg!(not(feature = "serde")));
+            zenpal_free_string(json);
+            assert!(zenpal_calculate_pacing_json(core::ptr::null(), 20.0).is_null());
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Calibration From Measured TTS Samples
+//! 
+//! Fits the speech-rate constants from real synthesized audio instead of
+//! tuning them by hand. Each sample is a script and the measured duration of
+//! its speech (no added breaks).
+//! 
+//! ## Models
+//! 
+//! - `fit`: `seconds = chars / chars_per_second` (least squares through
+//!   the origin)
+//! - `fit_with_punctuation`: additionally fits a fixed latency per
+//!   punctuation class, for voices that pause on their own at commas and
+//!   sentence ends
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::calibration;
+//! 
+//! let samples = [
+//!     ("Welcome. Take a deep breath.", 2.2),
+//!     ("Notice the weight of your body against the floor.", 3.4),
+//! ];
+//! let fit = calibration::fit(&samples).unwrap();
+//! let config = fit.to_config();
+//! assert!(config.chars_per_second > 10.0 && config.chars_per_second < 15.0);
+//! ```
+
+use crate::pacing_engine::{MeditationPacer, PacingConfig, PunctuationType};
+use crate::prelude::*;
+
+// ============================================
+// Types
+// ============================================
+
+/// Outcome of fitting speech-rate constants to measured samples
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationResult {
+    /// Fitted speech rate (chars per second, excluding whitespace)
+    pub chars_per_second: f64,
+    /// Fitted seconds of inherent TTS pause per punctuation class (empty
+    /// unless fitted with `fit_with_punctuation`)
+    pub punctuation_latency: HashMap<PunctuationType, f64>,
+    /// Root-mean-square error of the fit in seconds
+    pub rmse_seconds: f64,
+    /// Number of samples used
+    pub sample_count: usize,
+}
+
+impl CalibrationResult {
+    /// Apply the fitted rate on top of `base`
+    pub fn apply_to(&self, base: PacingConfig) -> PacingConfig {
+        PacingConfig {
+            chars_per_second: self.chars_per_second,
+            language: None,
+            ..base
+        }
+    }
+
+    /// Default configuration with the fitted rate
+    pub fn to_config(&self) -> PacingConfig {
+        self.apply_to(PacingConfig::default())
+    }
+}
+
+/// Per-sample features: whitespace-free chars and internal punctuation counts
+struct SampleFeatures {
+    chars: f64,
+    punctuation: HashMap<PunctuationType, f64>,
+}
+
+// ============================================
+// Fitting
+// ============================================
+
+/// Fit chars-per-second from `(text, measured speech seconds)` samples
+/// 
+/// Returns `None` if there are no usable samples (all empty text).
+pub fn fit(samples: &[(&str, f64)]) -> Option<CalibrationResult> {
+    let features = extract_features(samples);
+    let rows: Vec<Vec<f64>> = features.iter().map(|f| vec![f.chars]).collect();
+    let y: Vec<f64> = samples.iter().map(|(_, s)| *s).collect();
+    
+    let coefficients = solve_least_squares(&rows, &y)?;
+    finish(coefficients[0], HashMap::new(), &rows, &coefficients, &y)
+}
+
+/// Fit chars-per-second plus a fixed latency per punctuation class
+/// 
+/// Only punctuation classes that occur inside some sample (not at the very
+/// end) get a latency. Falls back to `None` if the system is singular.
+pub fn fit_with_punctuation(samples: &[(&str, f64)]) -> Option<CalibrationResult> {
+    let features = extract_features(samples);
+    let classes: Vec<PunctuationType> = PunctuationType::PAUSING
+        .iter()
+        .copied()
+        .filter(|p| features.iter().any(|f| f.punctuation.get(p).copied().unwrap_or(0.0) > 0.0))
+        .collect();
+    
+    let rows: Vec<Vec<f64>> = features.iter()
+        .map(|f| {
+            let mut row = vec![f.chars];
+            row.extend(classes.iter().map(|p| f.punctuation.get(p).copied().unwrap_or(0.0)));
+            row
+        })
+        .collect();
+    let y: Vec<f64> = samples.iter().map(|(_, s)| *s).collect();
+    
+    let coefficients = solve_least_squares(&rows, &y)?;
+    let latency = classes.iter()
+        .zip(&coefficients[1..])
+        .map(|(p, c)| (*p, *c))
+        .collect();
+    finish(coefficients[0], latency, &rows, &coefficients, &y)
+}
+
+// ============================================
+// Helper Functions
+// ============================================
+
+/// Atomize each sample with default rules and count its features
+fn extract_features(samples: &[(&str, f64)]) -> Vec<SampleFeatures> {
+    let pacer = MeditationPacer::new();
+    samples.iter()
+        .map(|(text, _)| {
+            let atoms = pacer.atomize(text);
+            let mut punctuation = HashMap::new();
+            // Trailing punctuation adds no measurable time to the clip
+            for atom in atoms.iter().take(atoms.len().saturating_sub(1)) {
+                *punctuation.entry(atom.punctuation).or_insert(0.0) += 1.0;
+            }
+            SampleFeatures {
+                chars: atoms.iter().map(|a| a.char_count()).sum::<usize>() as f64,
+                punctuation,
+            }
+        })
+        .collect()
+}
+
+/// Turn fitted seconds-per-char into a result with error statistics
+fn finish(
+    seconds_per_char: f64,
+    punctuation_latency: HashMap<PunctuationType, f64>,
+    rows: &[Vec<f64>],
+    coefficients: &[f64],
+    y: &[f64],
+) -> Option<CalibrationResult> {
+    if seconds_per_char <= 0.0 || !seconds_per_char.is_finite() {
+        return None;
+    }
+    let squared_error: f64 = rows.iter()
+        .zip(y)
+        .map(|(row, actual)| {
+            let predicted: f64 = row.iter().zip(coefficients).map(|(x, c)| x * c).sum();
+            (predicted - actual).powi(2)
+        })
+        .sum();
+    
+    Some(CalibrationResult {
+        chars_per_second: 1.0 / seconds_per_char,
+        punctuation_latency,
+        rmse_seconds: (squared_error / rows.len() as f64).sqrt(),
+        sample_count: rows.len(),
+    })
+}
+
+/// Ordinary least squares (no intercept) via the normal equations
+/// 
+/// Gaussian elimination with partial pivoting; `None` if singular.
+fn solve_least_squares(rows: &[Vec<f64>], y: &[f64]) -> Option<Vec<f64>> {
+    let n = rows.first()?.len();
+    
+    // Augmented matrix [XᵀX | Xᵀy]
+    let mut m = vec![vec![0.0; n + 1]; n];
+    for (row, target) in rows.iter().zip(y) {
+        for i in 0..n {
+            for j in 0..n {
+                m[i][j] += row[i] * row[j];
+            }
+            m[i][n] += row[i] * target;
+        }
+    }
+    
+    for col in 0..n {
+        let pivot = (col..n).max_by(|a, b| m[*a][col].abs().total_cmp(&m[*b][col].abs()))?;
+        if m[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        m.swap(col, pivot);
+        let pivot_row = m[col].clone();
+        for (r, row) in m.iter_mut().enumerate() {
+            if r != col {
+                let factor = row[col] / pivot_row[col];
+                for (x, p) in row.iter_mut().zip(&pivot_row).skip(col) {
+                    *x -= factor * p;
+                }
+            }
+        }
+    }
+    
+    Some((0..n).map(|i| m[i][n] / m[i][i]).collect())
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_recovers_rate() {
+        // Synthetic samples spoken at exactly 10 chars/sec
+        let texts = ["Welcome. Take a deep breath.", "Relax your shoulders.", "Notice the quiet around you."];
+        let samples: Vec<(&str, f64)> = texts.iter()
+            .map(|t| (*t, t.chars().filter(|c| c.is_alphanumeric()).count() as f64 / 10.0))
+            .collect();
+        
+        let fit = fit(&samples).unwrap();
+        assert!((fit.chars_per_second - 10.0).abs() < 1e-9);
+        assert!(fit.rmse_seconds < 1e-9);
+        assert_eq!(fit.sample_count, 3);
+        assert_eq!(fit.to_config().chars_per_second, fit.chars_per_second);
+    }
+
+    #[test]
+    fn test_fit_with_punctuation_latency() {
+        // 12 chars/sec plus 0.5s per sentence break and 0.2s per comma
+        let samples = [
+            ("Rest here. Breathe.", 15.0 / 12.0 + 0.5),
+            ("Soft, slow, easy.", 12.0 / 12.0 + 0.4),
+            ("Calm. Quiet, still. Rest.", 18.0 / 12.0 + 1.0 + 0.2),
+            ("Let go now", 8.0 / 12.0),
+        ];
+        let fit = fit_with_punctuation(&samples).unwrap();
+        assert!((fit.chars_per_second - 12.0).abs() < 1e-6);
+        assert!((fit.punctuation_latency[&PunctuationType::SentenceEnd] - 0.5).abs() < 1e-6);
+        assert!((fit.punctuation_latency[&PunctuationType::Comma] - 0.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fit_without_samples() {
+        assert!(fit(&[]).is_none());
+        assert!(fit(&[("", 1.0)]).is_none());
+    }
+}
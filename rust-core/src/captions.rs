@@ -0,0 +1,187 @@
+//! Caption Export
+//! 
+//! Turns the estimated atom timeline of a `PacingResult` into subtitle
+//! files, so spoken meditations can show on-screen text in sync with the
+//! audio. Each atom becomes one caption, shown while the atom is spoken and
+//! hidden during the pause that follows it.
+//! 
+//! Two formats are supported: SubRip (`to_srt`) and WebVTT (`to_vtt`),
+//! the latter for the web player's `<track>` element with optional cue
+//! settings and a `STYLE` block.
+//! 
+//! Timings are estimates from the pacer's speech model; re-pace with
+//! measured speech (`repace_with_measured`) for tighter captions.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::MeditationPacer;
+//! 
+//! let result = MeditationPacer::new().calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+//! let srt = result.to_srt();
+//! assert!(srt.starts_with("1\n00:00:00,000 --> "));
+//! ```
+
+use crate::pacing_engine::{PacingResult, TimelineEntry};
+use crate::prelude::*;
+
+// ============================================
+// Types
+// ============================================
+
+/// Optional styling for WebVTT output
+#[derive(Debug, Clone, Default)]
+pub struct VttOptions {
+    /// Cue settings appended to every timing line (e.g. `line:85% align:center`)
+    pub cue_settings: Option<String>,
+    /// CSS emitted in a `STYLE` block before the first cue (e.g. `::cue { color: white; }`)
+    pub style: Option<String>,
+}
+
+// ============================================
+// Export
+// ============================================
+
+impl PacingResult {
+    /// Render the timeline as SubRip (`.srt`) captions
+    pub fn to_srt(&self) -> String {
+        let mut out = String::new();
+
+        for (number, entry) in self.captioned_entries().enumerate() {
+            if number > 0 {
+                out.push('\n');
+            }
+            out.push_str(&format!(
+                "{}\n{} --> {}\n{}\n",
+                number + 1,
+                format_timestamp(entry.start_seconds, ','),
+                format_timestamp(entry.speech_end_seconds(), ','),
+                entry.text.trim(),
+            ));
+        }
+
+        out
+    }
+
+    /// Render the timeline as WebVTT (`.vtt`) captions
+    pub fn to_vtt(&self) -> String {
+        self.to_vtt_with(&VttOptions::default())
+    }
+
+    /// Render the timeline as WebVTT with cue settings and styling
+    pub fn to_vtt_with(&self, options: &VttOptions) -> String {
+        let mut out = String::from("WEBVTT\n");
+
+        if let Some(style) = options.style.as_deref().filter(|s| !s.trim().is_empty()) {
+            out.push_str(&format!("\nSTYLE\n{}\n", style.trim()));
+        }
+
+        let settings = options.cue_settings.as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| format!(" {}", s))
+            .unwrap_or_default();
+
+        for (number, entry) in self.captioned_entries().enumerate() {
+            out.push_str(&format!(
+                "\n{}\n{} --> {}{}\n{}\n",
+                number + 1,
+                format_timestamp(entry.start_seconds, '.'),
+                format_timestamp(entry.speech_end_seconds(), '.'),
+                settings,
+                escape_vtt(entry.text.trim()),
+            ));
+        }
+
+        out
+    }
+
+    /// Timeline entries that carry visible text
+    fn captioned_entries(&self) -> impl Iterator<Item = &TimelineEntry> {
+        self.timeline.iter().filter(|entry| !entry.text.trim().is_empty())
+    }
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Format seconds as `HH:MM:SS<sep>mmm`
+fn format_timestamp(seconds: f64, millis_separator: char) -> String {
+    let total_millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    let millis = total_millis % 1000;
+    let total_seconds = total_millis / 1000;
+
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        total_seconds / 3600,
+        (total_seconds / 60) % 60,
+        total_seconds % 60,
+        millis_separator,
+        millis,
+    )
+}
+
+/// Escape characters that WebVTT cue text treats as markup
+fn escape_vtt(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MeditationPacer;
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0.0, ','), "00:00:00,000");
+        assert_eq!(format_timestamp(3723.4567, ','), "01:02:03,457");
+        assert_eq!(format_timestamp(59.9996, '.'), "00:01:00.000");
+    }
+
+    #[test]
+    fn test_srt_follows_timeline() {
+        let pacer = MeditationPacer::new();
+        let result = pacer.calculate_pacing("Breathe in. Hold it. Let go.".to_string(), 30.0);
+        let srt = result.to_srt();
+
+        let blocks: Vec<&str> = srt.split("\n\n").collect();
+        assert_eq!(blocks.len(), 3);
+
+        let second: Vec<&str> = blocks[1].lines().collect();
+        assert_eq!(second[0], "2");
+        let entry = &result.timeline[1];
+        assert_eq!(
+            second[1],
+            format!(
+                "{} --> {}",
+                format_timestamp(entry.start_seconds, ','),
+                format_timestamp(entry.speech_end_seconds(), ','),
+            )
+        );
+        assert_eq!(second[2], "Hold it");
+    }
+
+    #[test]
+    fn test_vtt_with_settings() {
+        let pacer = MeditationPacer::new();
+        let result = pacer.calculate_pacing("Breathe in. Hold it.".to_string(), 20.0);
+
+        let plain = result.to_vtt();
+        assert!(plain.starts_with("WEBVTT\n\n1\n00:00:00.000 --> "));
+        assert!(!plain.contains("STYLE"));
+
+        let styled = result.to_vtt_with(&VttOptions {
+            cue_settings: Some("line:85% align:center".to_string()),
+            style: Some("::cue { color: white; }".to_string()),
+        });
+        assert!(styled.starts_with("WEBVTT\n\nSTYLE\n::cue { color: white; }\n\n1\n"));
+        assert_eq!(styled.matches(" line:85% align:center\n").count(), 2);
+    }
+}
@@ -0,0 +1,317 @@
+//! Chunked SSML Output
+//! 
+//! TTS providers cap the size of a single request, so long sessions have
+//! to be synthesized in pieces. Chunking paces the whole script first and
+//! only then splits the rendered SSML at atom boundaries, so the silence
+//! distribution is the same as for an unchunked session.
+//! 
+//! ## Chunk Boundaries
+//! 
+//! - Chunks are filled greedily with whole atoms (each atom carries the
+//!   break tags that follow it) while they stay within the character limit
+//! - An atom that is longer than the limit on its own gets a chunk to
+//!   itself rather than being cut mid-phrase
+//! - With a `PacingConfig::break_budget`, a chunk also ends before its
+//!   break tags would exceed the per-request caps; the whole script is
+//!   paced without merging pauses, since chunking is what keeps it in
+//!   budget
+//! - The pause after the last atom of a chunk is placed according to
+//!   `BoundaryPause`: left in the SSML as break tags (the default), or
+//!   taken out of the SSML and reported as trailing silence on the chunk,
+//!   leading silence on the next chunk, or half of each. Whoever
+//!   concatenates the audio inserts that silence, so the total duration is
+//!   preserved exactly instead of depending on how a provider renders a
+//!   break at the very end of a request
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::MeditationPacer;
+//! 
+//! let text = "Breathe in. Hold it. Let go. Rest here.".to_string();
+//! let chunked = MeditationPacer::new().calculate_pacing_chunked(text, 20.0, 80);
+//! assert!(chunked.chunks.len() > 1);
+//! assert!(chunked.chunks.iter().all(|c| c.ssml.chars().count() <= 80));
+//! ```
+
+use crate::pacing_engine::{MeditationPacer, PacingResult};
+use crate::prelude::*;
+
+// ============================================
+// Types
+// ============================================
+
+/// Where the pause between two chunks goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoundaryPause {
+    /// Break tags at the end of the earlier chunk's SSML
+    #[default]
+    Inline,
+    /// Trailing silence metadata on the earlier chunk
+    Trailing,
+    /// Leading silence metadata on the later chunk
+    Leading,
+    /// Half trailing on the earlier chunk, half leading on the later one
+    Split,
+}
+
+/// One request-sized piece of a paced session
+#[derive(Debug, Clone, PartialEq)]
+pub struct SsmlChunk {
+    /// SSML for this chunk
+    pub ssml: String,
+    /// Index of the first atom in the chunk
+    pub first_atom: usize,
+    /// Number of atoms in the chunk
+    pub atom_count: usize,
+    /// Estimated offset where the chunk (including leading silence) starts
+    pub start_seconds: f64,
+    /// Silence to insert before the chunk's audio
+    pub leading_silence_seconds: f64,
+    /// Estimated speech time within the chunk
+    pub speech_seconds: f64,
+    /// Silence rendered as break tags within the chunk's SSML
+    pub silence_seconds: f64,
+    /// Silence to insert after the chunk's audio
+    pub trailing_silence_seconds: f64,
+}
+
+impl SsmlChunk {
+    /// Estimated total duration of the chunk, including inserted silence
+    pub fn duration_seconds(&self) -> f64 {
+        self.leading_silence_seconds
+            + self.speech_seconds
+            + self.silence_seconds
+            + self.trailing_silence_seconds
+    }
+}
+
+/// A paced session split into request-sized chunks
+#[derive(Debug, Clone)]
+pub struct ChunkedPacing {
+    /// Pacing for the session as a whole
+    pub result: PacingResult,
+    /// Chunks in playback order; with `BoundaryPause::Inline` their SSML
+    /// joined with spaces is `result.ssml`
+    pub chunks: Vec<SsmlChunk>,
+}
+
+// ============================================
+// Chunked Pacing
+// ============================================
+
+impl MeditationPacer {
+    /// Pace a script and split the SSML into chunks of at most
+    /// `max_chars_per_chunk` characters
+    /// 
+    /// Pauses between chunks stay in the SSML (`BoundaryPause::Inline`).
+    pub fn calculate_pacing_chunked(
+        &self,
+        text: String,
+        target_duration_seconds: f64,
+        max_chars_per_chunk: usize,
+    ) -> ChunkedPacing {
+        self.calculate_pacing_chunked_with(
+            text,
+            target_duration_seconds,
+            max_chars_per_chunk,
+            BoundaryPause::Inline,
+        )
+    }
+
+    /// Pace and chunk a script, placing pauses between chunks per `boundary`
+    pub fn calculate_pacing_chunked_with(
+        &self,
+        text: String,
+        target_duration_seconds: f64,
+        max_chars_per_chunk: usize,
+        boundary: BoundaryPause,
+    ) -> ChunkedPacing {
+        let atoms = self.atomize_text(&text);
+        let result = self.without_break_budget().calculate_pacing(text, target_duration_seconds);
+        
+        // The timeline holds the rendered pauses, which render to the same tags
+        let mut pauses: Vec<f64> = result.timeline.iter().map(|e| e.pause_seconds).collect();
+        let fragments = self.render_fragments(&atoms, &pauses);
+        let mut break_tags = self.break_tag_durations(&pauses);
+        if let Some(first) = break_tags.first_mut() {
+            first.extend(self.intro_break_tags());
+        }
+        let budget = self.config().break_budget;
+        
+        // Pack whole atoms greedily (sizes include their inline breaks)
+        let mut ranges: Vec<(usize, usize)> = Vec::new();
+        let mut current_chars = 0;
+        let mut current_tags = 0;
+        let mut current_break_seconds = 0.0;
+        for (i, fragment) in fragments.iter().enumerate() {
+            let fragment_chars = fragment.chars().count();
+            let fragment_tags = break_tags[i].len();
+            let fragment_break_seconds: f64 = break_tags[i].iter().sum();
+            match ranges.last_mut() {
+                Some((_, count))
+                    if current_chars + 1 + fragment_chars <= max_chars_per_chunk
+                        && budget.allows(current_tags + fragment_tags, current_break_seconds + fragment_break_seconds) =>
+                {
+                    *count += 1;
+                    current_chars += 1 + fragment_chars;
+                    current_tags += fragment_tags;
+                    current_break_seconds += fragment_break_seconds;
+                }
+                _ => {
+                    ranges.push((i, 1));
+                    current_chars = fragment_chars;
+                    current_tags = fragment_tags;
+                    current_break_seconds = fragment_break_seconds;
+                }
+            }
+        }
+        
+        // Take boundary pauses out of the SSML unless they stay inline; the
+        // last chunk ends with the outro, which always stays inline
+        let mut carried = vec![0.0; ranges.len()];
+        let fragments = if boundary == BoundaryPause::Inline {
+            fragments
+        } else {
+            for (k, (first, count)) in ranges.iter().enumerate().take(ranges.len().saturating_sub(1)) {
+                let last = first + count - 1;
+                carried[k] = pauses[last];
+                pauses[last] = 0.0;
+            }
+            self.render_fragments(&atoms, &pauses)
+        };
+        
+        let mut chunks = Vec::with_capacity(ranges.len());
+        let mut offset = 0.0;
+        for (k, (first, count)) in ranges.iter().copied().enumerate() {
+            let entries = &result.timeline[first..first + count];
+            let intro = if k == 0 { result.intro_pause_seconds } else { 0.0 };
+            let incoming = if k > 0 { carried[k - 1] } else { 0.0 };
+            let (leading, trailing) = match boundary {
+                BoundaryPause::Inline => (0.0, 0.0),
+                BoundaryPause::Trailing => (0.0, carried[k]),
+                BoundaryPause::Leading => (incoming, 0.0),
+                BoundaryPause::Split => (incoming / 2.0, carried[k] / 2.0),
+            };
+            
+            let chunk = SsmlChunk {
+                ssml: fragments[first..first + count].join(" "),
+                first_atom: first,
+                atom_count: count,
+                start_seconds: offset,
+                leading_silence_seconds: leading,
+                speech_seconds: entries.iter().map(|e| e.speech_seconds).sum(),
+                silence_seconds: intro + pauses[first..first + count].iter().sum::<f64>(),
+                trailing_silence_seconds: trailing,
+            };
+            offset += chunk.duration_seconds();
+            chunks.push(chunk);
+        }
+        
+        ChunkedPacing { result, chunks }
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pacing_engine::{BreakBudget, PacingConfig, PacingWarning};
+
+    #[test]
+    fn test_chunks_reassemble_result() {
+        let pacer = MeditationPacer::new();
+        let text = "Breathe in slowly. Hold it gently. Let it go. Feel the calm. Rest here now.";
+        let chunked = pacer.calculate_pacing_chunked(text.to_string(), 30.0, 100);
+        
+        assert!(chunked.chunks.len() > 1);
+        assert!(chunked.chunks.iter().all(|c| c.ssml.chars().count() <= 100));
+        
+        let joined: Vec<&str> = chunked.chunks.iter().map(|c| c.ssml.as_str()).collect();
+        assert_eq!(joined.join(" "), chunked.result.ssml);
+        
+        // Chunks tile the session without gaps
+        let mut offset = 0.0;
+        for chunk in &chunked.chunks {
+            assert!((chunk.start_seconds - offset).abs() < 1e-9);
+            offset += chunk.duration_seconds();
+        }
+        assert!((offset - chunked.result.estimated_total_seconds).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_oversized_atom_gets_own_chunk() {
+        let pacer = MeditationPacer::new();
+        let text = "Rest. Let every muscle in your body soften completely. Rest.";
+        let chunked = pacer.calculate_pacing_chunked(text.to_string(), 30.0, 20);
+        
+        let counts: Vec<usize> = chunked.chunks.iter().map(|c| c.atom_count).collect();
+        assert_eq!(counts, vec![1, 1, 1]);
+        assert_eq!(chunked.chunks[1].first_atom, 1);
+    }
+
+    #[test]
+    fn test_boundary_pause_carry_over() {
+        let pacer = MeditationPacer::new();
+        let text = "Rest. Let every muscle in your body soften completely. Rest.";
+        let inline = pacer.calculate_pacing_chunked(text.to_string(), 30.0, 20);
+        let boundary_pause = inline.result.timeline[0].pause_seconds;
+        
+        for mode in [BoundaryPause::Trailing, BoundaryPause::Leading, BoundaryPause::Split] {
+            let chunked = pacer.calculate_pacing_chunked_with(text.to_string(), 30.0, 20, mode);
+            let first = &chunked.chunks[0];
+            let second = &chunked.chunks[1];
+            
+            // The boundary pause leaves the SSML but not the timeline
+            assert!(!first.ssml.contains("<break"));
+            assert_eq!(first.silence_seconds, 0.0);
+            let carried = first.trailing_silence_seconds + second.leading_silence_seconds;
+            assert!((carried - boundary_pause).abs() < 1e-9);
+            
+            let total: f64 = chunked.chunks.iter().map(|c| c.duration_seconds()).sum();
+            assert!((total - chunked.result.estimated_total_seconds).abs() < 1e-9);
+            assert!((second.start_seconds - first.duration_seconds()).abs() < 1e-9);
+        }
+        
+        let split = pacer.calculate_pacing_chunked_with(text.to_string(), 30.0, 20, BoundaryPause::Split);
+        assert!((split.chunks[0].trailing_silence_seconds - boundary_pause / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_outro_stays_in_last_chunk() {
+        let config = PacingConfig { outro_pause_seconds: 10.0, ..PacingConfig::default() };
+        let pacer = MeditationPacer::with_config(config);
+        let text = "Rest. Let every muscle in your body soften completely. Rest.";
+        for mode in [BoundaryPause::Inline, BoundaryPause::Trailing, BoundaryPause::Leading, BoundaryPause::Split] {
+            let chunked = pacer.calculate_pacing_chunked_with(text.to_string(), 60.0, 20, mode);
+            let last = chunked.chunks.last().unwrap();
+            
+            assert_eq!(last.trailing_silence_seconds, 0.0);
+            assert!(last.silence_seconds >= 10.0);
+            assert!(last.ssml.contains("<break"));
+            let total: f64 = chunked.chunks.iter().map(|c| c.duration_seconds()).sum();
+            assert!((total - chunked.result.estimated_total_seconds).abs() < 1e-9, "{:?}", mode);
+        }
+    }
+
+    #[test]
+    fn test_chunks_stay_within_break_budget() {
+        let config = PacingConfig {
+            break_budget: BreakBudget { max_total_seconds: Some(10.0), max_tags: Some(5) },
+            ..PacingConfig::default()
+        };
+        let pacer = MeditationPacer::with_config(config);
+        let text = "Breathe in. Hold it. Let go. Feel the calm. Rest here. Soften. Release.";
+        let chunked = pacer.calculate_pacing_chunked(text.to_string(), 40.0, 10_000);
+        
+        assert!(chunked.chunks.len() > 1);
+        for chunk in &chunked.chunks {
+            assert!(chunk.ssml.matches("<break").count() <= 5);
+            assert!(chunk.silence_seconds <= 10.0 + 0.1);
+        }
+        assert!(chunked.result.warnings.iter().all(|w| !matches!(w, PacingWarning::BreaksMerged { .. })));
+    }
+}
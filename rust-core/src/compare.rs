@@ -0,0 +1,135 @@
+//! A/B Config Comparison
+//! 
+//! Paces one script under several configs and returns a compact summary of
+//! each, side by side, for calibration experiments: which config lands
+//! closest to the target, how much of the session is silence, and how long
+//! the longest pause gets.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::compare::compare_configs;
+//! use zenpal_core::PacingConfig;
+//! 
+//! let summaries = compare_configs(
+//!     "Breathe in. Hold it. Let go.",
+//!     30.0,
+//!     &[PacingConfig::default(), PacingConfig::sleep_story()],
+//! );
+//! assert_eq!(summaries.len(), 2);
+//! assert!(summaries[0].drift_seconds.abs() < 5.0);
+//! ```
+
+use crate::pacing_engine::{MeditationPacer, PacingConfig, PacingResult};
+use crate::prelude::*;
+
+// ============================================
+// Types
+// ============================================
+
+/// Headline numbers of one pacing result
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PacingSummary {
+    /// Estimated total duration
+    pub estimated_total_seconds: f64,
+    /// Estimated speech time
+    pub estimated_speech_seconds: f64,
+    /// Share of the estimated total that is silence (0.0 to 1.0)
+    pub silence_ratio: f64,
+    /// Longest rendered pause
+    pub longest_pause_seconds: f64,
+    /// Estimated total minus target (positive = runs long)
+    pub drift_seconds: f64,
+    /// Number of atoms followed by a rendered pause
+    pub break_count: usize,
+    /// Number of warnings on the result
+    pub warning_count: usize,
+}
+
+impl From<&PacingResult> for PacingSummary {
+    fn from(result: &PacingResult) -> Self {
+        let silence_ratio = if result.estimated_total_seconds > 0.0 {
+            result.total_silence_added / result.estimated_total_seconds
+        } else {
+            0.0
+        };
+        Self {
+            estimated_total_seconds: result.estimated_total_seconds,
+            estimated_speech_seconds: result.estimated_speech_seconds,
+            silence_ratio,
+            longest_pause_seconds: result.timeline.iter().map(|e| e.pause_seconds).fold(0.0, f64::max),
+            drift_seconds: result.estimated_total_seconds - result.target_duration_seconds,
+            break_count: result.timeline.iter().filter(|e| e.pause_seconds > 0.0).count(),
+            warning_count: result.warnings.len(),
+        }
+    }
+}
+
+// ============================================
+// Comparison
+// ============================================
+
+/// Pace `text` under every config, returning summaries in config order
+/// 
+/// Invalid configs are repaired the same way `MeditationPacer::with_config`
+/// repairs them.
+pub fn compare_configs(text: &str, target_duration_seconds: f64, configs: &[PacingConfig]) -> Vec<PacingSummary> {
+    configs.iter()
+        .map(|config| {
+            let result = MeditationPacer::with_config(config.clone())
+                .calculate_pacing(text.to_string(), target_duration_seconds);
+            PacingSummary::from(&result)
+        })
+        .collect()
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summaries_follow_configs() {
+        let text = "Breathe in. Hold it, gently. Let go.";
+        let tight = PacingConfig {
+            silence_safety_buffer: 1.0,
+            ..PacingConfig::default()
+        };
+        let summaries = compare_configs(text, 40.0, &[PacingConfig::default(), tight]);
+
+        // The 1.1x buffer runs long; without it the estimate is closer
+        assert!(summaries[0].drift_seconds > summaries[1].drift_seconds);
+        assert!(summaries[1].drift_seconds.abs() < 0.5);
+
+        let result = MeditationPacer::new().calculate_pacing(text.to_string(), 40.0);
+        let summary = PacingSummary::from(&result);
+        assert_eq!(summary, summaries[0]);
+        assert_eq!(summary.break_count, 3);
+        assert!(summary.silence_ratio > 0.5 && summary.silence_ratio < 1.0);
+        assert!(summary.longest_pause_seconds >= result.timeline[0].pause_seconds);
+    }
+
+    #[test]
+    fn test_invalid_configs_are_repaired() {
+        let broken = PacingConfig {
+            chars_per_second: -1.0,
+            ..PacingConfig::default()
+        };
+        let summaries = compare_configs("Breathe in. Let go.", 20.0, &[broken, PacingConfig::default()]);
+        assert_eq!(summaries[0], summaries[1]);
+
+        assert!(compare_configs("Breathe in.", 20.0, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_empty_script_summary() {
+        let summaries = compare_configs("", 20.0, &[PacingConfig::default()]);
+        assert_eq!(summaries[0].silence_ratio, 0.0);
+        assert_eq!(summaries[0].break_count, 0);
+        assert_eq!(summaries[0].longest_pause_seconds, 0.0);
+    }
+}
@@ -0,0 +1,143 @@
+//! Config Files
+//! 
+//! Load and save `PacingConfig` as JSON (with the `serde` feature) or TOML
+//! (with the `toml` feature), so calibration values can live in app config
+//! files and be reloaded without recompiling the core.
+//! 
+//! Fields missing from a file keep their defaults, and every loaded config
+//! is validated before it is returned.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::PacingConfig;
+//! 
+//! let config = PacingConfig::from_json_str(r#"{"chars_per_second": 11.5}"#).unwrap();
+//! assert_eq!(config.chars_per_second, 11.5);
+//! assert_eq!(config.max_break_seconds, PacingConfig::default().max_break_seconds);
+//! ```
+
+use std::fmt;
+
+use crate::pacing_engine::{ConfigError, PacingConfig};
+
+// ============================================
+// Types
+// ============================================
+
+/// Why a config file could not be loaded
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigFileError {
+    /// The file is not valid JSON/TOML or does not match the config shape
+    Parse(String),
+    /// The file parsed but the config failed validation
+    Invalid(Vec<ConfigError>),
+    /// The config cannot be written in the requested format
+    Serialize(String),
+}
+
+impl fmt::Display for ConfigFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigFileError::Parse(message) => write!(f, "could not parse config: {}", message),
+            ConfigFileError::Invalid(errors) => {
+                let reasons: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                write!(f, "invalid pacing config: {}", reasons.join("; "))
+            }
+            ConfigFileError::Serialize(message) => write!(f, "could not write config: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ConfigFileError {}
+
+// ============================================
+// Load / Save
+// ============================================
+
+impl PacingConfig {
+    /// Load a config from JSON
+    pub fn from_json_str(json: &str) -> Result<Self, ConfigFileError> {
+        let config: Self = serde_json::from_str(json)
+            .map_err(|e| ConfigFileError::Parse(e.to_string()))?;
+        config.validate().map_err(ConfigFileError::Invalid)?;
+        Ok(config)
+    }
+
+    /// Save the config as pretty-printed JSON
+    /// 
+    /// Fails only for configs that cannot be represented (a
+    /// `PauseCurve::Custom` progression).
+    pub fn to_json_string(&self) -> Result<String, ConfigFileError> {
+        serde_json::to_string_pretty(self).map_err(|e| ConfigFileError::Serialize(e.to_string()))
+    }
+
+    /// Load a config from TOML
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(source: &str) -> Result<Self, ConfigFileError> {
+        let config: Self = toml::from_str(source)
+            .map_err(|e| ConfigFileError::Parse(e.to_string()))?;
+        config.validate().map_err(ConfigFileError::Invalid)?;
+        Ok(config)
+    }
+
+    /// Save the config as TOML
+    #[cfg(feature = "toml")]
+    pub fn to_toml_string(&self) -> Result<String, ConfigFileError> {
+        toml::to_string(self).map_err(|e| ConfigFileError::Serialize(e.to_string()))
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use crate::{PauseCurve, ProgressiveCurve, PunctuationType, Preset};
+
+    #[test]
+    fn test_json_round_trip() {
+        let config = PacingConfig::preset(Preset::Focus);
+        let json = config.to_json_string().unwrap();
+        let loaded = PacingConfig::from_json_str(&json).unwrap();
+        assert_eq!(loaded.weight_for(PunctuationType::SentenceEnd), 2);
+        assert_eq!(loaded.chars_per_second, config.chars_per_second);
+
+        assert!(matches!(PacingConfig::from_json_str("{"), Err(ConfigFileError::Parse(_))));
+        assert!(matches!(
+            PacingConfig::from_json_str(r#"{"max_break_seconds": 0.0}"#),
+            Err(ConfigFileError::Invalid(_))
+        ));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml_round_trip() {
+        let config = PacingConfig::preset(Preset::Sleep);
+        let source = config.to_toml_string().unwrap();
+        let loaded = PacingConfig::from_toml_str(&source).unwrap();
+        assert_eq!(loaded.weight_for(PunctuationType::Paragraph), 8);
+        assert_eq!(loaded.prosody_ramp, config.prosody_ramp);
+
+        let partial = PacingConfig::from_toml_str("chars_per_second = 10.0\n").unwrap();
+        assert_eq!(partial.chars_per_second, 10.0);
+    }
+
+    #[test]
+    fn test_custom_curve_cannot_be_saved() {
+        let config = PacingConfig {
+            progression: Some(ProgressiveCurve::shaped(1.0, 2.0, PauseCurve::Custom(Arc::new(|t| t)))),
+            ..PacingConfig::default()
+        };
+        let error = config.to_json_string().unwrap_err();
+        assert!(matches!(error, ConfigFileError::Serialize(_)));
+        assert!(error.to_string().starts_with("could not write config: "));
+
+        let error = PacingConfig::from_json_str(r#"{"max_break_seconds": 0.0}"#).unwrap_err();
+        assert!(error.to_string().starts_with("invalid pacing config: "));
+    }
+}
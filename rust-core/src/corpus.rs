@@ -0,0 +1,139 @@
+//! Benchmark Corpus
+//! 
+//! Deterministic meditation scripts of fixed sizes for benchmarking the
+//! pacing hot paths (atomization, silence distribution, SSML rendering),
+//! so regex and string-building changes can be measured on inputs shaped
+//! like production scripts: mixed punctuation, ellipses, dashes, and a
+//! paragraph break every few sentences.
+//! 
+//! The crate's criterion benches (`cargo bench`) run over `bench_corpus()`.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::corpus::bench_corpus;
+//! 
+//! let corpus = bench_corpus();
+//! assert_eq!(corpus.len(), 3);
+//! assert_eq!(corpus[0].text.split_whitespace().count(), corpus[0].word_count);
+//! ```
+
+use crate::pacing_engine::TARGET_WORDS_PER_MINUTE;
+use crate::prelude::*;
+
+/// Word counts of the scripts in `bench_corpus`
+pub const BENCH_WORD_COUNTS: [usize; 3] = [100, 1_000, 10_000];
+
+/// Sentences the generated scripts cycle through
+const SENTENCES: [&str; 12] = [
+    "Welcome to this moment of stillness.",
+    "Find a comfortable position, and let your eyes gently close.",
+    "Breathe in slowly... and let it go.",
+    "Notice the weight of your body resting against the ground.",
+    "There is nowhere else you need to be; nothing else you need to do.",
+    "Let your shoulders soften — and your jaw release.",
+    "With each breath, feel yourself sinking a little deeper.",
+    "If your mind wanders, simply return to the breath.",
+    "Feel the air, cool as it enters, warm as it leaves…",
+    "Rest here for a while.",
+    "Is there any tension left in your hands?",
+    "Allow it to melt away, one breath at a time.",
+];
+
+/// Sentences per paragraph
+const SENTENCES_PER_PARAGRAPH: usize = 5;
+
+// ============================================
+// Types
+// ============================================
+
+/// One script of the benchmark corpus
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchScript {
+    /// Short label for benchmark IDs (e.g. "1k")
+    pub name: &'static str,
+    /// Whitespace-separated words in `text`
+    pub word_count: usize,
+    /// The script
+    pub text: String,
+    /// Target duration at 70 words per minute of session
+    pub target_duration_seconds: f64,
+}
+
+// ============================================
+// Corpus
+// ============================================
+
+/// The 100-, 1,000-, and 10,000-word benchmark scripts
+pub fn bench_corpus() -> Vec<BenchScript> {
+    let names = ["100", "1k", "10k"];
+    names.iter()
+        .zip(BENCH_WORD_COUNTS)
+        .map(|(name, word_count)| BenchScript {
+            name,
+            word_count,
+            text: generate_script(word_count),
+            target_duration_seconds: word_count as f64 / TARGET_WORDS_PER_MINUTE * 60.0,
+        })
+        .collect()
+}
+
+/// A script of exactly `word_count` words built from the corpus sentences
+/// 
+/// The last sentence is cut short to hit the count.
+pub fn generate_script(word_count: usize) -> String {
+    let mut out = String::new();
+    let mut words = 0;
+    for (i, sentence) in SENTENCES.iter().cycle().enumerate() {
+        if words >= word_count {
+            break;
+        }
+        if i > 0 {
+            out.push_str(if i % SENTENCES_PER_PARAGRAPH == 0 { "\n\n" } else { " " });
+        }
+        let take = (word_count - words).min(sentence.split_whitespace().count());
+        out.push_str(&sentence.split_whitespace().take(take).collect::<Vec<_>>().join(" "));
+        words += take;
+    }
+    out
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pacing_engine::MeditationPacer;
+
+    #[test]
+    fn test_corpus_sizes() {
+        for script in bench_corpus() {
+            assert_eq!(script.text.split_whitespace().count(), script.word_count, "{}", script.name);
+        }
+        assert_eq!(generate_script(0), "");
+        assert_eq!(generate_script(3), "Welcome to this");
+
+        let pacer = MeditationPacer::new();
+        let script = generate_script(1_000);
+        let atoms = pacer.atomize(&script);
+        let pauses = pacer.distribute_pauses(&atoms, 900.0);
+        let result = pacer.calculate_pacing(script, 900.0);
+        assert_eq!(pacer.render_atoms(&atoms, &pauses), result.ssml);
+    }
+
+    #[test]
+    fn test_corpus_shape() {
+        let corpus = bench_corpus();
+        assert_eq!(corpus, bench_corpus());
+
+        let small = &corpus[0];
+        assert!((small.target_duration_seconds - 100.0 / 70.0 * 60.0).abs() < 1e-9);
+        // Five-sentence paragraphs of 46 and 42 words, then the rest
+        assert_eq!(small.text.matches("\n\n").count(), 2);
+        for mark in ["...", "—", ";", "?", "…"] {
+            assert!(corpus[1].text.contains(mark), "{}", mark);
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Result Diffing
+//! 
+//! Compares two pacing results (e.g. before and after tuning a config or
+//! editing a script) as data instead of two large SSML strings: how the
+//! totals moved, which atoms were kept, added, or removed, and which kept
+//! atoms had their pause changed.
+//! 
+//! ## Alignment
+//! 
+//! Atoms are aligned by their spoken text with a longest common
+//! subsequence, so an inserted sentence shows up as one added atom rather
+//! than shifting every atom after it. Pauses are compared only between
+//! aligned atoms; a change smaller than `BREAK_TOLERANCE_SECONDS` (below
+//! what a break tag can render) is not reported.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::MeditationPacer;
+//! 
+//! let pacer = MeditationPacer::new();
+//! let before = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+//! let after = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 30.0);
+//! let diff = before.diff(&after);
+//! assert!((diff.duration_delta_seconds - 10.0).abs() < 1.5);
+//! assert_eq!(diff.changed_breaks.len(), 1);
+//! ```
+
+use crate::pacing_engine::PacingResult;
+use crate::prelude::*;
+
+/// Smallest pause change reported as a changed break
+pub const BREAK_TOLERANCE_SECONDS: f64 = 0.005;
+
+// ============================================
+// Types
+// ============================================
+
+/// How one atom lines up between two results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtomAlignment {
+    /// The same text in both, at these atom indices
+    Kept {
+        /// Index in the original result
+        before: usize,
+        /// Index in the other result
+        after: usize,
+    },
+    /// Only in the original result
+    Removed {
+        /// Index in the original result
+        before: usize,
+    },
+    /// Only in the other result
+    Added {
+        /// Index in the other result
+        after: usize,
+    },
+}
+
+/// A kept atom whose following pause changed
+#[derive(Debug, Clone, PartialEq)]
+pub struct BreakChange {
+    /// Spoken text of the atom
+    pub text: String,
+    /// Index in the original result
+    pub before_index: usize,
+    /// Index in the other result
+    pub after_index: usize,
+    /// Pause after the atom in the original result
+    pub before_seconds: f64,
+    /// Pause after the atom in the other result
+    pub after_seconds: f64,
+}
+
+impl BreakChange {
+    /// How much longer the pause got (negative if shorter)
+    pub fn delta_seconds(&self) -> f64 {
+        self.after_seconds - self.before_seconds
+    }
+}
+
+/// Structured comparison of two pacing results
+#[derive(Debug, Clone, PartialEq)]
+pub struct PacingDiff {
+    /// Change in estimated total duration
+    pub duration_delta_seconds: f64,
+    /// Change in estimated speech time
+    pub speech_delta_seconds: f64,
+    /// Change in rendered silence
+    pub silence_delta_seconds: f64,
+    /// Every atom of both results, in playback order
+    pub alignment: Vec<AtomAlignment>,
+    /// Kept atoms whose pause changed, in order
+    pub changed_breaks: Vec<BreakChange>,
+}
+
+impl PacingDiff {
+    /// Whether any atom was added or removed
+    pub fn text_changed(&self) -> bool {
+        self.alignment.iter().any(|a| !matches!(a, AtomAlignment::Kept { .. }))
+    }
+
+    /// Whether the results are the same (same atoms, same breaks)
+    pub fn is_empty(&self) -> bool {
+        !self.text_changed() && self.changed_breaks.is_empty()
+    }
+}
+
+// ============================================
+// Diffing
+// ============================================
+
+impl PacingResult {
+    /// Compare this result (before) with `other` (after)
+    pub fn diff(&self, other: &PacingResult) -> PacingDiff {
+        let before: Vec<&str> = self.timeline.iter().map(|e| e.text.as_str()).collect();
+        let after: Vec<&str> = other.timeline.iter().map(|e| e.text.as_str()).collect();
+        let alignment = align(&before, &after);
+
+        let changed_breaks = alignment.iter()
+            .filter_map(|a| match *a {
+                AtomAlignment::Kept { before, after } => Some((before, after)),
+                _ => None,
+            })
+            .filter_map(|(b, a)| {
+                let before_seconds = self.timeline[b].pause_seconds;
+                let after_seconds = other.timeline[a].pause_seconds;
+                ((after_seconds - before_seconds).abs() >= BREAK_TOLERANCE_SECONDS).then(|| BreakChange {
+                    text: self.timeline[b].text.clone(),
+                    before_index: b,
+                    after_index: a,
+                    before_seconds,
+                    after_seconds,
+                })
+            })
+            .collect();
+
+        PacingDiff {
+            duration_delta_seconds: other.estimated_total_seconds - self.estimated_total_seconds,
+            speech_delta_seconds: other.estimated_speech_seconds - self.estimated_speech_seconds,
+            silence_delta_seconds: other.total_silence_added - self.total_silence_added,
+            alignment,
+            changed_breaks,
+        }
+    }
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Longest-common-subsequence alignment of two atom lists
+fn align(before: &[&str], after: &[&str]) -> Vec<AtomAlignment> {
+    let (n, m) = (before.len(), after.len());
+
+    // lengths[i][j]: LCS length of before[i..] and after[j..]
+    let mut lengths = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i][j] = if before[i] == after[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if before[i] == after[j] {
+            out.push(AtomAlignment::Kept { before: i, after: j });
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            out.push(AtomAlignment::Removed { before: i });
+            i += 1;
+        } else {
+            out.push(AtomAlignment::Added { after: j });
+            j += 1;
+        }
+    }
+    out.extend((i..n).map(|before| AtomAlignment::Removed { before }));
+    out.extend((j..m).map(|after| AtomAlignment::Added { after }));
+    out
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MeditationPacer;
+
+    #[test]
+    fn test_inserted_sentence_aligns() {
+        let pacer = MeditationPacer::new();
+        let before = pacer.calculate_pacing("Breathe in. Hold it. Let go.".to_string(), 30.0);
+        let after = pacer.calculate_pacing("Breathe in. Hold it. Feel the calm. Let go.".to_string(), 30.0);
+        let diff = before.diff(&after);
+
+        assert_eq!(diff.alignment, vec![
+            AtomAlignment::Kept { before: 0, after: 0 },
+            AtomAlignment::Kept { before: 1, after: 1 },
+            AtomAlignment::Added { after: 2 },
+            AtomAlignment::Kept { before: 2, after: 3 },
+        ]);
+        assert!(diff.text_changed());
+        assert!(diff.speech_delta_seconds > 0.0);
+        // The same silence budget is now shared by three pauses
+        assert_eq!(diff.changed_breaks.len(), 2);
+        assert!(diff.changed_breaks.iter().all(|b| b.delta_seconds() < 0.0));
+    }
+
+    #[test]
+    fn test_identical_results() {
+        let pacer = MeditationPacer::new();
+        let result = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+        let diff = result.diff(&result.clone());
+        assert!(diff.is_empty());
+        assert_eq!(diff.duration_delta_seconds, 0.0);
+
+        assert_eq!(align(&["a"], &[]), vec![AtomAlignment::Removed { before: 0 }]);
+    }
+
+    #[test]
+    fn test_reworded_atom_is_removed_and_added() {
+        assert_eq!(align(&["a", "b", "c"], &["a", "x", "c"]), vec![
+            AtomAlignment::Kept { before: 0, after: 0 },
+            AtomAlignment::Removed { before: 1 },
+            AtomAlignment::Added { after: 1 },
+            AtomAlignment::Kept { before: 2, after: 2 },
+        ]);
+        assert_eq!(align(&[], &["a"]), vec![AtomAlignment::Added { after: 0 }]);
+    }
+
+    #[test]
+    fn test_longer_target_changes_only_breaks() {
+        let pacer = MeditationPacer::new();
+        let short = pacer.calculate_pacing("Breathe in. Hold it. Let go.".to_string(), 20.0);
+        let long = pacer.calculate_pacing("Breathe in. Hold it. Let go.".to_string(), 40.0);
+        let diff = short.diff(&long);
+
+        assert!(!diff.text_changed());
+        assert!(!diff.is_empty());
+        assert_eq!(diff.speech_delta_seconds, 0.0);
+        assert_eq!(diff.changed_breaks.len(), 2);
+        let added: f64 = diff.changed_breaks.iter().map(BreakChange::delta_seconds).sum();
+        assert!((added - diff.silence_delta_seconds).abs() < 1e-9);
+    }
+}
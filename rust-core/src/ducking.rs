@@ -0,0 +1,205 @@
+//! Background Music Ducking
+//! 
+//! Derives a volume envelope for a background soundscape from the pacing
+//! plan: music is ducked while the guide speaks and restored during the
+//! pauses, so the player can automate the soundscape volume without
+//! analysing the voice track.
+//! 
+//! ## Envelope
+//! 
+//! - Speech intervals come from the estimated timeline; each ducked
+//!   interval starts `fade_seconds` early so the music is down by the time
+//!   speech starts
+//! - Pauses shorter than `min_restore_seconds` stay ducked (pumping the
+//!   volume between clauses is distracting)
+//! - Each segment carries a fade hint: how long the ramp into its level
+//!   takes, starting at the segment start
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::{MeditationPacer, MusicLevel};
+//! 
+//! let result = MeditationPacer::new().calculate_pacing("Breathe in. Let go.".to_string(), 30.0);
+//! let envelope = result.music_envelope();
+//! assert_eq!(envelope[0].level, MusicLevel::Ducked);
+//! assert_eq!(envelope[1].level, MusicLevel::Full);
+//! ```
+
+use crate::pacing_engine::PacingResult;
+use crate::prelude::*;
+
+// ============================================
+// Types
+// ============================================
+
+/// Background music level over an interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MusicLevel {
+    /// Lowered under speech
+    Ducked,
+    /// Restored during silence
+    Full,
+}
+
+/// One interval of the music envelope
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnvelopeSegment {
+    /// Level to hold over the interval
+    pub level: MusicLevel,
+    /// Offset where the interval (and its fade) starts, in seconds
+    pub start_seconds: f64,
+    /// Offset where the interval ends
+    pub end_seconds: f64,
+    /// Length of the ramp into `level` at the start of the interval
+    pub fade_seconds: f64,
+}
+
+/// Tuning for `PacingResult::music_envelope_with`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DuckingOptions {
+    /// Ramp length between levels
+    pub fade_seconds: f64,
+    /// Shortest pause that restores the music
+    pub min_restore_seconds: f64,
+}
+
+impl Default for DuckingOptions {
+    fn default() -> Self {
+        Self {
+            fade_seconds: 0.5,
+            min_restore_seconds: 2.0,
+        }
+    }
+}
+
+// ============================================
+// Envelope
+// ============================================
+
+impl PacingResult {
+    /// Music envelope with the default fade and restore threshold
+    pub fn music_envelope(&self) -> Vec<EnvelopeSegment> {
+        self.music_envelope_with(&DuckingOptions::default())
+    }
+
+    /// Music envelope covering the whole estimated session
+    /// 
+    /// Segments alternate between `Ducked` and `Full` and tile
+    /// `0..estimated_total_seconds` without gaps.
+    pub fn music_envelope_with(&self, options: &DuckingOptions) -> Vec<EnvelopeSegment> {
+        let fade = options.fade_seconds.max(0.0);
+        
+        // Speech intervals, ducked early by the fade and merged across
+        // pauses too short to restore
+        let mut ducked: Vec<(f64, f64)> = Vec::new();
+        for entry in self.timeline.iter().filter(|e| e.speech_seconds > 0.0) {
+            let start = (entry.start_seconds - fade).max(0.0);
+            let end = entry.speech_end_seconds();
+            match ducked.last_mut() {
+                Some(last) if start - last.1 < options.min_restore_seconds => last.1 = end,
+                _ => ducked.push((start, end)),
+            }
+        }
+        
+        let total = self.estimated_total_seconds.max(ducked.last().map_or(0.0, |d| d.1));
+        let mut envelope = Vec::with_capacity(ducked.len() * 2 + 1);
+        let mut offset = 0.0;
+        for (start, end) in ducked {
+            if start > offset {
+                envelope.push(segment(MusicLevel::Full, offset, start, fade));
+            }
+            envelope.push(segment(MusicLevel::Ducked, start, end, fade));
+            offset = end;
+        }
+        if total > offset {
+            envelope.push(segment(MusicLevel::Full, offset, total, fade));
+        }
+        envelope
+    }
+}
+
+/// Envelope segment with its fade clamped to half its length
+fn segment(level: MusicLevel, start_seconds: f64, end_seconds: f64, fade: f64) -> EnvelopeSegment {
+    EnvelopeSegment {
+        level,
+        start_seconds,
+        end_seconds,
+        fade_seconds: fade.min((end_seconds - start_seconds) / 2.0),
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MeditationPacer, PacingConfig};
+
+    #[test]
+    fn test_envelope_tiles_session() {
+        let pacer = MeditationPacer::new();
+        let result = pacer.calculate_pacing("Breathe in. Hold it. Let go.".to_string(), 30.0);
+        let envelope = result.music_envelope();
+        
+        let levels: Vec<MusicLevel> = envelope.iter().map(|s| s.level).collect();
+        assert_eq!(levels, vec![
+            MusicLevel::Ducked, MusicLevel::Full,
+            MusicLevel::Ducked, MusicLevel::Full,
+            MusicLevel::Ducked,
+        ]);
+        for pair in envelope.windows(2) {
+            assert_eq!(pair[0].end_seconds, pair[1].start_seconds);
+        }
+        assert_eq!(envelope[0].start_seconds, 0.0);
+        assert!((envelope[4].end_seconds - result.estimated_total_seconds).abs() < 1e-9);
+        
+        // Music is down before the second atom starts speaking
+        let second = &result.timeline[1];
+        assert!((envelope[2].start_seconds - (second.start_seconds - 0.5)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_short_pauses_stay_ducked() {
+        let pacer = MeditationPacer::new();
+        let result = pacer.calculate_pacing("Breathe in. Hold it. Let go.".to_string(), 30.0);
+        let options = DuckingOptions {
+            min_restore_seconds: 60.0,
+            ..DuckingOptions::default()
+        };
+        let envelope = result.music_envelope_with(&options);
+        assert_eq!(envelope.len(), 1);
+        assert_eq!(envelope[0].level, MusicLevel::Ducked);
+    }
+
+    #[test]
+    fn test_intro_pause_starts_full() {
+        let config = PacingConfig { intro_pause_seconds: 10.0, ..PacingConfig::default() };
+        let result = MeditationPacer::with_config(config).calculate_pacing("Breathe in. Let go.".to_string(), 40.0);
+        let envelope = result.music_envelope();
+
+        assert_eq!(envelope[0].level, MusicLevel::Full);
+        assert_eq!(envelope[0].start_seconds, 0.0);
+        assert!((envelope[1].start_seconds - (result.timeline[0].start_seconds - 0.5)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fades_fit_their_segments() {
+        let pacer = MeditationPacer::new();
+        let result = pacer.calculate_pacing("Breathe in. Hold it. Let go.".to_string(), 30.0);
+
+        let long_fade = DuckingOptions { fade_seconds: 100.0, min_restore_seconds: 0.0 };
+        for segment in result.music_envelope_with(&long_fade) {
+            assert!(segment.fade_seconds <= (segment.end_seconds - segment.start_seconds) / 2.0 + 1e-9);
+        }
+
+        let negative = DuckingOptions { fade_seconds: -1.0, ..DuckingOptions::default() };
+        let envelope = result.music_envelope_with(&negative);
+        assert!(envelope.iter().all(|segment| segment.fade_seconds == 0.0));
+        assert_eq!(envelope[2].start_seconds, result.timeline[1].start_seconds);
+    }
+}
@@ -0,0 +1,161 @@
+//! ElevenLabs Request Payloads
+//! 
+//! Builds the JSON body for ElevenLabs' text-to-speech endpoint from a
+//! `PacingResult`, so every consumer sends the same payload. Only the body
+//! is built here; the HTTP call (URL, voice id, API key header) stays with
+//! the caller. Available with the `elevenlabs` feature.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::elevenlabs::{TextToSpeechRequest, VoiceSettings};
+//! use zenpal_core::MeditationPacer;
+//! 
+//! let result = MeditationPacer::new().calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+//! let body = TextToSpeechRequest::from_result(&result)
+//!     .with_voice_settings(VoiceSettings::calm())
+//!     .to_json();
+//! assert!(body.contains("\"model_id\":\"eleven_multilingual_v2\""));
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::pacing_engine::PacingResult;
+
+/// Model used when none is set (supports SSML breaks in every language)
+pub const DEFAULT_MODEL_ID: &str = "eleven_multilingual_v2";
+
+// ============================================
+// Types
+// ============================================
+
+/// Voice settings sent with a request
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VoiceSettings {
+    /// Consistency between generations (0.0 - 1.0)
+    pub stability: f64,
+    /// Closeness to the original voice (0.0 - 1.0)
+    pub similarity_boost: f64,
+    /// Style exaggeration (0.0 - 1.0, 0.0 = none)
+    pub style: f64,
+    /// Boost similarity to the original speaker
+    pub use_speaker_boost: bool,
+    /// Speaking speed multiplier (provider default when unset)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub speed: Option<f64>,
+}
+
+impl VoiceSettings {
+    /// Steady, unexaggerated delivery suited to guided meditation
+    pub fn calm() -> Self {
+        Self {
+            stability: 0.75,
+            style: 0.0,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for VoiceSettings {
+    fn default() -> Self {
+        Self {
+            stability: 0.5,
+            similarity_boost: 0.75,
+            style: 0.0,
+            use_speaker_boost: true,
+            speed: None,
+        }
+    }
+}
+
+/// JSON body for a text-to-speech request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextToSpeechRequest {
+    /// SSML to synthesize
+    pub text: String,
+    /// Model to synthesize with
+    pub model_id: String,
+    /// Voice settings (the voice's stored settings when unset)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub voice_settings: Option<VoiceSettings>,
+    /// Text spoken before this request, for continuous prosody
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub previous_text: Option<String>,
+    /// Text spoken after this request, for continuous prosody
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub next_text: Option<String>,
+}
+
+impl TextToSpeechRequest {
+    /// Request synthesizing a paced result with the default model
+    pub fn from_result(result: &PacingResult) -> Self {
+        Self {
+            text: result.ssml.clone(),
+            model_id: DEFAULT_MODEL_ID.to_string(),
+            voice_settings: None,
+            previous_text: None,
+            next_text: None,
+        }
+    }
+
+    /// Use a different model
+    pub fn with_model_id(mut self, model_id: &str) -> Self {
+        self.model_id = model_id.to_string();
+        self
+    }
+
+    /// Send explicit voice settings
+    pub fn with_voice_settings(mut self, settings: VoiceSettings) -> Self {
+        self.voice_settings = Some(settings);
+        self
+    }
+
+    /// Set the surrounding text when this request is one part of a session
+    pub fn with_context(mut self, previous_text: Option<&str>, next_text: Option<&str>) -> Self {
+        self.previous_text = previous_text.map(str::to_string);
+        self.next_text = next_text.map(str::to_string);
+        self
+    }
+
+    /// Serialize the request body
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("request body is always serializable")
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MeditationPacer;
+
+    #[test]
+    fn test_request_body() {
+        let result = MeditationPacer::new().calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+        let request = TextToSpeechRequest::from_result(&result)
+            .with_model_id("eleven_turbo_v2_5")
+            .with_context(Some("Welcome."), None);
+        let body: serde_json::Value = serde_json::from_str(&request.to_json()).unwrap();
+
+        assert_eq!(body["text"], result.ssml.as_str());
+        assert_eq!(body["model_id"], "eleven_turbo_v2_5");
+        assert_eq!(body["previous_text"], "Welcome.");
+        assert!(body.get("next_text").is_none());
+        assert!(body.get("voice_settings").is_none());
+    }
+
+    #[test]
+    fn test_voice_settings_serialization() {
+        let result = MeditationPacer::new().calculate_pacing("Rest.".to_string(), 5.0);
+        let request = TextToSpeechRequest::from_result(&result)
+            .with_voice_settings(VoiceSettings::calm());
+        let body: serde_json::Value = serde_json::from_str(&request.to_json()).unwrap();
+
+        assert_eq!(body["voice_settings"]["stability"], 0.75);
+        assert_eq!(body["voice_settings"]["use_speaker_boost"], true);
+        assert!(body["voice_settings"].get("speed").is_none());
+    }
+}
@@ -0,0 +1,297 @@
+//! Playback Events
+//! 
+//! Flattens a pacing plan into an ordered playback script: speak this
+//! atom, pause, sound a cue, enter or leave a section. Clients that do not
+//! play the synthesized audio (text-guided mode, haptics, a visual breath
+//! guide) can drive a session from the same plan as the audio.
+//! 
+//! Offsets come from the estimated timeline. Events are ordered by offset;
+//! events at the same offset keep their logical order (a segment ends
+//! before the next one starts, an atom is spoken before its pause).
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::{MeditationPacer, PlaybackEvent};
+//! 
+//! let result = MeditationPacer::new().calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+//! let events = result.events();
+//! assert!(matches!(events[0], PlaybackEvent::SpeakAtom { atom_index: 0, .. }));
+//! assert!(matches!(events[1], PlaybackEvent::Pause { after_atom: 0, .. }));
+//! ```
+
+use crate::pacing_engine::PacingResult;
+use crate::prelude::*;
+use crate::session::ComposedSession;
+
+// ============================================
+// Types
+// ============================================
+
+/// One step of a playback script
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlaybackEvent {
+    /// A section or session segment begins
+    SegmentStart {
+        /// Section or segment name
+        name: String,
+        /// Offset in seconds
+        offset_seconds: f64,
+    },
+    /// Speak (or show) an atom
+    SpeakAtom {
+        /// Index of the atom
+        atom_index: usize,
+        /// Text of the atom
+        text: String,
+        /// Offset in seconds
+        offset_seconds: f64,
+        /// Estimated speaking time
+        duration_seconds: f64,
+    },
+    /// Silence after an atom
+    Pause {
+        /// Index of the atom the pause follows
+        after_atom: usize,
+        /// Offset in seconds
+        offset_seconds: f64,
+        /// Length of the pause
+        duration_seconds: f64,
+    },
+    /// A non-speech cue such as a bell
+    Cue {
+        /// Cue name
+        name: String,
+        /// Offset in seconds
+        offset_seconds: f64,
+        /// How long the cue sounds
+        duration_seconds: f64,
+        /// Audio for the cue, if any
+        audio_src: Option<String>,
+    },
+    /// A section or session segment ends
+    SegmentEnd {
+        /// Section or segment name
+        name: String,
+        /// Offset in seconds
+        offset_seconds: f64,
+    },
+}
+
+impl PlaybackEvent {
+    /// Offset of the event from the start of playback, in seconds
+    pub fn offset_seconds(&self) -> f64 {
+        match self {
+            PlaybackEvent::SegmentStart { offset_seconds, .. }
+            | PlaybackEvent::SpeakAtom { offset_seconds, .. }
+            | PlaybackEvent::Pause { offset_seconds, .. }
+            | PlaybackEvent::Cue { offset_seconds, .. }
+            | PlaybackEvent::SegmentEnd { offset_seconds, .. } => *offset_seconds,
+        }
+    }
+
+    /// The same event moved later by `seconds` with atom indices moved by
+    /// `atoms`
+    fn shifted(mut self, seconds: f64, atoms: usize) -> Self {
+        match &mut self {
+            PlaybackEvent::SpeakAtom { atom_index, .. } => *atom_index += atoms,
+            PlaybackEvent::Pause { after_atom, .. } => *after_atom += atoms,
+            _ => {}
+        }
+        match &mut self {
+            PlaybackEvent::SegmentStart { offset_seconds, .. }
+            | PlaybackEvent::SpeakAtom { offset_seconds, .. }
+            | PlaybackEvent::Pause { offset_seconds, .. }
+            | PlaybackEvent::Cue { offset_seconds, .. }
+            | PlaybackEvent::SegmentEnd { offset_seconds, .. } => *offset_seconds += seconds,
+        }
+        self
+    }
+}
+
+// ============================================
+// Event Generation
+// ============================================
+
+impl PacingResult {
+    /// The plan as an ordered list of playback events
+    /// 
+    /// Sections (from sectioned pacing) are bracketed by `SegmentStart` and
+    /// `SegmentEnd`; cues are placed at their own offsets inside pauses.
+    pub fn events(&self) -> Vec<PlaybackEvent> {
+        let mut events = Vec::with_capacity(self.timeline.len() * 2 + self.cues.len());
+        
+        // Atom index where each section starts
+        let mut section_starts = Vec::with_capacity(self.sections.len());
+        let mut first_atom = 0;
+        for section in &self.sections {
+            section_starts.push(first_atom);
+            first_atom += section.atom_count;
+        }
+        
+        for entry in &self.timeline {
+            for (section, _) in self.sections.iter()
+                .zip(&section_starts)
+                .filter(|(section, start)| **start == entry.atom_index && section.atom_count > 0)
+            {
+                events.push(PlaybackEvent::SegmentStart {
+                    name: section.name.clone(),
+                    offset_seconds: section.start_seconds,
+                });
+            }
+            
+            events.push(PlaybackEvent::SpeakAtom {
+                atom_index: entry.atom_index,
+                text: entry.text.clone(),
+                offset_seconds: entry.start_seconds,
+                duration_seconds: entry.speech_seconds,
+            });
+            if entry.pause_seconds > 0.0 {
+                events.push(PlaybackEvent::Pause {
+                    after_atom: entry.atom_index,
+                    offset_seconds: entry.speech_end_seconds(),
+                    duration_seconds: entry.pause_seconds,
+                });
+            }
+            for cue in self.cues.iter().filter(|cue| cue.after_atom == entry.atom_index) {
+                events.push(PlaybackEvent::Cue {
+                    name: cue.name.clone(),
+                    offset_seconds: cue.start_seconds,
+                    duration_seconds: cue.duration_seconds,
+                    audio_src: cue.audio_src.clone(),
+                });
+            }
+            
+            for (section, _) in self.sections.iter()
+                .zip(&section_starts)
+                .filter(|(section, start)| section.atom_count > 0 && **start + section.atom_count == entry.atom_index + 1)
+            {
+                events.push(PlaybackEvent::SegmentEnd {
+                    name: section.name.clone(),
+                    offset_seconds: section.start_seconds + section.duration_seconds(),
+                });
+            }
+        }
+        
+        // Stable, so events at equal offsets keep their logical order
+        events.sort_by(|a, b| a.offset_seconds().total_cmp(&b.offset_seconds()));
+        events
+    }
+}
+
+impl ComposedSession {
+    /// The whole session as playback events, each segment bracketed by
+    /// `SegmentStart` and `SegmentEnd`
+    pub fn events(&self) -> Vec<PlaybackEvent> {
+        let mut events = Vec::new();
+        let mut first_atom = 0;
+        
+        for segment in &self.segments {
+            events.push(PlaybackEvent::SegmentStart {
+                name: segment.name.clone(),
+                offset_seconds: segment.start_seconds,
+            });
+            events.extend(segment.result.events()
+                .into_iter()
+                .map(|event| event.shifted(segment.start_seconds, first_atom)));
+            events.push(PlaybackEvent::SegmentEnd {
+                name: segment.name.clone(),
+                offset_seconds: segment.end_seconds(),
+            });
+            first_atom += segment.result.atom_count;
+        }
+        
+        events
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bell, MeditationPacer, ScriptSection, SectionProfile, Session, SessionSegment};
+
+    #[test]
+    fn test_events_follow_timeline() {
+        let pacer = MeditationPacer::new();
+        let text = "Settle in. Breathe in. Breathe out. Rest here.".to_string();
+        let result = pacer.calculate_pacing_with_bells(text, 60.0, &[Bell::at(30.0, 3.0)]);
+        let events = result.events();
+        
+        let speaks = events.iter().filter(|e| matches!(e, PlaybackEvent::SpeakAtom { .. })).count();
+        let pauses = events.iter().filter(|e| matches!(e, PlaybackEvent::Pause { .. })).count();
+        assert_eq!(speaks, 4);
+        assert_eq!(pauses, 3);
+        
+        let cue = events.iter().position(|e| matches!(e, PlaybackEvent::Cue { .. })).unwrap();
+        assert!(matches!(events[cue - 1], PlaybackEvent::Pause { .. }));
+        assert!((events[cue].offset_seconds() - result.cues[0].start_seconds).abs() < 1e-9);
+        assert!(events.windows(2).all(|w| w[0].offset_seconds() <= w[1].offset_seconds()));
+    }
+
+    #[test]
+    fn test_segment_events() {
+        let pacer = MeditationPacer::new();
+        let sections = vec![
+            ScriptSection::new("intro", "Welcome. Settle in.", SectionProfile::default()),
+            ScriptSection::new("body", "Breathe in.", SectionProfile::default()),
+        ];
+        let events = pacer.calculate_sectioned(&sections, 60.0).events();
+        assert!(matches!(&events[0], PlaybackEvent::SegmentStart { name, .. } if name == "intro"));
+        assert!(matches!(events.last(), Some(PlaybackEvent::SegmentEnd { name, .. }) if name == "body"));
+        let body_start = events.iter()
+            .position(|e| matches!(e, PlaybackEvent::SegmentStart { name, .. } if name == "body"))
+            .unwrap();
+        assert!(matches!(&events[body_start - 1], PlaybackEvent::SegmentEnd { name, .. } if name == "intro"));
+        
+        let session = Session::new()
+            .segment(SessionSegment::new("intro", "Welcome. Settle in.", 20.0))
+            .segment(SessionSegment::new("body", "Breathe in.", 10.0));
+        let composed = session.compose();
+        let events = composed.events();
+        let last_speak = events.iter()
+            .rev()
+            .find(|e| matches!(e, PlaybackEvent::SpeakAtom { .. }))
+            .unwrap();
+        assert!(matches!(last_speak, PlaybackEvent::SpeakAtom { atom_index: 2, .. }));
+        assert_eq!(last_speak.offset_seconds(), composed.segments[1].start_seconds);
+    }
+
+    #[test]
+    fn test_empty_sections_are_not_bracketed() {
+        let pacer = MeditationPacer::new();
+        let sections = vec![
+            ScriptSection::new("intro", "Welcome.", SectionProfile::default()),
+            ScriptSection::new("empty", "", SectionProfile::default()),
+            ScriptSection::new("body", "Breathe in.", SectionProfile::default()),
+        ];
+        let events = pacer.calculate_sectioned(&sections, 30.0).events();
+        let names: Vec<&str> = events.iter()
+            .filter_map(|e| match e {
+                PlaybackEvent::SegmentStart { name, .. } => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(names, vec!["intro", "body"]);
+    }
+
+    #[test]
+    fn test_session_pauses_are_shifted() {
+        let session = Session::new()
+            .segment(SessionSegment::new("first", "Welcome. Settle in.", 20.0))
+            .segment(SessionSegment::new("second", "Breathe in. Let go.", 20.0));
+        let composed = session.compose();
+        let second = &composed.segments[1];
+        let pause = second.result.timeline[0].speech_end_seconds() + second.start_seconds;
+
+        let events = composed.events();
+        assert!(events.iter().any(|e| matches!(
+            e,
+            PlaybackEvent::Pause { after_atom: 2, offset_seconds, .. } if (*offset_seconds - pause).abs() < 1e-9
+        )));
+    }
+}
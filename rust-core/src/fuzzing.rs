@@ -0,0 +1,273 @@
+//! Fuzzing and Property Tests
+//! 
+//! `Arbitrary` configs plus invariant checks, for driving the atomizer and
+//! pacer with pathological input (combining marks, bidi controls, emoji
+//! sequences, runs of delimiters) from `cargo fuzz` or a property-test
+//! loop. Available with the `arbitrary` feature.
+//! 
+//! The invariants every pacing result must hold:
+//! 
+//! - **Non-negative silence**: every pause, the total silence, and the
+//!   estimated total are finite and non-negative
+//! - **SSML round-trip**: `verify_roundtrip` passes for the input text
+//!   (after sanitizing and banned-word filtering, with markers removed)
+//! 
+//! Generated configs cover the numeric and enum fields, including values
+//! `MeditationPacer::with_config` has to repair. `atom_pattern`, `markers`,
+//! and `banned_words` stay at their defaults: a custom pattern may
+//! legitimately drop text, which the round-trip check would flag.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::fuzzing::fuzz_pacing;
+//! 
+//! // A cargo-fuzz target is one line:
+//! // fuzz_target!(|data: &[u8]| fuzz_pacing(data).unwrap());
+//! assert!(fuzz_pacing("Breathe in… exhale — e\u{301}\u{200f}!!".as_bytes()).is_ok());
+//! ```
+
+use std::fmt;
+
+use arbitrary::{Arbitrary, Unstructured};
+
+use crate::locale::Locale;
+use crate::pacing_engine::{
+    AllocationStrategy, BreakFormat, DurationModel, Language, MeditationPacer, PacingConfig, PacingResult,
+    PauseCurve, PauseJitter, ProgressiveCurve, ProsodyRamp, PunctuationType,
+};
+use crate::roundtrip::{verify_roundtrip, RoundtripError};
+use crate::sanitize::SymbolHandling;
+
+/// Longest target duration a fuzzed case uses (one hour)
+const MAX_FUZZ_TARGET_SECONDS: f64 = 3600.0;
+
+// ============================================
+// Types
+// ============================================
+
+/// A broken pacing invariant
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvariantViolation {
+    /// A pause after an atom is negative or not finite
+    NegativePause {
+        /// Atom the pause follows
+        atom_index: usize,
+        /// The pause
+        seconds: f64,
+    },
+    /// A result total is negative or not finite
+    InvalidTotal {
+        /// Name of the `PacingResult` field
+        field: &'static str,
+        /// The total
+        seconds: f64,
+    },
+    /// The SSML does not speak the words of the input
+    Roundtrip(RoundtripError),
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NegativePause { atom_index, seconds } => {
+                write!(f, "pause after atom {} is {}s", atom_index, seconds)
+            }
+            Self::InvalidTotal { field, seconds } => write!(f, "{} is {}s", field, seconds),
+            Self::Roundtrip(err) => write!(f, "SSML does not round-trip: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for InvariantViolation {}
+
+// ============================================
+// Arbitrary
+// ============================================
+
+impl<'a> Arbitrary<'a> for PacingConfig {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut config = PacingConfig {
+            chars_per_second: in_range(u, -1.0, 30.0)?,
+            language: if u.arbitrary()? { Some(*u.choose(&Language::ALL)?) } else { None },
+            locale: if u.arbitrary()? { Some(Locale::for_language(*u.choose(&Language::ALL)?)) } else { None },
+            syllables_per_second: in_range(u, -1.0, 10.0)?,
+            duration_model: match u.int_in_range(0..=2)? {
+                0 => DurationModel::Characters,
+                1 => DurationModel::Syllables,
+                _ => DurationModel::WordsPerMinute(in_range(u, -10.0, 300.0)?),
+            },
+            silence_safety_buffer: in_range(u, 0.5, 2.0)?,
+            max_break_seconds: in_range(u, -1.0, 10.0)?,
+            min_break_seconds: in_range(u, -0.5, 3.0)?,
+            break_format: if u.arbitrary()? { BreakFormat::milliseconds() } else { BreakFormat::seconds(u.int_in_range(0..=3)?) },
+            redistribute_dropped_silence: u.arbitrary()?,
+            keep_quotes_intact: u.arbitrary()?,
+            soften_quote_pauses: u.arbitrary()?,
+            repeated_punctuation_multiplier: in_range(u, -1.0, 3.0)?,
+            intro_pause_seconds: in_range(u, -1.0, 20.0)?,
+            outro_pause_seconds: in_range(u, -1.0, 20.0)?,
+            strip_html: u.arbitrary()?,
+            symbols: match u.int_in_range(0..=2)? {
+                0 => SymbolHandling::Allow,
+                1 => SymbolHandling::Strip,
+                _ => SymbolHandling::Replace { with: ",".to_string() },
+            },
+            spell_out_numbers: u.arbitrary()?,
+            allocation: if u.arbitrary()? { AllocationStrategy::Weighted } else { AllocationStrategy::EqualPerSentence },
+            ..PacingConfig::default()
+        };
+        for punctuation in PunctuationType::PAUSING {
+            config.set_weight(punctuation, u.int_in_range(0..=10)?);
+        }
+        if u.arbitrary()? {
+            config.set_pause_floor(*u.choose(&PunctuationType::PAUSING)?, in_range(u, -1.0, 8.0)?);
+        }
+        if u.arbitrary()? {
+            let shape = match u.int_in_range(0..=4)? {
+                0 => PauseCurve::Linear,
+                1 => PauseCurve::EaseIn,
+                2 => PauseCurve::EaseOut,
+                3 => PauseCurve::Sigmoid,
+                _ => PauseCurve::Power(in_range(u, 0.1, 4.0)?),
+            };
+            config.progression = Some(ProgressiveCurve::shaped(in_range(u, 0.0, 3.0)?, in_range(u, 0.0, 3.0)?, shape));
+        }
+        if u.arbitrary()? {
+            config.jitter = Some(PauseJitter::new(in_range(u, -0.5, 1.5)?, u.arbitrary()?));
+        }
+        if u.arbitrary()? {
+            config.prosody_ramp = Some(ProsodyRamp::new(in_range(u, 0.0, 1.5)?, in_range(u, 0.0, 1.5)?));
+        }
+        for _ in 0..u.int_in_range(0..=2)? {
+            config.keyword_bonuses.insert(u.arbitrary()?, u.int_in_range(0..=5)?);
+        }
+        Ok(config)
+    }
+}
+
+// ============================================
+// Invariants
+// ============================================
+
+/// Check that no pause or total is negative or non-finite
+pub fn check_silence(result: &PacingResult) -> Result<(), InvariantViolation> {
+    let valid = |seconds: f64| seconds.is_finite() && seconds >= 0.0;
+    if let Some(entry) = result.timeline.iter().find(|e| !valid(e.pause_seconds)) {
+        return Err(InvariantViolation::NegativePause {
+            atom_index: entry.atom_index,
+            seconds: entry.pause_seconds,
+        });
+    }
+    let totals = [
+        ("total_silence_added", result.total_silence_added),
+        ("estimated_speech_seconds", result.estimated_speech_seconds),
+        ("estimated_total_seconds", result.estimated_total_seconds),
+    ];
+    match totals.into_iter().find(|(_, seconds)| !valid(*seconds)) {
+        Some((field, seconds)) => Err(InvariantViolation::InvalidTotal { field, seconds }),
+        None => Ok(()),
+    }
+}
+
+/// Check that the SSML speaks exactly the words of `text`
+/// 
+/// `pacer` must be the one that produced `result`, so the same sanitizing,
+/// banned-word filter, and markers apply.
+pub fn check_ssml_round_trip(pacer: &MeditationPacer, text: &str, result: &PacingResult) -> Result<(), InvariantViolation> {
+    let mut filtered = pacer.filter_words(&pacer.sanitize_text(text)).0.into_owned();
+    for marker in pacer.config().markers.keys() {
+        filtered = filtered.replace(marker.as_str(), " ");
+    }
+    verify_roundtrip(&filtered, &result.ssml).map_err(InvariantViolation::Roundtrip)
+}
+
+/// Pace `text` under `config` and check every invariant
+pub fn check_pacing(config: PacingConfig, text: &str, target_duration_seconds: f64) -> Result<PacingResult, InvariantViolation> {
+    let pacer = MeditationPacer::with_config(config);
+    let result = pacer.calculate_pacing(text.to_string(), target_duration_seconds);
+    check_silence(&result)?;
+    check_ssml_round_trip(&pacer, text, &result)?;
+    Ok(result)
+}
+
+/// Fuzz entry point: decode a config, script, and target from raw bytes
+/// and check every invariant
+/// 
+/// Inputs too short to decode are accepted as passing.
+pub fn fuzz_pacing(data: &[u8]) -> Result<(), InvariantViolation> {
+    let mut u = Unstructured::new(data);
+    let Ok(text) = <&str>::arbitrary(&mut u) else {
+        return Ok(());
+    };
+    let target = in_range(&mut u, 0.0, MAX_FUZZ_TARGET_SECONDS).unwrap_or(60.0);
+    let config = PacingConfig::arbitrary(&mut u).unwrap_or_default();
+    check_pacing(config, text, target).map(|_| ())
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// A float spread evenly over `lo..=hi`
+fn in_range(u: &mut Unstructured, lo: f64, hi: f64) -> arbitrary::Result<f64> {
+    let step = u.int_in_range(0..=u16::MAX)?;
+    Ok(lo + (hi - lo) * f64::from(step) / f64::from(u16::MAX))
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pacing_engine::splitmix64;
+
+    #[test]
+    fn test_pathological_unicode() {
+        let scripts = [
+            "",
+            "…… —— ;;; \n\n\n !?!?",
+            "e\u{301}\u{301}\u{301}, a\u{20dd}. Zalgo t\u{336}e\u{336}x\u{336}t.",
+            "\u{200f}שלום, עולם.\u{200e} مرحبا، بالعالم.",
+            "👨‍👩‍👧‍👦 breathe 🧘🏽‍♀️. rest\u{fe0f}…",
+            "\u{feff}\u{200b}zero\u{200d}width\u{2060}. \u{0}nul\u{7f}.",
+            "深呼吸してください。ゆっくり、吐いて。",
+            "a<b, c>d. <breakfast/> no tag.",
+        ];
+        for script in scripts {
+            for target in [0.0, 1.0, 60.0, MAX_FUZZ_TARGET_SECONDS] {
+                let checked = check_pacing(PacingConfig::sleep_story(), script, target);
+                assert!(checked.is_ok(), "{:?} at {}s: {}", script, target, checked.unwrap_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_inputs_hold_invariants() {
+        for case in 0..500u64 {
+            let data: Vec<u8> = (0..256u64).map(|i| splitmix64(case << 16 | i) as u8).collect();
+            if let Err(violation) = fuzz_pacing(&data) {
+                panic!("case {}: {}", case, violation);
+            }
+        }
+    }
+
+    #[test]
+    fn test_violations_are_reported() {
+        let pacer = MeditationPacer::new();
+        let mut result = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+        assert!(check_ssml_round_trip(&pacer, "Breathe in. Let go.", &result).is_ok());
+        assert!(matches!(
+            check_ssml_round_trip(&pacer, "Breathe out. Let go.", &result),
+            Err(InvariantViolation::Roundtrip(RoundtripError::Changed { .. }))
+        ));
+
+        result.timeline[0].pause_seconds = -0.5;
+        assert_eq!(
+            check_silence(&result),
+            Err(InvariantViolation::NegativePause { atom_index: 0, seconds: -0.5 })
+        );
+    }
+}
@@ -0,0 +1,258 @@
+//! Golden Fixtures
+//! 
+//! Records full `PacingResult`s as deterministic JSON and compares later
+//! results against them, so apps can lock pacing behavior across crate
+//! upgrades: a changed pause, atom, or warning fails the comparison with
+//! the path of the first difference. Available with the `golden` feature.
+//! 
+//! Fixtures have sorted object keys and floats rounded to
+//! `FIXTURE_DECIMALS` places, so they diff cleanly in version control.
+//! Numbers compare within `FIXTURE_TOLERANCE`.
+//! 
+//! `check_golden` records the fixture when the file does not exist yet, or
+//! re-records it when the `ZENPAL_UPDATE_GOLDENS` environment variable is
+//! set (after an intended behavior change).
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::golden::{compare_to_golden, fixture_json, GoldenError};
+//! use zenpal_core::MeditationPacer;
+//! 
+//! let pacer = MeditationPacer::new();
+//! let result = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+//! let golden = fixture_json(&result);
+//! assert_eq!(compare_to_golden(&result, &golden), Ok(()));
+//! 
+//! let longer = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 30.0);
+//! let Err(GoldenError::Mismatch { path, .. }) = compare_to_golden(&longer, &golden) else {
+//!     panic!("expected a mismatch");
+//! };
+//! assert_eq!(path, "break_stats.max_seconds");
+//! ```
+
+use std::fmt;
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::pacing_engine::PacingResult;
+
+/// Decimal places floats are rounded to in fixtures
+pub const FIXTURE_DECIMALS: i32 = 6;
+
+/// Largest difference between numbers that still compares equal
+pub const FIXTURE_TOLERANCE: f64 = 1e-6;
+
+/// Environment variable that makes `check_golden` re-record fixtures
+pub const UPDATE_GOLDENS_ENV: &str = "ZENPAL_UPDATE_GOLDENS";
+
+// ============================================
+// Types
+// ============================================
+
+/// Why a result does not match its golden fixture
+#[derive(Debug, Clone, PartialEq)]
+pub enum GoldenError {
+    /// The fixture file could not be read or written
+    Io(String),
+    /// The fixture is not valid JSON
+    Parse(String),
+    /// The result differs from the fixture
+    Mismatch {
+        /// JSON path of the first difference (e.g. `timeline[2].pause_seconds`)
+        path: String,
+        /// Value in the fixture (JSON; `null` if missing)
+        expected: String,
+        /// Value in the result (JSON; `null` if missing)
+        actual: String,
+    },
+}
+
+impl fmt::Display for GoldenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GoldenError::Io(message) => write!(f, "could not access golden fixture: {}", message),
+            GoldenError::Parse(message) => write!(f, "could not parse golden fixture: {}", message),
+            GoldenError::Mismatch { path, expected, actual } => write!(
+                f,
+                "result differs from golden fixture at {}: expected {}, got {}",
+                path, expected, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GoldenError {}
+
+// ============================================
+// Recording and Comparison
+// ============================================
+
+/// Serialize a result as a deterministic, pretty-printed fixture
+pub fn fixture_json(result: &PacingResult) -> String {
+    let value = serde_json::to_value(result).expect("PacingResult serializes to JSON");
+    format!("{:#}\n", canonical(value))
+}
+
+/// Compare a result against a fixture's JSON
+pub fn compare_to_golden(result: &PacingResult, golden_json: &str) -> Result<(), GoldenError> {
+    let expected: Value = serde_json::from_str(golden_json).map_err(|e| GoldenError::Parse(e.to_string()))?;
+    let actual = canonical(serde_json::to_value(result).expect("PacingResult serializes to JSON"));
+    match first_difference(&expected, &actual, String::new()) {
+        Some(path) => Err(GoldenError::Mismatch {
+            expected: lookup(&expected, &path).to_string(),
+            actual: lookup(&actual, &path).to_string(),
+            path,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Compare a result against the fixture at `path`, recording it if the
+/// file is missing or `ZENPAL_UPDATE_GOLDENS` is set
+pub fn check_golden(result: &PacingResult, path: impl AsRef<Path>) -> Result<(), GoldenError> {
+    let path = path.as_ref();
+    if std::env::var_os(UPDATE_GOLDENS_ENV).is_some() || !path.exists() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| GoldenError::Io(e.to_string()))?;
+        }
+        return std::fs::write(path, fixture_json(result)).map_err(|e| GoldenError::Io(e.to_string()));
+    }
+    let golden = std::fs::read_to_string(path).map_err(|e| GoldenError::Io(e.to_string()))?;
+    compare_to_golden(result, &golden)
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Sort object keys and round floats
+fn canonical(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            Value::Object(entries.into_iter().map(|(k, v)| (k, canonical(v))).collect::<Map<_, _>>())
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(canonical).collect()),
+        Value::Number(n) if n.is_f64() => {
+            let scale = 10f64.powi(FIXTURE_DECIMALS);
+            let rounded = (n.as_f64().unwrap_or(0.0) * scale).round() / scale;
+            serde_json::Number::from_f64(rounded).map_or(Value::Null, Value::Number)
+        }
+        other => other,
+    }
+}
+
+/// Path of the first difference between two values, if any
+fn first_difference(expected: &Value, actual: &Value, path: String) -> Option<String> {
+    match (expected, actual) {
+        (Value::Object(e), Value::Object(a)) => {
+            let mut keys: Vec<&String> = e.keys().chain(a.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            keys.into_iter().find_map(|key| {
+                let child = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                match (e.get(key), a.get(key)) {
+                    (Some(e), Some(a)) => first_difference(e, a, child),
+                    _ => Some(child),
+                }
+            })
+        }
+        (Value::Array(e), Value::Array(a)) => {
+            (0..e.len().max(a.len())).find_map(|i| {
+                let child = format!("{}[{}]", path, i);
+                match (e.get(i), a.get(i)) {
+                    (Some(e), Some(a)) => first_difference(e, a, child),
+                    _ => Some(child),
+                }
+            })
+        }
+        (Value::Number(e), Value::Number(a)) => {
+            let (e, a) = (e.as_f64().unwrap_or(f64::NAN), a.as_f64().unwrap_or(f64::NAN));
+            ((e - a).abs() > FIXTURE_TOLERANCE).then_some(path)
+        }
+        (e, a) => (e != a).then_some(path),
+    }
+}
+
+/// The value at a path produced by `first_difference` (`null` if missing)
+fn lookup<'a>(value: &'a Value, path: &str) -> &'a Value {
+    let mut current = value;
+    for segment in path.split('.').filter(|s| !s.is_empty()) {
+        let (key, indices) = segment.split_once('[').map_or((segment, ""), |(k, rest)| (k, rest));
+        if !key.is_empty() {
+            current = current.get(key).unwrap_or(&Value::Null);
+        }
+        for index in indices.split('[').filter(|s| !s.is_empty()) {
+            let index: usize = index.trim_end_matches(']').parse().unwrap_or(usize::MAX);
+            current = current.get(index).unwrap_or(&Value::Null);
+        }
+    }
+    current
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pacing_engine::{MeditationPacer, PacingConfig};
+
+    #[test]
+    fn test_fixture_is_deterministic() {
+        let config = PacingConfig::sleep_story();
+        let text = "Welcome. Breathe in, slowly... and let go.\n\nRest here.";
+        let first = MeditationPacer::with_config(config.clone()).calculate_pacing(text.to_string(), 90.0);
+        let second = MeditationPacer::with_config(config).calculate_pacing(text.to_string(), 90.0);
+        assert_eq!(fixture_json(&first), fixture_json(&second));
+        assert_eq!(compare_to_golden(&second, &fixture_json(&first)), Ok(()));
+
+        let value: Value = serde_json::from_str(&fixture_json(&first)).unwrap();
+        let keys: Vec<&String> = value.as_object().unwrap().keys().collect();
+        assert!(keys.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_mismatch_path() {
+        let pacer = MeditationPacer::new();
+        let result = pacer.calculate_pacing("Breathe in. Hold it. Let go.".to_string(), 30.0);
+        let golden = fixture_json(&result);
+
+        let mut changed = result.clone();
+        changed.timeline[1].pause_seconds += 0.5;
+        let Err(GoldenError::Mismatch { path, expected, actual }) = compare_to_golden(&changed, &golden) else {
+            panic!("expected a mismatch");
+        };
+        assert_eq!(path, "timeline[1].pause_seconds");
+        assert_eq!(actual.parse::<f64>().unwrap() - expected.parse::<f64>().unwrap(), 0.5);
+
+        let mut nudged = result.clone();
+        nudged.estimated_total_seconds += 1e-9;
+        assert_eq!(compare_to_golden(&nudged, &golden), Ok(()));
+        assert!(matches!(compare_to_golden(&result, "not json"), Err(GoldenError::Parse(_))));
+    }
+
+    #[test]
+    fn test_check_golden_records_then_compares() {
+        let path = std::env::temp_dir()
+            .join(format!("zenpal-golden-{}", std::process::id()))
+            .join("breathe.json");
+        let _ = std::fs::remove_file(&path);
+        let pacer = MeditationPacer::new();
+        let result = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+
+        assert_eq!(check_golden(&result, &path), Ok(()));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), fixture_json(&result));
+        assert_eq!(check_golden(&result, &path), Ok(()));
+
+        let other = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 40.0);
+        if std::env::var_os(UPDATE_GOLDENS_ENV).is_none() {
+            assert!(check_golden(&other, &path).is_err());
+        }
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -0,0 +1,91 @@
+//! Label Track Export
+//! 
+//! Writes the pause plan of a `PacingResult` as an Audacity label file so
+//! audio engineers can line the planned silences up against rendered audio
+//! and adjust them by hand. ocenaudio imports the same format.
+//! 
+//! ## Format
+//! 
+//! One region label per rendered pause, tab-separated:
+//! 
+//! ```text
+//! <start seconds>\t<end seconds>\t<label>
+//! ```
+//! 
+//! Offsets come from the estimated atom timeline, so they drift from real
+//! audio by however much the speech estimate is off.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::MeditationPacer;
+//! 
+//! let result = MeditationPacer::new().calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+//! let labels = result.to_audacity_labels();
+//! assert_eq!(labels.lines().count(), 1);
+//! ```
+
+use crate::pacing_engine::PacingResult;
+use crate::prelude::*;
+
+// ============================================
+// Export
+// ============================================
+
+impl PacingResult {
+    /// Render every rendered pause as an Audacity region label
+    pub fn to_audacity_labels(&self) -> String {
+        let mut out = String::new();
+
+        for entry in self.timeline.iter().filter(|entry| entry.pause_seconds > 0.0) {
+            out.push_str(&format!(
+                "{:.6}\t{:.6}\tpause {:.2}s after \"{}\"\n",
+                entry.speech_end_seconds(),
+                entry.end_seconds(),
+                entry.pause_seconds,
+                entry.text.trim(),
+            ));
+        }
+
+        out
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::MeditationPacer;
+
+    #[test]
+    fn test_labels_cover_pauses() {
+        let pacer = MeditationPacer::new();
+        let result = pacer.calculate_pacing("Breathe in. Hold it. Let go.".to_string(), 30.0);
+        let labels = result.to_audacity_labels();
+
+        let lines: Vec<&str> = labels.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let fields: Vec<&str> = lines[0].split('\t').collect();
+        assert_eq!(fields.len(), 3);
+        let start: f64 = fields[0].parse().unwrap();
+        let end: f64 = fields[1].parse().unwrap();
+        assert!((start - result.timeline[0].speech_end_seconds()).abs() < 1e-6);
+        assert!((end - result.timeline[1].start_seconds).abs() < 1e-6);
+        assert!(fields[2].ends_with("after \"Breathe in\""));
+    }
+
+    #[test]
+    fn test_labels_skip_silent_atoms() {
+        let pacer = MeditationPacer::new();
+        assert_eq!(pacer.calculate_pacing(String::new(), 30.0).to_audacity_labels(), "");
+
+        // Speech longer than the target leaves no pauses to label
+        let result = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 0.5);
+        assert!(result.timeline.iter().all(|entry| entry.pause_seconds == 0.0));
+        assert_eq!(result.to_audacity_labels(), "");
+    }
+}
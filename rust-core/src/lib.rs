@@ -4,25 +4,219 @@
 //! 1. Used directly in Rust applications
 //! 2. Compiled to WebAssembly for browser use
 //! 3. Bridged to Swift via UniFFI for iOS
+//! 4. Linked from C (Android NDK, Unity) through the `c_api` exports
+//! 5. Built with `#![no_std]` + `alloc` for embedded devices (ESP32-class
+//!    hardware with a speaker) and constrained WASM runtimes
+//! 6. Built for WASI (`wasm32-wasip1`), library and `zenpal-pace` CLI alike,
+//!    for sandboxed content pipelines
 //! 
-//! The core is intentionally kept simple with no async, no external dependencies
-//! beyond regex, and uses only concrete types for easy FFI bridging.
+//! The core is intentionally kept simple with no async and only concrete
+//! types for easy FFI bridging. With default features its one dependency is
+//! regex; every integration (serde, rayon, UniFFI, wasm-bindgen, PyO3, ...)
+//! is an opt-in feature.
+//!
+//! ## Feature Flags
+//! 
+//! - **std** (default): the standard library, plus regex for custom
+//!   `atom_pattern`s; every other feature except `alloc` implies it
+//! - **alloc**: the `no_std` build (see below); ignored when `std` is on
+//! - **c-api**: `extern "C"` entry points for C consumers, via the `c_api`
+//!   module (header in `include/zenpal_core.h`)
+//! - **serde**: `Serialize`/`Deserialize` on configs, results, atoms, and
+//!   punctuation types, for passing them across FFI/WASM as JSON; also
+//!   `PacingConfig::from_json_str`/`to_json_string`
+//! - **toml**: `PacingConfig::from_toml_str`/`to_toml_string` (implies `serde`)
+//! - **schemars**: JSON Schemas for `PacingConfig` and `PacingResult`
+//!   (implies `serde`), via the `schema` module
+//! - **elevenlabs**: text-to-speech request bodies built from a
+//!   `PacingResult` (implies `serde`), via the `elevenlabs` module
+//! - **parallel**: `MeditationPacer::calculate_batch` paces scripts across
+//!   threads with rayon (on `wasm32-wasip1`, which cannot spawn threads,
+//!   rayon runs the batch on the calling thread)
+//! - **uniffi**: UniFFI proc-macro definitions for generating Swift/Kotlin
+//!   bindings directly from this crate
+//! - **uniffi-cli**: the `uniffi-bindgen` binary that generates them
+//! - **wasm**: `#[wasm_bindgen]` wrappers taking and returning JS-friendly
+//!   types, via the `wasm` module (browser only; leave it off for WASI)
+//! - **python**: PyO3 `Config`/`Pacer`/`Result` classes, via the `python`
+//!   module
+//! - **arbitrary**: `Arbitrary` for `PacingConfig` and pacing invariant
+//!   checks (non-negative silence, SSML round-trip) for fuzzing and
+//!   property tests, via the `fuzzing` module
+//! - **golden**: deterministic JSON fixtures of `PacingResult`s and
+//!   comparison against stored goldens (implies `serde`), via the `golden`
+//!   module
+//! - **cli**: the `zenpal-pace` binary, which paces a script file and prints
+//!   SSML, JSON, or a report (implies `serde` and `toml`)
 //!
 //! ## Key Constants (Production-Calibrated)
 //! 
 //! - **12 characters per second** (observed from TTS data)
 //! - **70 words per minute** target density (50/50 speech-to-silence ratio)
 //! - **1.1x safety buffer** on silence (TTS often faster than expected)
+//! 
+//! ## `no_std`
+//! 
+//! With `default-features = false, features = ["alloc"]` the crate is
+//! `#![no_std]` and needs only an allocator. Pacing, rendering, and the
+//! analysis modules work as with `std`, with these differences:
+//! 
+//! - `PacingConfig::atom_pattern` is ignored (custom patterns need regex);
+//!   the built-in delimiters, locale delimiters, and markers still split
+//! - The map type of `PacingConfig` fields is `hashbrown`'s, re-exported
+//!   as `zenpal_core::HashMap`
+//! - Float math (`powf`, `exp`, rounding) comes from `libm`
+//! 
+//! The integration features (serde, UniFFI, wasm, Python, ...) all need
+//! `std`. Firmware links the crate as a Rust library and provides the
+//! allocator and panic handler itself; the manifest only builds the Rust
+//! library, and the staticlib/cdylib outputs for C, Swift/Kotlin, browsers,
+//! and Python come from the separate `bindings` crate.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("zenpal-core needs the `std` feature, or `alloc` for `no_std` builds");
+
+extern crate alloc;
+#[cfg(all(test, not(feature = "std")))]
+#[macro_use]
+extern crate std;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
 
+pub mod acronyms;
+pub mod affirmations;
+pub mod alignment;
+pub mod bells;
+pub mod breathwork;
+#[cfg(feature = "c-api")]
+pub mod c_api;
+pub mod calibration;
+pub mod captions;
+pub mod chunking;
+pub mod compare;
+pub mod corpus;
+#[cfg(feature = "serde")]
+pub mod config_file;
+pub mod diff;
+pub mod ducking;
+#[cfg(feature = "elevenlabs")]
+pub mod elevenlabs;
+pub mod events;
+#[cfg(feature = "golden")]
+pub mod golden;
+#[cfg(feature = "arbitrary")]
+pub mod fuzzing;
+pub mod labels;
+pub mod lint;
+pub mod locale;
+pub mod numbers;
 pub mod pacing_engine;
+mod prelude;
+pub mod prompt;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod readability;
+pub mod report;
+pub mod roundtrip;
+pub mod sanitize;
+#[cfg(feature = "schemars")]
+pub mod schema;
+pub mod sections;
+pub mod sequence;
+pub mod session;
+pub mod splicing;
+pub mod stats;
+pub mod templates;
+pub mod transcript;
+#[cfg(feature = "uniffi")]
+mod uniffi_api;
+pub mod voices;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 // Re-export main types for convenience
+pub use prelude::HashMap;
 pub use pacing_engine::MeditationPacer;
 pub use pacing_engine::PacingConfig;
+pub use pacing_engine::AllocationStrategy;
+pub use pacing_engine::DurationModel;
+pub use pacing_engine::Language;
+pub use pacing_engine::PacingOverrides;
+pub use pacing_engine::ProgressiveCurve;
+pub use pacing_engine::PauseCurve;
+pub use pacing_engine::PauseJitter;
+pub use pacing_engine::Preset;
+pub use pacing_engine::ProsodyRamp;
+pub use pacing_engine::AtomIter;
+pub use pacing_engine::AtomRefIter;
+pub use pacing_engine::AtomOverride;
+pub use pacing_engine::BreakBudget;
+pub use pacing_engine::BreakFormat;
+pub use pacing_engine::BreakStats;
+pub use pacing_engine::BreakUnit;
+pub use pacing_engine::BannedWordAction;
+pub use pacing_engine::ConfigError;
+pub use pacing_engine::Cue;
+pub use pacing_engine::PacingError;
 pub use pacing_engine::PacingResult;
+pub use pacing_engine::PacingWarning;
+pub use pacing_engine::PunctuationType;
+pub use pacing_engine::SectionTiming;
+pub use pacing_engine::SilenceRatioLimits;
+pub use pacing_engine::SpeechEstimates;
+pub use pacing_engine::TargetDurationLimits;
+pub use pacing_engine::TimelineEntry;
+pub use pacing_engine::WordReplacement;
+pub use pacing_engine::WordTiming;
+pub use alignment::AlignedPacing;
+pub use alignment::AlignedWord;
+pub use alignment::AtomDrift;
+pub use bells::Bell;
+pub use bells::BellTime;
+pub use captions::VttOptions;
+pub use chunking::BoundaryPause;
+pub use chunking::ChunkedPacing;
+pub use chunking::SsmlChunk;
+pub use compare::PacingSummary;
+pub use diff::AtomAlignment;
+pub use diff::BreakChange;
+pub use diff::PacingDiff;
+pub use ducking::DuckingOptions;
+pub use ducking::EnvelopeSegment;
+pub use ducking::MusicLevel;
+pub use events::PlaybackEvent;
+pub use locale::Locale;
+pub use locale::WordCounting;
+#[cfg(feature = "serde")]
+pub use config_file::ConfigFileError;
+pub use roundtrip::RoundtripError;
+pub use sanitize::SymbolHandling;
+pub use sections::ScriptSection;
+pub use sections::SectionProfile;
+pub use sequence::Scene;
+pub use sequence::Sequence;
+pub use session::ComposedSegment;
+pub use session::ComposedSession;
+pub use session::Session;
+pub use session::SessionSegment;
+pub use splicing::SilenceSamples;
+pub use splicing::StitchError;
+pub use stats::ScriptStats;
 
 // Re-export convenience functions
 pub use pacing_engine::format_meditation_ssml;
 pub use pacing_engine::calculate_pacing_details;
+pub use pacing_engine::calculate_pacing_exact;
+pub use pacing_engine::repace_with_measured;
+pub use pacing_engine::estimate_cost;
 pub use pacing_engine::calculate_target_words_for_prompt;
 pub use pacing_engine::calculate_target_words_custom;
+pub use roundtrip::verify_roundtrip;
+pub use stats::analyze;
+#[cfg(feature = "serde")]
+pub use pacing_engine::calculate_pacing_json;
+#[cfg(feature = "serde")]
+pub use pacing_engine::calculate_pacing_json_with_config;
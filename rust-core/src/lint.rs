@@ -0,0 +1,340 @@
+//! Script Linter
+//! 
+//! Flags things in a meditation script that make the paced audio feel
+//! rushed or jarring, before any pacing or synthesis happens:
+//! 
+//! - **Long runs without a pause point**: the pacer can only place silence
+//!   at punctuation, so a long unpunctuated stretch is read in one breath
+//! - **Person shifts**: a script that speaks to the listener ("you") with
+//!   the odd first-person sentence ("I"), or the other way round
+//! - **Alarming words**: words that startle rather than soothe ("panic",
+//!   "emergency")
+//! - **Dense paragraphs**: paragraphs that take longer than
+//!   `max_paragraph_seconds` of session at the target words per minute, so
+//!   the listener goes too long without a deep rest
+//! 
+//! Findings carry byte offsets into the script so editors can highlight
+//! them.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::lint::{lint_script, LintKind};
+//! 
+//! let findings = lint_script("Breathe in. There is no emergency here.");
+//! assert_eq!(findings.len(), 1);
+//! assert_eq!(findings[0].kind, LintKind::AlarmingWord);
+//! ```
+
+use crate::pacing_engine::TARGET_WORDS_PER_MINUTE;
+use crate::prelude::*;
+
+/// Longest run of words the linter accepts without a pause point
+const DEFAULT_MAX_WORDS_WITHOUT_PAUSE: usize = 20;
+
+/// Longest paragraph, in seconds of session at the target density
+const DEFAULT_MAX_PARAGRAPH_SECONDS: f64 = 60.0;
+
+/// Words that startle rather than soothe
+const DEFAULT_ALARMING_WORDS: [&str; 18] = [
+    "alarm", "attack", "danger", "dangerous", "dead", "death", "die",
+    "emergency", "fear", "hurry", "kill", "pain", "panic", "quickly",
+    "scream", "terrified", "urgent", "warning",
+];
+
+/// Characters the pacer can place a pause after
+const PAUSE_CHARS: [char; 9] = ['.', ',', ';', ':', '!', '?', '—', '–', '…'];
+
+/// Characters that end a sentence
+const SENTENCE_END_CHARS: [char; 4] = ['.', '!', '?', '…'];
+
+/// Second-person words
+const SECOND_PERSON: [&str; 5] = ["you", "your", "yours", "yourself", "you're"];
+
+/// First-person singular words
+const FIRST_PERSON: [&str; 6] = ["i", "me", "my", "mine", "myself", "i'm"];
+
+// ============================================
+// Types
+// ============================================
+
+/// What a finding is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintKind {
+    /// Too many words without a pause point
+    LongRun,
+    /// A sentence in a different grammatical person than the script
+    PersonShift,
+    /// A word that startles rather than soothes
+    AlarmingWord,
+    /// A paragraph too long for the target density
+    DenseParagraph,
+}
+
+/// One problem found in a script
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintFinding {
+    /// What the finding is about
+    pub kind: LintKind,
+    /// Byte offset where the flagged text starts
+    pub start: usize,
+    /// Byte offset where the flagged text ends
+    pub end: usize,
+    /// Human-readable explanation
+    pub message: String,
+}
+
+/// Thresholds for `lint_script_with`
+#[derive(Debug, Clone)]
+pub struct LintOptions {
+    /// Longest run of words without a pause point
+    pub max_words_without_pause: usize,
+    /// Target density (words per minute of session, silence included)
+    pub words_per_minute: f64,
+    /// Longest paragraph, in seconds of session at `words_per_minute`
+    pub max_paragraph_seconds: f64,
+    /// Words to flag, lowercase
+    pub alarming_words: Vec<String>,
+}
+
+impl Default for LintOptions {
+    fn default() -> Self {
+        Self {
+            max_words_without_pause: DEFAULT_MAX_WORDS_WITHOUT_PAUSE,
+            words_per_minute: TARGET_WORDS_PER_MINUTE,
+            max_paragraph_seconds: DEFAULT_MAX_PARAGRAPH_SECONDS,
+            alarming_words: DEFAULT_ALARMING_WORDS.iter().map(|w| w.to_string()).collect(),
+        }
+    }
+}
+
+/// A word with its byte range
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Word<'a> {
+    pub(crate) text: &'a str,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+// ============================================
+// Linting
+// ============================================
+
+/// Lint a script with the default thresholds
+pub fn lint_script(text: &str) -> Vec<LintFinding> {
+    lint_script_with(text, &LintOptions::default())
+}
+
+/// Lint a script, returning findings in text order
+pub fn lint_script_with(text: &str, options: &LintOptions) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+    findings.extend(long_runs(text, options.max_words_without_pause));
+    findings.extend(person_shifts(text));
+    findings.extend(alarming_words(text, &options.alarming_words));
+    findings.extend(dense_paragraphs(text, options));
+    findings.sort_by_key(|f| (f.start, f.end));
+    findings
+}
+
+/// Runs of more than `max_words` words between pause points
+fn long_runs(text: &str, max_words: usize) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+    let mut run: Vec<Word> = Vec::new();
+    let mut flush = |run: &mut Vec<Word>| {
+        if run.len() > max_words {
+            findings.push(LintFinding {
+                kind: LintKind::LongRun,
+                start: run[0].start,
+                end: run[run.len() - 1].end,
+                message: format!("{} words without a pause point (max {})", run.len(), max_words),
+            });
+        }
+        run.clear();
+    };
+
+    for (i, piece) in split_inclusive_at(text, |c| PAUSE_CHARS.contains(&c) || c == '\n') {
+        run.extend(words(piece, i));
+        if piece.ends_with(|c: char| PAUSE_CHARS.contains(&c) || c == '\n') {
+            flush(&mut run);
+        }
+    }
+    flush(&mut run);
+
+    findings
+}
+
+/// Sentences in the minority grammatical person
+fn person_shifts(text: &str) -> Vec<LintFinding> {
+    let sentences: Vec<(usize, &str)> = split_inclusive_at(text, |c| SENTENCE_END_CHARS.contains(&c) || c == '\n')
+        .into_iter()
+        .filter(|(_, s)| !s.trim().is_empty())
+        .collect();
+    let person = |sentence: &str| {
+        let lower: Vec<String> = words(sentence, 0).iter().map(|w| w.text.to_lowercase()).collect();
+        let second = lower.iter().filter(|w| SECOND_PERSON.contains(&w.as_str())).count();
+        let first = lower.iter().filter(|w| FIRST_PERSON.contains(&w.as_str())).count();
+        (second, first)
+    };
+
+    let (second, first) = sentences.iter()
+        .map(|(_, s)| person(s))
+        .fold((0, 0), |acc, p| (acc.0 + p.0, acc.1 + p.1));
+    if second == 0 || first == 0 {
+        return Vec::new();
+    }
+    let (script_person, other_person) = if second >= first {
+        ("second person (\"you\")", "first person (\"I\")")
+    } else {
+        ("first person (\"I\")", "second person (\"you\")")
+    };
+
+    sentences.iter()
+        .filter(|(_, s)| {
+            let (s2, s1) = person(s);
+            if second >= first { s1 > 0 } else { s2 > 0 }
+        })
+        .map(|(offset, s)| {
+            let trimmed_start = offset + (s.len() - s.trim_start().len());
+            LintFinding {
+                kind: LintKind::PersonShift,
+                start: trimmed_start,
+                end: offset + s.trim_end().len(),
+                message: format!("sentence in {} in a script written in {}", other_person, script_person),
+            }
+        })
+        .collect()
+}
+
+/// Every occurrence of an alarming word
+fn alarming_words(text: &str, alarming: &[String]) -> Vec<LintFinding> {
+    words(text, 0).into_iter()
+        .filter(|w| alarming.iter().any(|a| a.eq_ignore_ascii_case(w.text)))
+        .map(|w| LintFinding {
+            kind: LintKind::AlarmingWord,
+            start: w.start,
+            end: w.end,
+            message: format!("\"{}\" may startle the listener", w.text),
+        })
+        .collect()
+}
+
+/// Paragraphs longer than `max_paragraph_seconds` at the target density
+fn dense_paragraphs(text: &str, options: &LintOptions) -> Vec<LintFinding> {
+    if options.words_per_minute <= 0.0 {
+        return Vec::new();
+    }
+    let max_words = options.max_paragraph_seconds / 60.0 * options.words_per_minute;
+
+    let mut findings = Vec::new();
+    let mut offset = 0;
+    for paragraph in text.split("\n\n") {
+        let paragraph_words = words(paragraph, offset);
+        if paragraph_words.len() as f64 > max_words {
+            let seconds = paragraph_words.len() as f64 / options.words_per_minute * 60.0;
+            findings.push(LintFinding {
+                kind: LintKind::DenseParagraph,
+                start: paragraph_words[0].start,
+                end: paragraph_words[paragraph_words.len() - 1].end,
+                message: format!(
+                    "paragraph of {} words runs {:.0}s at {:.0} wpm without a paragraph break (max {:.0}s)",
+                    paragraph_words.len(),
+                    seconds,
+                    options.words_per_minute,
+                    options.max_paragraph_seconds,
+                ),
+            });
+        }
+        offset += paragraph.len() + 2;
+    }
+
+    findings
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Words (letters, digits, apostrophes) in `text`, offset by `base`
+pub(crate) fn words(text: &str, base: usize) -> Vec<Word<'_>> {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '\'' || c == '’';
+    let mut out = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (is_word_char(c), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                out.push(Word { text: &text[s..i], start: base + s, end: base + i });
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        out.push(Word { text: &text[s..], start: base + s, end: base + text.len() });
+    }
+    out
+}
+
+/// Split after every character matching `at`, keeping byte offsets
+pub(crate) fn split_inclusive_at(text: &str, at: impl Fn(char) -> bool) -> Vec<(usize, &str)> {
+    let mut out = Vec::new();
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        if at(c) {
+            let end = i + c.len_utf8();
+            out.push((start, &text[start..end]));
+            start = end;
+        }
+    }
+    if start < text.len() {
+        out.push((start, &text[start..]));
+    }
+    out
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_run_and_alarming_word() {
+        let text = "Breathe in. Let your attention drift down from the crown of your head across \
+                    your forehead and your cheeks and your jaw and your neck and into the shoulders. \
+                    Do not panic.";
+        let findings = lint_script(text);
+
+        let kinds: Vec<LintKind> = findings.iter().map(|f| f.kind).collect();
+        assert_eq!(kinds, vec![LintKind::LongRun, LintKind::AlarmingWord]);
+        assert!(text[findings[0].start..findings[0].end].starts_with("Let your attention"));
+        assert_eq!(&text[findings[1].start..findings[1].end], "panic");
+    }
+
+    #[test]
+    fn test_person_shift() {
+        let text = "Let your shoulders soften. Feel your breath. I am calm. Rest here, you are safe.";
+        let findings = lint_script(text);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].kind, LintKind::PersonShift);
+        assert_eq!(&text[findings[0].start..findings[0].end], "I am calm.");
+
+        assert!(lint_script("I am calm. I am safe. I am enough.").is_empty());
+    }
+
+    #[test]
+    fn test_dense_paragraph() {
+        let sentence = "Breathe in and breathe out. ";
+        let dense = sentence.repeat(20);
+        let text = format!("Welcome.\n\n{}", dense.trim());
+        let findings = lint_script(&text);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].kind, LintKind::DenseParagraph);
+        assert_eq!(findings[0].start, "Welcome.\n\n".len());
+
+        let relaxed = LintOptions { max_paragraph_seconds: 120.0, ..LintOptions::default() };
+        assert!(lint_script_with(&text, &relaxed).is_empty());
+    }
+}
@@ -0,0 +1,365 @@
+//! Locales
+//! 
+//! Bundles the per-language rules the pacer depends on, selected with
+//! `PacingConfig::locale`:
+//! 
+//! - **Speech rate**: the `Language` preset (used unless
+//!   `PacingConfig::language` names another language)
+//! - **Punctuation classes**: characters that end an atom, and the pause
+//!   class each stands for, added to or overriding the built-in set (e.g.
+//!   Japanese `。` is a sentence end, `、` a comma)
+//! - **Word counting**: how `word_count` is derived from atom text, for
+//!   scripts that do not separate words with spaces
+//! 
+//! Without a locale, words are counted with `WordCounting::Morphemes`:
+//! whitespace-separated tokens, except that Han and kana runs inside a
+//! token are split into approximate morphemes, so an unspaced Japanese or
+//! Chinese paragraph is not counted as a single word.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::{Locale, MeditationPacer, PacingConfig, PunctuationType};
+//! 
+//! let pacer = MeditationPacer::with_config(PacingConfig::for_locale(Locale::japanese()));
+//! let atoms = pacer.atomize("ゆっくり、息を吸って。吐いて。");
+//! assert_eq!(atoms.len(), 3);
+//! assert_eq!(atoms[0].punctuation, PunctuationType::Comma);
+//! assert_eq!(atoms[1].punctuation_char, "。");
+//! ```
+
+use crate::pacing_engine::{is_spoken_char, Language, PunctuationType};
+use crate::prelude::*;
+
+/// Characters per word for Japanese (keeps words-per-minute targets in
+/// line with English at the preset speech rates)
+const JAPANESE_CHARS_PER_WORD: f64 = 2.0;
+
+/// Average morpheme length in characters, per script: kanji/hanzi
+/// compounds are mostly two characters, kana runs carry particles and
+/// inflections, and katakana loanwords run longer
+const HAN_CHARS_PER_MORPHEME: usize = 2;
+const HIRAGANA_CHARS_PER_MORPHEME: usize = 3;
+const KATAKANA_CHARS_PER_MORPHEME: usize = 4;
+
+// ============================================
+// Types
+// ============================================
+
+/// How words are counted in atom text
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum WordCounting {
+    /// Whitespace-separated tokens
+    Whitespace,
+    /// Whitespace-separated tokens, with Han, hiragana, and katakana runs
+    /// split into approximate morphemes and detached Spanish inverted
+    /// marks not counted (otherwise identical to `Whitespace`)
+    #[default]
+    Morphemes,
+    /// Non-whitespace characters divided by `chars_per_word` (rounded,
+    /// at least one word for non-empty text), for scripts written
+    /// without spaces
+    Characters {
+        /// Characters that count as one word
+        chars_per_word: f64,
+    },
+}
+
+/// Per-language pacing rules
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct Locale {
+    /// Language whose speech rate preset applies
+    pub language: Language,
+    /// Extra atom-ending characters and their pause class; keys must be
+    /// single characters (longer keys are ignored)
+    pub punctuation: HashMap<String, PunctuationType>,
+    /// How words are counted
+    pub word_counting: WordCounting,
+}
+
+impl Locale {
+    /// English: the built-in punctuation and default word counting
+    pub fn english() -> Self {
+        Self::plain(Language::English)
+    }
+
+    /// Spanish: the built-in punctuation and default word counting
+    pub fn spanish() -> Self {
+        Self::plain(Language::Spanish)
+    }
+
+    /// German: the built-in punctuation and default word counting
+    pub fn german() -> Self {
+        Self::plain(Language::German)
+    }
+
+    /// Japanese: full-width punctuation and character-based word counting
+    pub fn japanese() -> Self {
+        Self {
+            language: Language::Japanese,
+            punctuation: cjk_punctuation(),
+            word_counting: WordCounting::Characters { chars_per_word: JAPANESE_CHARS_PER_WORD },
+        }
+    }
+
+    /// Hindi: the danda (`।`) ends a sentence and the double danda (`॥`),
+    /// which closes a verse or passage, a paragraph
+    pub fn hindi() -> Self {
+        Self {
+            punctuation: [
+                ("।".to_string(), PunctuationType::SentenceEnd),
+                ("॥".to_string(), PunctuationType::Paragraph),
+            ]
+            .into_iter()
+            .collect(),
+            ..Self::plain(Language::Hindi)
+        }
+    }
+
+    /// The locale for `language` (languages without dedicated rules get
+    /// the built-in punctuation and default word counting)
+    pub fn for_language(language: Language) -> Self {
+        match language {
+            Language::Japanese => Self::japanese(),
+            Language::Hindi => Self::hindi(),
+            other => Self::plain(other),
+        }
+    }
+
+    /// Look up a locale by ISO 639-1 code or BCP 47 tag (e.g. "ja-JP")
+    pub fn from_code(code: &str) -> Option<Self> {
+        Language::from_code(code).map(Self::for_language)
+    }
+
+    /// The pause class `c` stands for, if it is one of this locale's extra
+    /// punctuation characters
+    pub fn classify(&self, c: char) -> Option<PunctuationType> {
+        let mut buf = [0u8; 4];
+        self.punctuation.get(c.encode_utf8(&mut buf) as &str).copied()
+    }
+
+    /// The extra punctuation characters (single-character keys only)
+    pub fn punctuation_chars(&self) -> Vec<char> {
+        let mut chars: Vec<char> = self.punctuation.keys()
+            .filter_map(|key| {
+                let mut it = key.chars();
+                it.next().filter(|_| it.next().is_none())
+            })
+            .collect();
+        chars.sort_unstable();
+        chars
+    }
+
+    /// Count words in `text` under this locale's rule
+    pub fn count_words(&self, text: &str) -> usize {
+        self.word_counting.count_words(text)
+    }
+
+    /// Speech rate preset only
+    fn plain(language: Language) -> Self {
+        Self {
+            language,
+            punctuation: HashMap::new(),
+            word_counting: WordCounting::default(),
+        }
+    }
+}
+
+impl WordCounting {
+    /// Count words in `text`
+    pub fn count_words(&self, text: &str) -> usize {
+        match *self {
+            WordCounting::Whitespace => text.split_whitespace().count(),
+            WordCounting::Morphemes => text.split_whitespace().map(token_morphemes).sum(),
+            WordCounting::Characters { chars_per_word } => {
+                let chars = text.chars().filter(|c| is_spoken_char(*c)).count();
+                let valid = chars_per_word.is_finite() && chars_per_word > 0.0;
+                if chars == 0 || !valid {
+                    return chars.min(1);
+                }
+                ((chars as f64 / chars_per_word).round() as usize).max(1)
+            }
+        }
+    }
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Scripts written without spaces between words
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UnspacedScript {
+    Han,
+    Hiragana,
+    Katakana,
+}
+
+impl UnspacedScript {
+    /// The script of `c`, if it is one written without spaces
+    fn of(c: char) -> Option<Self> {
+        match c {
+            '\u{3005}' | '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}' => Some(Self::Han),
+            '\u{3041}'..='\u{309F}' => Some(Self::Hiragana),
+            '\u{30A0}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' | '\u{FF66}'..='\u{FF9F}' => Some(Self::Katakana),
+            _ => None,
+        }
+    }
+
+    /// Approximate morphemes in a run of `len` characters
+    fn morphemes(self, len: usize) -> usize {
+        let per_morpheme = match self {
+            Self::Han => HAN_CHARS_PER_MORPHEME,
+            Self::Hiragana => HIRAGANA_CHARS_PER_MORPHEME,
+            Self::Katakana => KATAKANA_CHARS_PER_MORPHEME,
+        };
+        len.div_ceil(per_morpheme)
+    }
+}
+
+/// Words in one whitespace-separated token
+/// 
+/// A token of only Spanish inverted marks (`¿`, `¡` set apart from their
+/// sentence) is no word, and any other token without Han or kana is one
+/// word. Otherwise each script run
+/// counts its approximate morphemes, and each other run containing a
+/// letter or digit ("iPhoneを") counts as one word; punctuation is free.
+fn token_morphemes(token: &str) -> usize {
+    if token.chars().all(|c| matches!(c, '¿' | '¡')) {
+        return 0;
+    }
+    if !token.chars().any(|c| UnspacedScript::of(c).is_some()) {
+        return 1;
+    }
+    
+    let mut count = 0;
+    let mut run: Option<(UnspacedScript, usize)> = None;
+    let mut in_other_word = false;
+    for c in token.chars() {
+        match (UnspacedScript::of(c), run) {
+            (Some(script), Some((current, len))) if script == current => run = Some((current, len + 1)),
+            (Some(script), _) => {
+                count += run.map_or(0, |(current, len)| current.morphemes(len));
+                run = Some((script, 1));
+                in_other_word = false;
+            }
+            (None, _) => {
+                count += run.take().map_or(0, |(current, len)| current.morphemes(len));
+                if c.is_alphanumeric() && !in_other_word {
+                    count += 1;
+                }
+                in_other_word = c.is_alphanumeric();
+            }
+        }
+    }
+    count + run.map_or(0, |(current, len)| current.morphemes(len))
+}
+
+/// Full-width CJK punctuation
+fn cjk_punctuation() -> HashMap<String, PunctuationType> {
+    [
+        ("。", PunctuationType::SentenceEnd),
+        ("．", PunctuationType::SentenceEnd),
+        ("！", PunctuationType::SentenceEnd),
+        ("？", PunctuationType::SentenceEnd),
+        ("、", PunctuationType::Comma),
+        ("，", PunctuationType::Comma),
+        ("；", PunctuationType::Semicolon),
+        ("：", PunctuationType::Comma),
+    ]
+    .into_iter()
+    .map(|(c, p)| (c.to_string(), p))
+    .collect()
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pacing_engine::{MeditationPacer, PacingConfig};
+
+    #[test]
+    fn test_japanese_pacing() {
+        let pacer = MeditationPacer::with_config(PacingConfig::for_locale(Locale::japanese()));
+        let text = "深呼吸してください。ゆっくり、吐いて！\n\n休みましょう";
+        let atoms = pacer.atomize(text);
+        let kinds: Vec<PunctuationType> = atoms.iter().map(|a| a.punctuation).collect();
+        assert_eq!(kinds, vec![
+            PunctuationType::SentenceEnd,
+            PunctuationType::Comma,
+            PunctuationType::Paragraph,
+            PunctuationType::None,
+        ]);
+        // 9 characters at 2 per word
+        assert_eq!(atoms[0].word_count, 5);
+        assert_eq!(pacer.config().effective_chars_per_second(), Language::Japanese.chars_per_second());
+
+        let result = pacer.calculate_pacing(text.to_string(), 30.0);
+        assert!(result.ssml.starts_with("深呼吸してください。<break"));
+        assert!(result.ssml.contains("吐いて！"));
+    }
+
+    #[test]
+    fn test_western_locales() {
+        for (locale, code) in [(Locale::english(), "en"), (Locale::spanish(), "es-MX"), (Locale::german(), "de")] {
+            assert_eq!(Locale::from_code(code), Some(locale.clone()));
+            assert_eq!(locale.count_words("Atme tief ein"), 3);
+            assert!(locale.punctuation_chars().is_empty());
+        }
+        let config = PacingConfig {
+            language: Some(Language::English),
+            ..PacingConfig::for_locale(Locale::german())
+        };
+        // An explicit language wins over the locale's
+        assert_eq!(config.effective_chars_per_second(), Language::English.chars_per_second());
+        assert_eq!(Locale::japanese().classify('、'), Some(PunctuationType::Comma));
+        assert_eq!(Locale::japanese().count_words("  "), 0);
+    }
+
+    #[test]
+    fn test_danda() {
+        let text = "गहरी साँस लें। धीरे से छोड़ें॥ आराम करें।";
+
+        // Built in: both dandas end a sentence
+        let atoms = MeditationPacer::new().atomize(text);
+        assert_eq!(atoms.len(), 3);
+        assert!(atoms.iter().all(|a| a.punctuation == PunctuationType::SentenceEnd));
+        assert_eq!(atoms[1].punctuation_char, "॥");
+
+        // The Hindi locale gives the double danda a paragraph pause
+        let pacer = MeditationPacer::with_config(PacingConfig::for_locale(Locale::hindi()));
+        let atoms = pacer.atomize(text);
+        assert_eq!(atoms[0].punctuation, PunctuationType::SentenceEnd);
+        assert_eq!(atoms[1].punctuation, PunctuationType::Paragraph);
+        assert_eq!(atoms[1].punctuation_char, "॥");
+
+        // Locales can remap the class
+        let mut locale = Locale::hindi();
+        locale.punctuation.insert("।".to_string(), PunctuationType::Semicolon);
+        let pacer = MeditationPacer::with_config(PacingConfig::for_locale(locale));
+        assert_eq!(pacer.atomize(text)[0].punctuation, PunctuationType::Semicolon);
+    }
+
+    #[test]
+    fn test_morpheme_counting() {
+        let counting = WordCounting::Morphemes;
+        // 深呼吸 (2) + してください (2)
+        assert_eq!(counting.count_words("深呼吸してください"), 4);
+        assert_eq!(counting.count_words("iPhoneを、マインドフルネスに。"), 5);
+        assert_eq!(counting.count_words("Breathe in — slowly"), 4);
+        assert_eq!(WordCounting::Whitespace.count_words("深呼吸してください"), 1);
+
+        // Stats no longer count an unspaced paragraph as one word
+        let stats = crate::analyze("今日は、ゆっくりと呼吸に意識を向けましょう。");
+        assert_eq!(stats.word_count, 11);
+    }
+}
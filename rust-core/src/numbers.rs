@@ -0,0 +1,267 @@
+//! Number Expansion
+//! 
+//! Digits are quick to write but slow to say: "Count from 1 to 10" has
+//! three characters of digits and about a second of speech in them. Speech
+//! estimation therefore counts numbers as the words a voice reads them as
+//! (and acronyms as letter names, see `acronyms`):
+//! 
+//! - **Integers**: `10` → "ten", `1,500` → "one thousand five hundred"
+//! - **Decimals**: `4.5` → "four point five"
+//! - **Ordinals**: `3rd` → "third"
+//! - **Times**: `3:30` → "three thirty", `6:05` → "six oh five",
+//!   `7:00` → "seven o'clock"
+//! 
+//! The words are English, which is a fair length estimate for most
+//! languages. With `PacingConfig::spell_out_numbers` the script itself is
+//! rewritten the same way before atomization, so a voice that would read
+//! "3:30" as a ratio says the words the estimate was based on.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::numbers::expand_numbers;
+//! 
+//! assert_eq!(expand_numbers("Count from 1 to 10."), "Count from one to ten.");
+//! assert_eq!(expand_numbers("Rest until 3:30, the 2nd bell."), "Rest until three thirty, the second bell.");
+//! ```
+
+use alloc::borrow::Cow;
+
+use crate::prelude::*;
+
+const ONES: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+
+const TENS: [&str; 10] = ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+
+/// Suffixes that make a number an ordinal
+const ORDINAL_SUFFIXES: [&str; 4] = ["st", "nd", "rd", "th"];
+
+const SCALES: [(u64, &str); 6] = [
+    (1_000_000_000_000_000_000, "quintillion"),
+    (1_000_000_000_000_000, "quadrillion"),
+    (1_000_000_000_000, "trillion"),
+    (1_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1_000, "thousand"),
+];
+
+// ============================================
+// Expansion
+// ============================================
+
+/// `text` with every number and time written out in English words
+/// 
+/// Borrows `text` when it has no digits.
+pub fn expand_numbers(text: &str) -> Cow<'_, str> {
+    if !text.contains(|c: char| c.is_ascii_digit()) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut out = String::with_capacity(text.len() * 2);
+    let mut last = 0;
+    let mut position = 0;
+    while position < text.len() {
+        if !text.as_bytes()[position].is_ascii_digit() {
+            position += 1;
+            continue;
+        }
+        // Digits inside a word ("mp3") are not read as numbers
+        if !is_word_start(text, position) {
+            position += digit_run(text, position);
+            continue;
+        }
+        let (words, end) = spoken_number(text, position);
+        out.push_str(&text[last..position]);
+        out.push_str(&words);
+        // "3pm" is read "three pm"
+        if text[end..].starts_with(char::is_alphabetic) {
+            out.push(' ');
+        }
+        last = end;
+        position = end;
+    }
+    out.push_str(&text[last..]);
+    Cow::Owned(out)
+}
+
+/// English words for a non-negative integer
+pub fn number_words(n: u64) -> String {
+    if n < 20 {
+        return ONES[n as usize].to_string();
+    }
+    if n < 100 {
+        let tens = TENS[(n / 10) as usize];
+        return match n % 10 {
+            0 => tens.to_string(),
+            ones => format!("{}-{}", tens, ONES[ones as usize]),
+        };
+    }
+    let (scale, name) = SCALES.iter()
+        .find(|(scale, _)| n >= *scale)
+        .copied()
+        .unwrap_or((100, "hundred"));
+    match n % scale {
+        0 => format!("{} {}", number_words(n / scale), name),
+        rest => format!("{} {} {}", number_words(n / scale), name, number_words(rest)),
+    }
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Words for the time or number starting at `start` (a digit at the start
+/// of a word), and the byte offset where it ends
+/// 
+/// A time is one or two hour digits, a colon, and two minute digits, as a
+/// word of its own. A number has optional thousands separators, decimals,
+/// and ordinal suffix.
+fn spoken_number(text: &str, start: usize) -> (String, usize) {
+    let digits = digit_run(text, start);
+    let hour_end = start + digits;
+    if digits <= 2
+        && text[hour_end..].starts_with(':')
+        && digit_run(text, hour_end + 1) == 2
+        && is_word_end(text, hour_end + 3)
+    {
+        let hour = integer_words(&text[start..hour_end]);
+        let words = match &text[hour_end + 1..hour_end + 3] {
+            "00" => format!("{} o'clock", hour),
+            minute if minute.starts_with('0') => format!("{} oh {}", hour, integer_words(&minute[1..])),
+            minute => format!("{} {}", hour, integer_words(minute)),
+        };
+        return (words, hour_end + 3);
+    }
+
+    let mut end = hour_end;
+    if digits <= 3 {
+        while text[end..].starts_with(',') && digit_run(text, end + 1) >= 3 {
+            end += 4;
+        }
+    }
+    let mut words = integer_words(&text[start..end]);
+    let decimals = if text[end..].starts_with('.') { digit_run(text, end + 1) } else { 0 };
+    if decimals > 0 {
+        words.push_str(" point");
+        for digit in text[end + 1..end + 1 + decimals].bytes() {
+            words.push(' ');
+            words.push_str(ONES[usize::from(digit - b'0')]);
+        }
+        end += 1 + decimals;
+    }
+    if let Some(suffix) = ORDINAL_SUFFIXES.iter().find(|suffix| text[end..].starts_with(*suffix)) {
+        if is_word_end(text, end + suffix.len()) {
+            if decimals == 0 {
+                words = ordinal(&words);
+            }
+            end += suffix.len();
+        }
+    }
+    (words, end)
+}
+
+/// Number of ASCII digits starting at `at`
+fn digit_run(text: &str, at: usize) -> usize {
+    text.get(at..).map_or(0, |rest| rest.bytes().take_while(u8::is_ascii_digit).count())
+}
+
+/// Whether no word character comes right before `at`
+fn is_word_start(text: &str, at: usize) -> bool {
+    !text[..at].chars().next_back().is_some_and(is_word_char)
+}
+
+/// Whether no word character comes right after `at`
+fn is_word_end(text: &str, at: usize) -> bool {
+    !text[at..].chars().next().is_some_and(is_word_char)
+}
+
+/// Whether `c` is part of a word (letter, digit, or underscore)
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Words for a run of digits (with optional thousands separators)
+/// 
+/// Numbers too large for a `u64` are read digit by digit.
+fn integer_words(digits: &str) -> String {
+    let digits: String = digits.chars().filter(|c| *c != ',').collect();
+    match digits.parse() {
+        Ok(n) => number_words(n),
+        Err(_) => digits.chars()
+            .map(|digit| ONES[usize::from(digit as u8 - b'0')])
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
+
+/// The ordinal form of number words ("twenty-one" → "twenty-first")
+fn ordinal(words: &str) -> String {
+    let split = words.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, last) = words.split_at(split);
+    let last = match last {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        last => match last.strip_suffix('y') {
+            Some(stem) => format!("{}ieth", stem),
+            None => format!("{}th", last),
+        },
+    };
+    format!("{}{}", head, last)
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pacing_engine::{DurationModel, MeditationPacer, PacingConfig};
+
+    #[test]
+    fn test_number_words() {
+        assert_eq!(number_words(0), "zero");
+        assert_eq!(number_words(42), "forty-two");
+        assert_eq!(number_words(115), "one hundred fifteen");
+        assert_eq!(number_words(2_000_007), "two million seven");
+        assert_eq!(number_words(u64::MAX).split(' ').next(), Some("eighteen"));
+    }
+
+    #[test]
+    fn test_expand_numbers() {
+        assert_eq!(
+            expand_numbers("Breathe 4 times, 1,500 steps, 2.25 miles, the 21st day, at 7:00 or 6:05."),
+            "Breathe four times, one thousand five hundred steps, two point two five miles, \
+             the twenty-first day, at seven o'clock or six oh five."
+        );
+        assert_eq!(expand_numbers("Meet at 3pm. Inhale 4:7."), "Meet at three pm. Inhale four:seven.");
+        assert_eq!(expand_numbers("Play the mp3 or track_2 at 10:30."), "Play the mp3 or track_2 at ten thirty.");
+        assert!(matches!(expand_numbers("No digits here."), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_words_are_counted_as_written() {
+        let script = "Walk 1,500 steps with the NHS team.";
+        let spelled = "Walk one thousand five hundred steps with the en aitch ess team.";
+        let pacer = MeditationPacer::new();
+        assert_eq!(pacer.calculate_pacing(script.to_string(), 20.0).total_words, 7);
+
+        // Speech under the words-per-minute model still counts spoken words
+        let pacer = MeditationPacer::with_config(PacingConfig {
+            duration_model: DurationModel::WordsPerMinute(120.0),
+            ..PacingConfig::default()
+        });
+        let written = pacer.calculate_pacing(script.to_string(), 20.0);
+        let spoken = pacer.calculate_pacing(spelled.to_string(), 20.0);
+        assert_eq!(written.total_words, 7);
+        assert_eq!(written.estimated_speech_seconds, spoken.estimated_speech_seconds);
+    }
+}
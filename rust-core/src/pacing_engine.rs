@@ -11,8 +11,14 @@
 //! based on punctuation weights:
 //! 
 //! - Comma (,): Weight 1 (short pause)
+//! - Semicolon (;) and dash (— –): Weight 2 (clause pause)
 //! - Sentence end (. ? !): Weight 3 (standard pause)  
+//! - Ellipsis (... …): Weight 4 (trailing-off pause)
 //! - Paragraph/newline: Weight 5 (long pause)
+//! - Quoted span edges: Weight 1 (soft pause, with `soften_quote_pauses`)
+//! 
+//! Weights are looked up in `PacingConfig::weights`, so they can be tuned
+//! per style without touching the enum.
 //! 
 //! ## Key Constants (Production-Calibrated)
 //! 
@@ -20,6 +26,16 @@
 //! - **70 words per minute** target density (50/50 speech-to-silence ratio)
 //! - **1.1x safety buffer** on silence (TTS often faster than expected)
 //! 
+//! ## Layout
+//! 
+//! This file holds `MeditationPacer` and the pacing entry points. The
+//! pieces they are built from live in submodules and are re-exported here:
+//! 
+//! - `config`: `PacingConfig` and everything it is built from
+//! - `atomize`: splitting text into speech atoms
+//! - `timing`: speech-duration estimates and silence distribution
+//! - `render`: break tags and the `PacingResult` built around the SSML
+//! 
 //! ## Example
 //! 
 //! ```rust
@@ -32,8 +48,34 @@
 //! );
 //! ```
 
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
+use core::cmp::Reverse;
+use core::fmt;
+use core::ops::Range;
+
+#[cfg(feature = "std")]
 use regex::Regex;
 
+use crate::acronyms::{expand_acronyms, spell_acronyms};
+use crate::locale::{Locale, WordCounting};
+use crate::numbers::expand_numbers;
+use crate::prelude::*;
+use crate::sanitize::{is_symbol_char, SymbolHandling};
+
+mod atomize;
+mod config;
+mod render;
+mod timing;
+#[cfg(test)]
+mod tests;
+
+pub use self::atomize::*;
+pub use self::config::*;
+pub use self::render::*;
+pub use self::timing::*;
+
 // ============================================
 // Constants (Production-Calibrated)
 // ============================================
@@ -43,10 +85,18 @@ use regex::Regex;
 /// 310 / 26 ≈ 12 chars/sec
 const CHARS_PER_SECOND: f64 = 12.0;
 
+/// Syllable-based speech rate (syllables per second)
+/// At ~3 characters per English syllable this matches 12 chars/sec
+const SYLLABLES_PER_SECOND: f64 = 4.0;
+
+/// Spoken words per minute used for word-based comparison estimates
+/// 12 chars/sec at ~5.2 chars per word ≈ 140 words/minute of speech
+const SPEECH_WORDS_PER_MINUTE: f64 = 140.0;
+
 /// Target words per minute for LLM prompts
 /// This ensures a 50/50 speech-to-silence ratio
 /// Formula: (60 seconds / 2) * 2.3 words/sec ≈ 70 words/minute
-const TARGET_WORDS_PER_MINUTE: f64 = 70.0;
+pub(crate) const TARGET_WORDS_PER_MINUTE: f64 = 70.0;
 
 /// Safety buffer multiplier for silence
 /// TTS is often faster than estimated, so we add 10% extra silence
@@ -58,135 +108,92 @@ const MAX_BREAK_SECONDS: f64 = 3.0;
 /// Minimum break duration (below this is imperceptible)
 const MIN_BREAK_SECONDS: f64 = 0.1;
 
-// ============================================
-// Punctuation Weights
-// ============================================
+/// Default lowest acceptable share of silence in a session
+const MIN_SILENCE_RATIO: f64 = 0.2;
+
+/// Default highest acceptable share of silence in a session
+const MAX_SILENCE_RATIO: f64 = 0.9;
 
-/// Weight for comma pauses (short breath)
-const WEIGHT_COMMA: u32 = 1;
+/// Default shortest target duration (anything shorter leaves no room to
+/// pause)
+const MIN_TARGET_SECONDS: f64 = 5.0;
 
-/// Weight for sentence-ending punctuation (natural pause)
-const WEIGHT_SENTENCE: u32 = 3;
+/// Default longest target duration (three hours)
+const MAX_TARGET_SECONDS: f64 = 10_800.0;
 
-/// Weight for paragraph breaks (long contemplative pause)
-const WEIGHT_PARAGRAPH: u32 = 5;
+/// Most decimal places a break tag is written with
+const MAX_BREAK_DECIMALS: u32 = 6;
+
+/// Iteration cap for the exact-duration solver
+const EXACT_SOLVER_MAX_ITERATIONS: usize = 20;
 
 // ============================================
 // Types
 // ============================================
 
-/// The type of punctuation that ends a speech atom
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum PunctuationType {
-    /// Comma - short pause
-    Comma,
-    /// Period, question mark, exclamation - standard pause
-    SentenceEnd,
-    /// Newline or paragraph break - long pause
-    Paragraph,
-    /// No punctuation (end of text)
-    None,
+/// Why a pacing request was rejected by the fallible API
+#[derive(Debug, Clone, PartialEq)]
+pub enum PacingError {
+    /// The text contains nothing to speak
+    EmptyText,
+    /// The target duration is NaN or infinite
+    NonFiniteDuration(f64),
+    /// The target duration is zero or negative
+    NegativeDuration(f64),
+    /// The target duration is outside `PacingConfig::target_limits`
+    TargetOutOfRange {
+        /// Requested session length
+        target_seconds: f64,
+        /// Configured shortest target
+        min_seconds: f64,
+        /// Configured longest target
+        max_seconds: f64,
+    },
+    /// The pacer was created from a config that failed validation
+    InvalidConfig(Vec<ConfigError>),
+    /// The text takes longer to speak than the whole target duration
+    TextTooLongForTarget {
+        /// Estimated speech time
+        speech_seconds: f64,
+        /// Requested session length
+        target_seconds: f64,
+    },
+    /// The text contains a word or phrase configured as
+    /// `BannedWordAction::Reject`
+    BannedWord {
+        /// The configured word or phrase
+        phrase: String,
+        /// The text as it appeared in the script
+        original: String,
+    },
 }
 
-impl PunctuationType {
-    /// Get the silence weight for this punctuation type
-    pub fn weight(&self) -> u32 {
+impl fmt::Display for PacingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            PunctuationType::Comma => WEIGHT_COMMA,
-            PunctuationType::SentenceEnd => WEIGHT_SENTENCE,
-            PunctuationType::Paragraph => WEIGHT_PARAGRAPH,
-            PunctuationType::None => 0,
-        }
-    }
-}
-
-/// A single "atom" of speech - text followed by punctuation
-#[derive(Debug, Clone)]
-pub struct SpeechAtom {
-    /// The text content (without trailing punctuation)
-    pub text: String,
-    /// The punctuation that ends this atom
-    pub punctuation: PunctuationType,
-    /// The original punctuation character(s)
-    pub punctuation_char: String,
-    /// Calculated silence weight
-    pub weight: u32,
-    /// Word count in this atom
-    pub word_count: usize,
-}
-
-impl SpeechAtom {
-    /// Create a new speech atom
-    pub fn new(text: String, punctuation: PunctuationType, punctuation_char: String) -> Self {
-        let weight = punctuation.weight();
-        let word_count = count_words(&text);
-        Self {
-            text,
-            punctuation,
-            punctuation_char,
-            weight,
-            word_count,
-        }
-    }
-}
-
-/// Configuration for the pacing engine
-#[derive(Debug, Clone)]
-pub struct PacingConfig {
-    /// Character-based speech rate (chars per second, excluding whitespace)
-    pub chars_per_second: f64,
-    /// Safety buffer multiplier for silence (e.g., 1.1 = 10% extra)
-    pub silence_safety_buffer: f64,
-    /// Maximum seconds per break tag
-    pub max_break_seconds: f64,
-    /// Minimum seconds per break tag
-    pub min_break_seconds: f64,
-    /// Weight for comma pauses
-    pub weight_comma: u32,
-    /// Weight for sentence-end pauses
-    pub weight_sentence: u32,
-    /// Weight for paragraph pauses
-    pub weight_paragraph: u32,
-}
-
-impl Default for PacingConfig {
-    fn default() -> Self {
-        Self {
-            chars_per_second: CHARS_PER_SECOND,
-            silence_safety_buffer: SILENCE_SAFETY_BUFFER,
-            max_break_seconds: MAX_BREAK_SECONDS,
-            min_break_seconds: MIN_BREAK_SECONDS,
-            weight_comma: WEIGHT_COMMA,
-            weight_sentence: WEIGHT_SENTENCE,
-            weight_paragraph: WEIGHT_PARAGRAPH,
+            PacingError::EmptyText => write!(f, "text contains nothing to speak"),
+            PacingError::NonFiniteDuration(d) => write!(f, "target duration {} is not finite", d),
+            PacingError::NegativeDuration(d) => write!(f, "target duration {}s is not positive", d),
+            PacingError::TargetOutOfRange { target_seconds, min_seconds, max_seconds } => write!(
+                f,
+                "target duration {}s is outside the accepted {}s to {}s",
+                target_seconds, min_seconds, max_seconds,
+            ),
+            PacingError::InvalidConfig(errors) => {
+                let reasons: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                write!(f, "invalid pacing config: {}", reasons.join("; "))
+            }
+            PacingError::TextTooLongForTarget { speech_seconds, target_seconds } => write!(
+                f,
+                "text needs {:.1}s of speech but the target is {:.1}s",
+                speech_seconds, target_seconds,
+            ),
+            PacingError::BannedWord { original, .. } => write!(f, "text contains banned phrase \"{}\"", original),
         }
     }
 }
 
-/// Result of the pacing calculation
-#[derive(Debug, Clone)]
-pub struct PacingResult {
-    /// The final SSML string
-    pub ssml: String,
-    /// Total character count (excluding whitespace)
-    pub total_chars: usize,
-    /// Total word count
-    pub total_words: usize,
-    /// Estimated speech duration in seconds (based on char count)
-    pub estimated_speech_seconds: f64,
-    /// Raw silence budget before safety buffer
-    pub raw_silence_budget: f64,
-    /// Final silence budget after safety buffer (1.1x)
-    pub final_silence_budget: f64,
-    /// Total silence actually added in seconds
-    pub total_silence_added: f64,
-    /// Target duration that was requested
-    pub target_duration_seconds: f64,
-    /// Actual estimated total duration
-    pub estimated_total_seconds: f64,
-    /// Number of speech atoms
-    pub atom_count: usize,
-}
+impl core::error::Error for PacingError {}
 
 // ============================================
 // Main Pacer Struct
@@ -197,8 +204,15 @@ pub struct PacingResult {
 /// This struct encapsulates all pacing logic and can be easily
 /// bridged to Swift or other languages.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
 pub struct MeditationPacer {
     config: PacingConfig,
+    /// Problems found when the config was set (already repaired in `config`)
+    config_errors: Vec<ConfigError>,
+    /// Atom splitter built when the config was set
+    splitter: AtomSplitter,
+    /// Banned-word matcher built when the config was set
+    word_filter: Option<WordFilter>,
 }
 
 impl MeditationPacer {
@@ -206,12 +220,105 @@ impl MeditationPacer {
     pub fn new() -> Self {
         Self {
             config: PacingConfig::default(),
+            config_errors: Vec::new(),
+            splitter: AtomSplitter::Delimiters { extra: Vec::new(), markers: Vec::new() },
+            word_filter: None,
         }
     }
 
     /// Create a new pacer with custom configuration
+    /// 
+    /// The config is validated first. Invalid fields are reset to their
+    /// defaults so pacing never produces NaN durations or hangs; the
+    /// problems are kept in `config_errors` and make `try_calculate_pacing`
+    /// fail.
     pub fn with_config(config: PacingConfig) -> Self {
-        Self { config }
+        let (config, config_errors) = match config.validate() {
+            Ok(()) => (config, Vec::new()),
+            Err(errors) => (config.repaired(&errors), errors),
+        };
+        Self {
+            splitter: AtomSplitter::for_config(&config),
+            word_filter: WordFilter::for_config(&config),
+            config,
+            config_errors,
+        }
+    }
+
+    /// Create a new pacer, rejecting an invalid configuration
+    pub fn try_with_config(config: PacingConfig) -> Result<Self, Vec<ConfigError>> {
+        config.validate()?;
+        Ok(Self::with_config(config))
+    }
+
+    /// Problems found in the config this pacer was created with
+    pub fn config_errors(&self) -> &[ConfigError] {
+        &self.config_errors
+    }
+
+    /// This pacer with `break_budget` lifted (for paths that keep to the
+    /// budget themselves)
+    pub(crate) fn without_break_budget(&self) -> Self {
+        let mut pacer = self.clone();
+        pacer.config.break_budget = BreakBudget::default();
+        pacer
+    }
+
+    /// This pacer without intro and outro pauses (for paths that time every
+    /// pause themselves)
+    pub(crate) fn without_framing(&self) -> Self {
+        let mut pacer = self.clone();
+        pacer.config.intro_pause_seconds = 0.0;
+        pacer.config.outro_pause_seconds = 0.0;
+        pacer
+    }
+
+    /// The configuration this pacer uses (after any repairs)
+    pub fn config(&self) -> &PacingConfig {
+        &self.config
+    }
+
+    /// Replace the configuration, validating it like `with_config`
+    pub fn set_config(&mut self, config: PacingConfig) {
+        *self = Self::with_config(config);
+    }
+
+    /// Set the character-based speech rate (clears any language preset)
+    pub fn set_chars_per_second(&mut self, chars_per_second: f64) {
+        self.update_config(|config| {
+            config.chars_per_second = chars_per_second;
+            config.language = None;
+        });
+    }
+
+    /// Set the speech rate from a language preset
+    pub fn set_language(&mut self, language: Language) {
+        self.update_config(|config| config.language = Some(language));
+    }
+
+    /// Set the multiplier applied to the silence budget
+    pub fn set_silence_safety_buffer(&mut self, buffer: f64) {
+        self.update_config(|config| config.silence_safety_buffer = buffer);
+    }
+
+    /// Set the shortest and longest break tags
+    pub fn set_break_limits(&mut self, min_break_seconds: f64, max_break_seconds: f64) {
+        self.update_config(|config| {
+            config.min_break_seconds = min_break_seconds;
+            config.max_break_seconds = max_break_seconds;
+        });
+    }
+
+    /// Set the weight of one punctuation class
+    pub fn set_weight(&mut self, punctuation: PunctuationType, weight: u32) {
+        self.update_config(|config| config.set_weight(punctuation, weight));
+    }
+
+    /// Edit a copy of the config and re-validate it
+    fn update_config(&mut self, edit: impl FnOnce(&mut PacingConfig)) {
+        let mut config = self.config.clone();
+        edit(&mut config);
+        self.set_config(config);
     }
 
     /// Format meditation text into SSML with calculated breaks
@@ -240,166 +347,308 @@ impl MeditationPacer {
     /// B. **Safety Buffer**: Apply 1.1x multiplier to silence budget
     /// C. **Distribution**: Distribute silence based on punctuation weights
     pub fn calculate_pacing(&self, text: String, target_duration_seconds: f64) -> PacingResult {
+        self.calculate_pacing_with_overrides(text, target_duration_seconds, &PacingOverrides::new())
+    }
+
+    /// Billing characters the paced SSML would cost, before synthesis
+    /// 
+    /// Equivalent to `calculate_pacing(..).billing_characters()`.
+    pub fn estimate_cost(&self, text: String, target_duration_seconds: f64) -> usize {
+        self.calculate_pacing(text, target_duration_seconds).billing_characters()
+    }
+
+    /// Calculate pacing with per-atom weight or pause overrides
+    /// 
+    /// Pinned pauses are honored exactly; the rest of the silence budget
+    /// is distributed across the remaining atoms by weight.
+    pub fn calculate_pacing_with_overrides(
+        &self,
+        text: String,
+        target_duration_seconds: f64,
+        overrides: &PacingOverrides,
+    ) -> PacingResult {
         // Step A: Sanitize & Analyze
-        let atoms = self.atomize_text(&text);
-        
-        // Count characters (excluding whitespace) for accurate TTS estimation
-        let total_chars: usize = atoms.iter()
-            .map(|a| a.text.chars().filter(|c| !c.is_whitespace()).count())
-            .sum();
-        let total_words: usize = atoms.iter().map(|a| a.word_count).sum();
-        
-        // Calculate total weight (excluding last atom - no break at end)
-        let total_weight: u32 = if atoms.len() > 1 {
-            atoms.iter().take(atoms.len() - 1).map(|a| a.weight).sum()
-        } else {
-            0
+        let (atoms, replacements) = self.atomize_filtered(&text);
+        let mut result = self.pace_atoms(&atoms, target_duration_seconds, overrides);
+        result.replacements = replacements;
+        result
+    }
+
+    /// Pace a batch of `(text, target_duration_seconds)` scripts
+    /// 
+    /// Every script is paced with this pacer's config. Results are in input
+    /// order. With the `parallel` feature, scripts are paced across threads.
+    pub fn calculate_batch(&self, scripts: &[(String, f64)]) -> Vec<PacingResult> {
+        let pace = |(text, target): &(String, f64)| {
+            let (atoms, replacements) = self.atomize_filtered(text);
+            let mut result = self.pace_atoms(&atoms, *target, &PacingOverrides::new());
+            result.replacements = replacements;
+            result
         };
         
-        // Estimate speech time using character-based formula
-        // Production data: 12 chars/sec
-        let estimated_speech_seconds = total_chars as f64 / self.config.chars_per_second;
-        
-        // Step B: Calculate silence budget with safety buffer
-        let raw_silence_budget = (target_duration_seconds - estimated_speech_seconds).max(0.0);
-        let final_silence_budget = raw_silence_budget * self.config.silence_safety_buffer;
-        
-        // Calculate time per weight unit
-        let time_per_unit = if total_weight > 0 {
-            final_silence_budget / total_weight as f64
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            scripts.par_iter().map(pace).collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            scripts.iter().map(pace).collect()
+        }
+    }
+
+    /// Steps B-D of the pipeline for already atomized text
+    pub(crate) fn pace_atoms(
+        &self,
+        atoms: &[SpeechAtom],
+        target_duration_seconds: f64,
+        overrides: &PacingOverrides,
+    ) -> PacingResult {
+        let (target_duration_seconds, range_warning) = self.limit_target(target_duration_seconds);
+        let (speech, pauses, raw_silence_budget, final_silence_budget) =
+            self.plan_pauses(atoms, target_duration_seconds, overrides);
+        
+        // Step D: Build SSML with distributed silence
+        let mut result = self.assemble_result(
+            atoms,
+            &pauses,
+            &speech,
+            target_duration_seconds,
+            raw_silence_budget,
+            final_silence_budget,
+        );
+        result.warnings.extend(self.check_silence_ratio(
+            result.total_words,
+            result.estimated_speech_seconds,
+            target_duration_seconds,
+        ));
+        result.warnings.extend(range_warning);
+        result
+    }
+
+    /// The target to pace to under `target_limits`, and the warning for an
+    /// out-of-range request
+    /// 
+    /// Non-finite targets cannot be rendered, so they are always paced at a
+    /// limit: infinities at the nearest one, NaN at `min_seconds`.
+    fn limit_target(&self, target_duration_seconds: f64) -> (f64, Option<PacingWarning>) {
+        let limits = self.config.target_limits;
+        if limits.contains(target_duration_seconds) {
+            return (target_duration_seconds, None);
+        }
+        let paced_seconds = if target_duration_seconds.is_nan() {
+            limits.min_seconds
+        } else if limits.clamp || target_duration_seconds.is_infinite() {
+            target_duration_seconds.clamp(limits.min_seconds, limits.max_seconds)
         } else {
-            0.0
+            target_duration_seconds
         };
-        
-        // Step C: Build SSML with distributed silence
-        let mut ssml = String::with_capacity(text.len() * 2);
-        let mut total_silence_added = 0.0;
-        let atom_count = atoms.len();
-        
-        for (i, atom) in atoms.iter().enumerate() {
-            let is_last = i == atom_count - 1;
-            
-            // Add the text
-            ssml.push_str(&atom.text);
-            ssml.push_str(&atom.punctuation_char);
+        let warning = PacingWarning::TargetOutOfRange {
+            target_seconds: target_duration_seconds,
+            min_seconds: limits.min_seconds,
+            max_seconds: limits.max_seconds,
+            paced_seconds,
+        };
+        (paced_seconds, Some(warning))
+    }
+
+    /// Calculate pacing that lands within `tolerance` seconds of the target
+    /// 
+    /// Unlike `calculate_pacing`, no safety buffer is applied. The silence
+    /// budget is re-solved against what the break tags actually render to
+    /// (after splitting, rounding, and minimum-length clipping) until the
+    /// estimate is within tolerance, or the best attempt after
+    /// `EXACT_SOLVER_MAX_ITERATIONS` passes is returned.
+    /// 
+    /// `total_silence_added` and `estimated_total_seconds` in the result use
+    /// the rendered (rounded) break durations. The target is checked against
+    /// `target_limits` first, as in `calculate_pacing`.
+    pub fn calculate_pacing_exact(
+        &self,
+        text: String,
+        target_duration_seconds: f64,
+        tolerance_seconds: f64,
+    ) -> PacingResult {
+        let (target_duration_seconds, range_warning) = self.limit_target(target_duration_seconds);
+        let (atoms, replacements) = self.atomize_filtered(&text);
+        let speech = self.speech_seconds(&atoms);
+        let estimated_speech_seconds: f64 = speech.iter().sum();
+        let raw_silence_budget = (target_duration_seconds - estimated_speech_seconds - self.framing_pauses()).max(0.0);
+        let overrides = PacingOverrides::new();
+        
+        let mut budget = raw_silence_budget;
+        let mut best: Option<(f64, f64, Vec<f64>, f64)> = None;
+        let intro: f64 = self.intro_break_tags().iter().sum();
+        
+        for _ in 0..EXACT_SOLVER_MAX_ITERATIONS {
+            let pauses = self.distribute_silence(&atoms, budget, &overrides);
+            let rendered = intro + self.break_tag_durations(&pauses).iter().flatten().sum::<f64>();
+            let error = target_duration_seconds - (estimated_speech_seconds + rendered);
             
-            // DO NOT add break after the very last atom
-            if !is_last && atom.weight > 0 && time_per_unit > 0.0 {
-                let break_duration = atom.weight as f64 * time_per_unit;
-                
-                // Only add break if it's above minimum threshold
-                if break_duration >= self.config.min_break_seconds {
-                    let break_ssml = self.format_break_tags(break_duration);
-                    ssml.push_str(&break_ssml);
-                    total_silence_added += break_duration;
-                }
+            if best.as_ref().is_none_or(|(best_error, ..)| error.abs() < best_error.abs()) {
+                best = Some((error, budget, pauses, rendered));
             }
-            
-            // Add space after punctuation (except at end)
-            if !is_last {
-                ssml.push(' ');
+            if error.abs() <= tolerance_seconds {
+                break;
             }
+            budget = (budget + error).max(0.0);
         }
         
-        PacingResult {
-            ssml,
-            total_chars,
-            total_words,
-            estimated_speech_seconds,
+        let (_, budget, pauses, rendered) = best.unwrap_or_default();
+        let mut result = self.assemble_result(
+            &atoms,
+            &pauses,
+            &speech,
+            target_duration_seconds,
             raw_silence_budget,
-            final_silence_budget,
-            total_silence_added,
+            budget,
+        );
+        result.total_silence_added = rendered;
+        result.estimated_total_seconds = estimated_speech_seconds + rendered;
+        result.warnings.extend(self.check_silence_ratio(
+            result.total_words,
+            estimated_speech_seconds,
             target_duration_seconds,
-            estimated_total_seconds: estimated_speech_seconds + total_silence_added,
-            atom_count,
-        }
+        ));
+        result.warnings.extend(range_warning);
+        result.replacements = replacements;
+        result
     }
 
-    /// Atomize text into speech atoms based on punctuation
-    fn atomize_text(&self, text: &str) -> Vec<SpeechAtom> {
-        let mut atoms = Vec::new();
-        
-        // Regex to split on punctuation while capturing the punctuation
-        // Matches: comma, period, question, exclamation, or newline
-        let re = Regex::new(r"([^,.\?!\n]+)([,.\?!\n]*)").unwrap();
-        
-        for cap in re.captures_iter(text) {
-            let content = cap.get(1).map_or("", |m| m.as_str()).trim();
-            let punct = cap.get(2).map_or("", |m| m.as_str());
-            
-            if content.is_empty() {
-                continue;
-            }
-            
-            let (punct_type, punct_char) = classify_punctuation(punct);
-            
-            atoms.push(SpeechAtom::new(
-                content.to_string(),
-                punct_type,
-                punct_char,
-            ));
+    /// Re-pace a script using its measured speech duration
+    /// 
+    /// For the second render of a two-pass pipeline: after synthesizing
+    /// once, the real speech length is known, so the silence budget is
+    /// exactly `target - measured` with no safety buffer.
+    pub fn repace_with_measured(
+        &self,
+        text: String,
+        measured_speech_seconds: f64,
+        target_duration_seconds: f64,
+    ) -> PacingResult {
+        let (target_duration_seconds, range_warning) = self.limit_target(target_duration_seconds);
+        let (atoms, replacements) = self.atomize_filtered(&text);
+        let silence_budget = (target_duration_seconds - measured_speech_seconds - self.framing_pauses()).max(0.0);
+        let pauses = self.distribute_silence(&atoms, silence_budget, &PacingOverrides::new());
+        
+        // Spread the measured total over atoms in proportion to the estimate
+        let mut speech = self.speech_seconds(&atoms);
+        let estimated: f64 = speech.iter().sum();
+        if estimated > 0.0 {
+            speech.iter_mut().for_each(|s| *s *= measured_speech_seconds / estimated);
         }
         
-        atoms
+        let mut result = self.assemble_result(
+            &atoms,
+            &pauses,
+            &speech,
+            target_duration_seconds,
+            silence_budget,
+            silence_budget,
+        );
+        result.warnings.extend(self.check_silence_ratio(
+            result.total_words,
+            measured_speech_seconds,
+            target_duration_seconds,
+        ));
+        result.warnings.extend(range_warning);
+        result.replacements = replacements;
+        result
     }
 
-    /// Format break duration into SSML break tags
+    /// Calculate pacing, rejecting input that cannot produce a usable session
     /// 
-    /// Since ElevenLabs has a max of 3 seconds per break,
-    /// longer durations are split into multiple tags.
-    fn format_break_tags(&self, total_seconds: f64) -> String {
-        let mut result = String::new();
-        let mut remaining = total_seconds;
+    /// Unlike `calculate_pacing`, which always returns its best effort, this
+    /// fails on empty text, a non-finite or non-positive target, an unusable
+    /// config, or text that is longer to speak than the target.
+    pub fn try_calculate_pacing(
+        &self,
+        text: String,
+        target_duration_seconds: f64,
+    ) -> Result<PacingResult, PacingError> {
+        if !target_duration_seconds.is_finite() {
+            return Err(PacingError::NonFiniteDuration(target_duration_seconds));
+        }
+        if target_duration_seconds <= 0.0 {
+            return Err(PacingError::NegativeDuration(target_duration_seconds));
+        }
+        if !self.config_errors.is_empty() {
+            return Err(PacingError::InvalidConfig(self.config_errors.clone()));
+        }
         
-        while remaining > self.config.min_break_seconds {
-            let break_duration = remaining.min(self.config.max_break_seconds);
-            result.push_str(&format!("<break time=\"{:.1}s\"/>", break_duration));
-            remaining -= break_duration;
+        let (atoms, replacements) = self.atomize_filtered(&text);
+        if let Some(rejected) = replacements.iter().find(|r| {
+            self.config.banned_words.get(&r.phrase) == Some(&BannedWordAction::Reject)
+        }) {
+            return Err(PacingError::BannedWord {
+                phrase: rejected.phrase.clone(),
+                original: rejected.original.clone(),
+            });
+        }
+        if atoms.is_empty() {
+            return Err(PacingError::EmptyText);
         }
         
-        result
+        let mut result = self.pace_atoms(&atoms, target_duration_seconds, &PacingOverrides::new());
+        result.replacements = replacements;
+        // Against the target actually paced, which clamping may shorten
+        let (paced_seconds, _) = self.limit_target(target_duration_seconds);
+        if result.estimated_speech_seconds > paced_seconds {
+            return Err(PacingError::TextTooLongForTarget {
+                speech_seconds: result.estimated_speech_seconds,
+                target_seconds: paced_seconds,
+            });
+        }
+        Ok(result)
     }
-}
 
-impl Default for MeditationPacer {
-    fn default() -> Self {
-        Self::new()
+    /// Calculate pacing for a target given in minutes
+    /// 
+    /// Like `try_calculate_pacing`, and additionally rejects targets
+    /// outside `target_limits` (e.g. a 2-second or 4-hour request) with
+    /// `PacingError::TargetOutOfRange`.
+    pub fn calculate_pacing_minutes(
+        &self,
+        text: String,
+        target_duration_minutes: f64,
+    ) -> Result<PacingResult, PacingError> {
+        let target_duration_seconds = target_duration_minutes * 60.0;
+        let limits = self.config.target_limits;
+        if target_duration_seconds.is_finite() && target_duration_seconds > 0.0 && !limits.contains(target_duration_seconds) {
+            return Err(PacingError::TargetOutOfRange {
+                target_seconds: target_duration_seconds,
+                min_seconds: limits.min_seconds,
+                max_seconds: limits.max_seconds,
+            });
+        }
+        self.try_calculate_pacing(text, target_duration_seconds)
     }
-}
 
-// ============================================
-// Helper Functions
-// ============================================
-
-/// Count words in a string
-fn count_words(text: &str) -> usize {
-    text.split_whitespace().count()
+    /// Calculate pacing, rejecting out-of-range silence ratios in strict mode
+    /// 
+    /// With `silence_ratio_limits.strict` off this always succeeds and the
+    /// ratio warning (if any) is left in `PacingResult::warnings`.
+    pub fn calculate_pacing_checked(
+        &self,
+        text: String,
+        target_duration_seconds: f64,
+    ) -> Result<PacingResult, PacingWarning> {
+        let result = self.calculate_pacing(text, target_duration_seconds);
+        if self.config.silence_ratio_limits.strict {
+            let violation = result.warnings.iter()
+                .find(|w| matches!(w, PacingWarning::SilenceRatioOutOfRange { .. }));
+            if let Some(warning) = violation {
+                return Err(warning.clone());
+            }
+        }
+        Ok(result)
+    }
 }
 
-/// Classify punctuation and return type + character
-fn classify_punctuation(punct: &str) -> (PunctuationType, String) {
-    if punct.is_empty() {
-        return (PunctuationType::None, String::new());
-    }
-    
-    // Check for paragraph/newline first (higher priority)
-    if punct.contains('\n') {
-        return (PunctuationType::Paragraph, punct.to_string());
-    }
-    
-    // Check for sentence-ending punctuation
-    if punct.contains('.') || punct.contains('?') || punct.contains('!') {
-        // Return just the first punctuation mark
-        let char = punct.chars().next().unwrap_or('.');
-        return (PunctuationType::SentenceEnd, char.to_string());
-    }
-    
-    // Check for comma
-    if punct.contains(',') {
-        return (PunctuationType::Comma, ",".to_string());
+impl Default for MeditationPacer {
+    fn default() -> Self {
+        Self::new()
     }
-    
-    (PunctuationType::None, String::new())
 }
 
 // ============================================
@@ -410,30 +659,65 @@ fn classify_punctuation(punct: &str) -> (PunctuationType, String) {
 /// 
 /// This is the simplest possible interface for calling from
 /// Swift, JavaScript, or other languages.
+#[cfg_attr(feature = "uniffi", uniffi::export)]
 pub fn format_meditation_ssml(text: String, target_duration_seconds: f64) -> String {
     let pacer = MeditationPacer::new();
     pacer.format_meditation_ssml(text, target_duration_seconds)
 }
 
 /// Get detailed pacing result as a simple struct
+#[cfg_attr(feature = "uniffi", uniffi::export)]
 pub fn calculate_pacing_details(text: String, target_duration_seconds: f64) -> PacingResult {
     let pacer = MeditationPacer::new();
     pacer.calculate_pacing(text, target_duration_seconds)
 }
 
+/// Get a pacing result within `tolerance_seconds` of the target
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+pub fn calculate_pacing_exact(
+    text: String,
+    target_duration_seconds: f64,
+    tolerance_seconds: f64,
+) -> PacingResult {
+    let pacer = MeditationPacer::new();
+    pacer.calculate_pacing_exact(text, target_duration_seconds, tolerance_seconds)
+}
+
+/// Re-pace a script from its measured speech duration (second pass)
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+pub fn repace_with_measured(
+    text: String,
+    measured_speech_seconds: f64,
+    target_duration_seconds: f64,
+) -> PacingResult {
+    let pacer = MeditationPacer::new();
+    pacer.repace_with_measured(text, measured_speech_seconds, target_duration_seconds)
+}
+
+/// Estimate the billing characters (TTS credits) for a script
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+pub fn estimate_cost(text: String, target_duration_seconds: f64) -> usize {
+    let pacer = MeditationPacer::new();
+    pacer.estimate_cost(text, target_duration_seconds)
+}
+
 /// Calculate the target word count for an LLM prompt
 /// 
 /// This ensures a 50/50 speech-to-silence ratio by using ~70 words per minute.
-/// Use this when building prompts for GPT to generate meditation scripts.
+/// Use this when building prompts for GPT to generate meditation scripts;
+/// `prompt::PromptBuilder` assembles the complete prompt around it.
 /// 
 /// # Arguments
 /// * `target_duration_seconds` - The total desired meditation duration
 /// 
 /// # Returns
-/// The number of words to request from the LLM
+/// The number of words to request from the LLM. For scripts without
+/// spaces (Japanese, Chinese), a word is an approximate morpheme, as
+/// counted by `WordCounting::Morphemes`.
 /// 
 /// # Example
 /// For a 5-minute meditation: 5 * 70 = 350 words
+#[cfg_attr(feature = "uniffi", uniffi::export)]
 pub fn calculate_target_words_for_prompt(target_duration_seconds: f64) -> usize {
     let minutes = target_duration_seconds / 60.0;
     (minutes * TARGET_WORDS_PER_MINUTE).round() as usize
@@ -442,229 +726,42 @@ pub fn calculate_target_words_for_prompt(target_duration_seconds: f64) -> usize
 /// Calculate target word count with custom words-per-minute density
 /// 
 /// Use this if you need to override the default 70 wpm density.
+#[cfg_attr(feature = "uniffi", uniffi::export)]
 pub fn calculate_target_words_custom(target_duration_seconds: f64, words_per_minute: f64) -> usize {
     let minutes = target_duration_seconds / 60.0;
     (minutes * words_per_minute).round() as usize
 }
 
-// ============================================
-// Tests
-// ============================================
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_word_count() {
-        assert_eq!(count_words("hello world"), 2);
-        assert_eq!(count_words("one"), 1);
-        assert_eq!(count_words("  spaces  between  "), 2);
-        assert_eq!(count_words(""), 0);
-    }
-
-    #[test]
-    fn test_punctuation_classification() {
-        assert_eq!(classify_punctuation(".").0, PunctuationType::SentenceEnd);
-        assert_eq!(classify_punctuation("?").0, PunctuationType::SentenceEnd);
-        assert_eq!(classify_punctuation("!").0, PunctuationType::SentenceEnd);
-        assert_eq!(classify_punctuation(",").0, PunctuationType::Comma);
-        assert_eq!(classify_punctuation("\n").0, PunctuationType::Paragraph);
-        assert_eq!(classify_punctuation("").0, PunctuationType::None);
-    }
-
-    #[test]
-    fn test_atomize_simple() {
-        let pacer = MeditationPacer::new();
-        let atoms = pacer.atomize_text("Hello, world.");
-        
-        assert_eq!(atoms.len(), 2);
-        assert_eq!(atoms[0].text, "Hello");
-        assert_eq!(atoms[0].punctuation, PunctuationType::Comma);
-        assert_eq!(atoms[1].text, "world");
-        assert_eq!(atoms[1].punctuation, PunctuationType::SentenceEnd);
-    }
-
-    #[test]
-    fn test_break_tag_splitting() {
-        let pacer = MeditationPacer::new();
-        
-        // 2 seconds should be single tag
-        let tags = pacer.format_break_tags(2.0);
-        assert_eq!(tags, "<break time=\"2.0s\"/>");
-        
-        // 5 seconds should be two tags (3.0 + 2.0)
-        let tags = pacer.format_break_tags(5.0);
-        assert_eq!(tags, "<break time=\"3.0s\"/><break time=\"2.0s\"/>");
-        
-        // 9 seconds should be three tags
-        let tags = pacer.format_break_tags(9.0);
-        assert_eq!(tags, "<break time=\"3.0s\"/><break time=\"3.0s\"/><break time=\"3.0s\"/>");
-    }
-
-    #[test]
-    fn test_basic_pacing() {
-        let pacer = MeditationPacer::new();
-        let result = pacer.calculate_pacing(
-            "Welcome. Take a deep breath.".to_string(),
-            60.0
-        );
-        
-        // Should have 2 atoms (two sentences)
-        assert_eq!(result.atom_count, 2);
-        
-        // Should have 5 words total
-        assert_eq!(result.total_words, 5);
-        
-        // SSML should contain break tags
-        assert!(result.ssml.contains("<break"));
-        
-        // Estimated total should be close to target
-        assert!(result.estimated_total_seconds > 0.0);
-    }
-
-    #[test]
-    fn test_no_overflow_when_speech_exceeds_target() {
-        let pacer = MeditationPacer::new();
-        
-        // Very short target with lots of text
-        let long_text = "This is a very long meditation script that contains many many words and will definitely take longer than five seconds to speak aloud.".to_string();
-        let result = pacer.calculate_pacing(long_text, 5.0);
-        
-        // Should not add negative silence
-        assert!(result.total_silence_added >= 0.0);
-        assert!(result.raw_silence_budget >= 0.0);
-        
-        // Should still produce valid SSML
-        assert!(!result.ssml.is_empty());
-    }
-
-    #[test]
-    fn test_empty_text() {
-        let pacer = MeditationPacer::new();
-        let result = pacer.calculate_pacing("".to_string(), 60.0);
-        
-        assert_eq!(result.total_words, 0);
-        assert_eq!(result.total_chars, 0);
-        assert_eq!(result.atom_count, 0);
-    }
-
-    #[test]
-    fn test_character_based_estimation() {
-        let pacer = MeditationPacer::new();
-        // "Welcome" = 7 chars, "Take" = 4, "a" = 1, "deep" = 4, "breath" = 6
-        // Total: 7 + 4 + 1 + 4 + 6 = 22 chars (excluding whitespace)
-        // Estimated speech = 22/12 = 1.833... seconds
-        let result = pacer.calculate_pacing(
-            "Welcome. Take a deep breath.".to_string(),
-            60.0
-        );
-        
-        // Check character count (excluding whitespace)
-        assert_eq!(result.total_chars, 22);
-        
-        // Estimated speech should be ~1.833 seconds (22 chars / 12 cps)
-        let expected_speech = 22.0 / 12.0;
-        assert!((result.estimated_speech_seconds - expected_speech).abs() < 0.01);
-        
-        // Safety buffer should be applied (1.1x)
-        let expected_raw = 60.0 - expected_speech;
-        assert!((result.raw_silence_budget - expected_raw).abs() < 0.01);
-        assert!((result.final_silence_budget - expected_raw * 1.1).abs() < 0.01);
-    }
-
-    #[test]
-    fn test_no_break_after_last_atom() {
-        let pacer = MeditationPacer::new();
-        let result = pacer.calculate_pacing(
-            "First sentence. Second sentence.".to_string(),
-            60.0
-        );
-        
-        // SSML should NOT end with a break tag
-        assert!(!result.ssml.trim_end().ends_with("/>"));
-        
-        // Should end with the punctuation of the last sentence
-        assert!(result.ssml.trim_end().ends_with("."));
-    }
-
-    #[test]
-    fn test_target_words_for_prompt() {
-        // 1 minute = 70 words
-        assert_eq!(calculate_target_words_for_prompt(60.0), 70);
-        
-        // 2 minutes = 140 words
-        assert_eq!(calculate_target_words_for_prompt(120.0), 140);
-        
-        // 5 minutes = 350 words
-        assert_eq!(calculate_target_words_for_prompt(300.0), 350);
-        
-        // 30 seconds = 35 words
-        assert_eq!(calculate_target_words_for_prompt(30.0), 35);
-    }
-
-    #[test]
-    fn test_custom_words_per_minute() {
-        // 60 seconds at 100 wpm = 100 words
-        assert_eq!(calculate_target_words_custom(60.0, 100.0), 100);
-        
-        // 120 seconds at 50 wpm = 100 words
-        assert_eq!(calculate_target_words_custom(120.0, 50.0), 100);
-    }
+/// Full pacing result as a JSON string
+/// 
+/// A single-string interface for bridges where marshaling the
+/// multi-field `PacingResult` is awkward.
+#[cfg(feature = "serde")]
+pub fn calculate_pacing_json(text: String, target_duration_seconds: f64) -> String {
+    let result = calculate_pacing_details(text, target_duration_seconds);
+    serde_json::to_string(&result).unwrap_or_else(|e| json_error(&e.to_string()))
+}
 
-    #[test]
-    fn test_production_calibration() {
-        // Test with production-like data
-        // Observed: ~60 words = ~310 characters = 26 seconds of speech
-        // Our constant: 12 chars/sec -> 310/12 = 25.83 sec (close to observed 26s)
-        let pacer = MeditationPacer::new();
-        
-        // Generate a meditation script with roughly 310 characters
-        let meditation_text = "Welcome to this moment of peace. \
-            Close your eyes gently. \
-            Take a slow, deep breath in. \
-            Feel the air fill your lungs completely. \
-            Now exhale slowly, releasing all tension. \
-            Notice how your body begins to relax. \
-            Each breath brings you deeper into calm. \
-            Let go of any thoughts that arise. \
-            Simply be present in this moment. \
-            You are safe. You are at peace.".to_string();
-        
-        // Count chars (for validation)
-        let char_count: usize = meditation_text.chars().filter(|c| !c.is_whitespace()).count();
-        println!("Test meditation char count: {}", char_count);
-        
-        // Target: 60 second meditation
-        let result = pacer.calculate_pacing(meditation_text, 60.0);
-        
-        // Estimated speech time should be roughly 26 seconds (within 5 seconds)
-        // 310 chars / 12 cps = ~25.8 seconds
-        assert!(result.estimated_speech_seconds > 20.0);
-        assert!(result.estimated_speech_seconds < 35.0);
-        
-        // With 60s target and ~26s speech, we should have ~34s raw silence
-        // With 1.1x buffer, final silence budget should be ~37.4s
-        assert!(result.final_silence_budget > result.raw_silence_budget);
-        
-        // Total estimated should overshoot target slightly (safety buffer)
-        assert!(result.estimated_total_seconds >= 60.0);
-        
-        // Should NOT have a break at the very end
-        assert!(!result.ssml.ends_with("/>"));
+/// Full pacing result as JSON, paced with a JSON-encoded `PacingConfig`
+/// 
+/// Returns `{"error": "..."}` if the config cannot be parsed or is invalid.
+#[cfg(feature = "serde")]
+pub fn calculate_pacing_json_with_config(
+    text: String,
+    target_duration_seconds: f64,
+    config_json: String,
+) -> String {
+    match PacingConfig::from_json_str(&config_json) {
+        Ok(config) => {
+            let result = MeditationPacer::with_config(config).calculate_pacing(text, target_duration_seconds);
+            serde_json::to_string(&result).unwrap_or_else(|e| json_error(&e.to_string()))
+        }
+        Err(e) => json_error(&e.to_string()),
     }
+}
 
-    #[test]
-    fn test_density_for_five_minute_meditation() {
-        // For a 5-minute meditation at 70 words/minute density
-        let target_words = calculate_target_words_for_prompt(300.0);
-        assert_eq!(target_words, 350); // 5 minutes * 70 wpm
-        
-        // This should give us a 50/50 speech-to-silence ratio
-        // 350 words at ~5.2 chars/word = ~1820 chars
-        // 1820 chars at 12 cps = ~151.7 seconds of speech
-        // 300 - 151.7 = 148.3 seconds of raw silence
-        // 148.3 * 1.1 = 163 seconds of final silence budget
-        // Total: 151.7 + 163 = 314.7 seconds (~5:15 total, slightly over)
-    }
+/// Error object returned by the JSON entry points
+#[cfg(feature = "serde")]
+fn json_error(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
 }
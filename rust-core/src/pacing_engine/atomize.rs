@@ -0,0 +1,1151 @@
+//! Atomization
+//! 
+//! Step A of the pipeline: script text split into `SpeechAtom`s. Covers
+//! the banned-word filter, the delimiter scanner (or a custom regex),
+//! punctuation, marker, and quote classification, and the per-atom counts
+//! (spoken characters, words, syllables) that speech estimates build on.
+
+use super::*;
+
+// ============================================
+// Constants
+// ============================================
+
+/// Built-in atomization pattern: group 1 is the spoken text, group 2 the
+/// punctuation run that ends it
+/// 
+/// Besides Latin punctuation it splits on the Arabic comma (`،`),
+/// semicolon (`؛`), question mark (`؟`), and full stop (`۔`), the Hebrew
+/// sof pasuq (`׃`), and the Devanagari danda (`।`) and double danda (`॥`).
+/// A `.` or `,` between two digits ("4.5", "1,500") is part of the number,
+/// not a split.
+pub const DEFAULT_ATOM_PATTERN: &str =
+    r"((?:[0-9]+(?:[.,][0-9]+)*|[^,;.\?!…—–\n،؛؟۔׃।॥])+)([,;.\?!…—–\n،؛؟۔׃।॥]*)";
+
+/// Punctuation characters the built-in pattern splits on
+pub(super) const DELIMITERS: [char; 16] = [',', ';', '.', '?', '!', '…', '—', '–', '\n', '،', '؛', '؟', '۔', '׃', '।', '॥'];
+
+/// `DELIMITERS` as a regex character class
+#[cfg(all(test, feature = "std"))]
+pub(super) const PUNCTUATION_CLASS: &str = r",;.\?!…—–\n،؛؟۔׃।॥";
+
+/// Digits with decimal points or thousands separators, kept in one atom
+#[cfg(all(test, feature = "std"))]
+pub(super) const NUMBER_PATTERN: &str = r"[0-9]+(?:[.,][0-9]+)*";
+
+// ============================================
+// Types
+// ============================================
+
+/// The type of punctuation that ends a speech atom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum PunctuationType {
+    /// Comma - short pause
+    Comma,
+    /// Semicolon - clause pause
+    Semicolon,
+    /// Em or en dash - clause pause
+    Dash,
+    /// Period, question mark, exclamation - standard pause
+    SentenceEnd,
+    /// Ellipsis (three dots or the single character) - trailing-off pause
+    Ellipsis,
+    /// Newline or paragraph break - long pause
+    Paragraph,
+    /// Edge of a quoted span (only with `PacingConfig::soften_quote_pauses`)
+    /// - soft pause
+    Quote,
+    /// No punctuation (end of text)
+    None,
+}
+
+impl PunctuationType {
+    /// All punctuation types that can carry a pause
+    pub const PAUSING: [PunctuationType; 7] = [
+        PunctuationType::Comma,
+        PunctuationType::Semicolon,
+        PunctuationType::Dash,
+        PunctuationType::SentenceEnd,
+        PunctuationType::Ellipsis,
+        PunctuationType::Paragraph,
+        PunctuationType::Quote,
+    ];
+
+    /// Get the default silence weight for this punctuation type
+    /// 
+    /// The pacer itself uses `PacingConfig::weights`; this is the
+    /// production-calibrated value that table starts from.
+    pub fn weight(&self) -> u32 {
+        match self {
+            PunctuationType::Comma => WEIGHT_COMMA,
+            PunctuationType::Semicolon => WEIGHT_SEMICOLON,
+            PunctuationType::Dash => WEIGHT_DASH,
+            PunctuationType::SentenceEnd => WEIGHT_SENTENCE,
+            PunctuationType::Ellipsis => WEIGHT_ELLIPSIS,
+            PunctuationType::Paragraph => WEIGHT_PARAGRAPH,
+            PunctuationType::Quote => WEIGHT_QUOTE,
+            PunctuationType::None => 0,
+        }
+    }
+
+    /// Whether this punctuation closes a sentence (sentence end, ellipsis,
+    /// or paragraph break)
+    pub fn ends_sentence(&self) -> bool {
+        matches!(
+            self,
+            PunctuationType::SentenceEnd | PunctuationType::Ellipsis | PunctuationType::Paragraph
+        )
+    }
+}
+
+impl fmt::Display for PunctuationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PunctuationType::Comma => "comma",
+            PunctuationType::Semicolon => "semicolon",
+            PunctuationType::Dash => "dash",
+            PunctuationType::SentenceEnd => "sentence end",
+            PunctuationType::Ellipsis => "ellipsis",
+            PunctuationType::Paragraph => "paragraph",
+            PunctuationType::Quote => "quote",
+            PunctuationType::None => "none",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A single "atom" of speech - text followed by punctuation
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SpeechAtom {
+    /// The text content (without trailing punctuation)
+    pub text: String,
+    /// The punctuation that ends this atom
+    pub punctuation: PunctuationType,
+    /// The original punctuation character(s)
+    pub punctuation_char: String,
+    /// Calculated silence weight
+    pub weight: u32,
+    /// Word count in this atom
+    pub word_count: usize,
+    /// Configured keywords found in this atom (sorted)
+    pub keywords: Vec<String>,
+    /// Extra silence weight earned by `keywords`
+    pub keyword_bonus: u32,
+}
+
+impl SpeechAtom {
+    /// Create a new speech atom
+    pub fn new(text: String, punctuation: PunctuationType, punctuation_char: String) -> Self {
+        let weight = punctuation.weight();
+        let word_count = count_words(&text);
+        Self {
+            text,
+            punctuation,
+            punctuation_char,
+            weight,
+            word_count,
+            keywords: Vec::new(),
+            keyword_bonus: 0,
+        }
+    }
+
+    /// Silence weight including any keyword bonus
+    pub fn effective_weight(&self) -> u32 {
+        self.weight + self.keyword_bonus
+    }
+
+    /// Character count excluding whitespace (the unit of speech estimation)
+    /// 
+    /// Combining diacritics, the Arabic tatweel, and invisible bidi
+    /// controls are not counted; numbers and acronyms count as their
+    /// spoken words.
+    pub fn char_count(&self) -> usize {
+        spoken_text(&self.text).chars().filter(|c| is_spoken_char(*c)).count()
+    }
+
+    /// Character count excluding whitespace, as written (digits and
+    /// acronyms are not expanded)
+    pub fn written_char_count(&self) -> usize {
+        self.text.chars().filter(|c| is_spoken_char(*c)).count()
+    }
+
+    /// Estimated syllable count (vowel-cluster heuristic, numbers and
+    /// acronyms as their spoken words)
+    pub fn syllable_count(&self) -> usize {
+        count_syllables(&spoken_text(&self.text))
+    }
+}
+
+/// A speech atom borrowing its text from the script
+/// 
+/// Produced by `MeditationPacer::atomize_ref`; the same fields as
+/// `SpeechAtom` without allocating per atom. Keywords borrow from the
+/// pacer's config.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeechAtomRef<'a> {
+    /// The text content (without trailing punctuation)
+    pub text: &'a str,
+    /// The punctuation that ends this atom
+    pub punctuation: PunctuationType,
+    /// The original punctuation character(s); owned only when markers
+    /// had to be stripped out
+    pub punctuation_char: Cow<'a, str>,
+    /// Calculated silence weight
+    pub weight: u32,
+    /// Word count in this atom
+    pub word_count: usize,
+    /// Configured keywords found in this atom (sorted)
+    pub keywords: Vec<&'a str>,
+    /// Extra silence weight earned by `keywords`
+    pub keyword_bonus: u32,
+}
+
+impl SpeechAtomRef<'_> {
+    /// Silence weight including any keyword bonus
+    pub fn effective_weight(&self) -> u32 {
+        self.weight + self.keyword_bonus
+    }
+
+    /// Character count excluding whitespace (the unit of speech estimation)
+    pub fn char_count(&self) -> usize {
+        spoken_text(self.text).chars().filter(|c| is_spoken_char(*c)).count()
+    }
+
+    /// Estimated syllable count (vowel-cluster heuristic)
+    pub fn syllable_count(&self) -> usize {
+        count_syllables(&spoken_text(self.text))
+    }
+
+    /// Copy into an owned `SpeechAtom`
+    pub fn to_atom(&self) -> SpeechAtom {
+        SpeechAtom {
+            text: self.text.to_string(),
+            punctuation: self.punctuation,
+            punctuation_char: self.punctuation_char.to_string(),
+            weight: self.weight,
+            word_count: self.word_count,
+            keywords: self.keywords.iter().map(|k| k.to_string()).collect(),
+            keyword_bonus: self.keyword_bonus,
+        }
+    }
+}
+
+/// One banned word or phrase that was replaced or dropped before pacing
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct WordReplacement {
+    /// The configured word or phrase
+    pub phrase: String,
+    /// The text as it appeared in the script
+    pub original: String,
+    /// What was spoken instead (empty if dropped)
+    pub replacement: String,
+}
+
+// ============================================
+// Atomization
+// ============================================
+
+impl MeditationPacer {
+    /// Words in `text` as written, counted the locale's way
+    fn written_word_count(&self, text: &str) -> usize {
+        self.config.locale.as_ref().map_or_else(|| count_words(text), |l| l.count_words(text))
+    }
+
+    /// Words a voice reads for `text`, with numbers and acronyms written
+    /// out (the basis of `DurationModel::WordsPerMinute`)
+    pub(super) fn spoken_word_count(&self, text: &str) -> usize {
+        self.written_word_count(&spoken_text(text))
+    }
+
+    /// Split text into the speech atoms the pacer will distribute silence over
+    /// 
+    /// Indices into the returned list are the indices used by
+    /// `PacingOverrides::at`.
+    pub fn atomize(&self, text: &str) -> Vec<SpeechAtom> {
+        self.atomize_text(text)
+    }
+
+    /// The text `atomize` splits: `sanitize_text`, then the banned-word
+    /// filter
+    /// 
+    /// Borrows `text` when nothing had to change.
+    pub fn prepare_text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        match self.sanitize_text(text) {
+            Cow::Borrowed(text) => self.filter_words(text).0,
+            Cow::Owned(text) => Cow::Owned(self.filter_words(&text).0.into_owned()),
+        }
+    }
+
+    /// Lazily split text into speech atoms
+    /// 
+    /// Splits `text` as given, one atom at a time, so very long scripts can
+    /// be scanned without materializing the whole list. Unlike `atomize` it
+    /// does not sanitize or filter banned words; pass it the output of
+    /// `prepare_text` to get the same atoms as `atomize`.
+    pub fn atomize_iter<'a>(&'a self, text: &'a str) -> AtomIter<'a> {
+        AtomIter {
+            inner: self.atomize_ref_iter(text),
+        }
+    }
+
+    /// Split text into atoms that borrow from it instead of allocating
+    /// 
+    /// Like `atomize_iter`, takes text as `prepare_text` returns it; convert
+    /// an atom with `SpeechAtomRef::to_atom` when an owned copy is needed.
+    pub fn atomize_ref<'a>(&'a self, text: &'a str) -> Vec<SpeechAtomRef<'a>> {
+        self.atomize_ref_iter(text).collect()
+    }
+
+    /// Lazily split text into borrowed atoms
+    pub fn atomize_ref_iter<'a>(&'a self, text: &'a str) -> AtomRefIter<'a> {
+        AtomRefIter {
+            pacer: self,
+            splitter: &self.splitter,
+            text,
+            position: 0,
+            peeked: None,
+        }
+    }
+
+    /// Atomize text into speech atoms based on punctuation
+    pub(crate) fn atomize_text(&self, text: &str) -> Vec<SpeechAtom> {
+        self.atomize_filtered(text).0
+    }
+
+    /// Sanitize, apply the banned-word filter, then atomize
+    pub(crate) fn atomize_filtered(&self, text: &str) -> (Vec<SpeechAtom>, Vec<WordReplacement>) {
+        let sanitized = self.sanitize_text(text);
+        let (filtered, replacements) = self.filter_words(&sanitized);
+        (self.atomize_iter(&filtered).collect(), replacements)
+    }
+
+    /// Replace or drop banned words, reporting each occurrence
+    /// 
+    /// A dropped phrase takes one adjoining space with it so no double
+    /// space is left behind.
+    pub(crate) fn filter_words<'a>(&self, text: &'a str) -> (Cow<'a, str>, Vec<WordReplacement>) {
+        let Some(filter) = &self.word_filter else {
+            return (Cow::Borrowed(text), Vec::new());
+        };
+        
+        let mut replacements = Vec::new();
+        let mut out = String::with_capacity(text.len());
+        let mut last = 0;
+        for found in filter.find_iter(text) {
+            let original = &text[found.clone()];
+            let normalized = normalized_phrase(original);
+            let Some((phrase, action)) = self.config.banned_words.iter()
+                .find(|(phrase, _)| normalized_phrase(phrase) == normalized)
+            else {
+                continue;
+            };
+            
+            let replacement = match action {
+                BannedWordAction::Replace { with } => with.clone(),
+                BannedWordAction::Reject => String::new(),
+            };
+            out.push_str(&text[last..found.start]);
+            last = found.end;
+            if replacement.is_empty() {
+                if out.ends_with(' ') && text[last..].starts_with(|c: char| c.is_whitespace() || c.is_ascii_punctuation()) {
+                    out.pop();
+                }
+            } else {
+                out.push_str(&replacement);
+            }
+            replacements.push(WordReplacement {
+                phrase: phrase.clone(),
+                original: original.to_string(),
+                replacement,
+            });
+        }
+        
+        if replacements.is_empty() {
+            return (Cow::Borrowed(text), replacements);
+        }
+        out.push_str(&text[last..]);
+        (Cow::Owned(out), replacements)
+    }
+
+    /// Build an atom from one match of the atom pattern
+    /// 
+    /// Returns `None` for matches with no spoken content.
+    fn atom_from_match<'a>(&'a self, content: &'a str, punct: &'a str) -> Option<SpeechAtomRef<'a>> {
+        let content = content.trim();
+        if content.is_empty() {
+            return None;
+        }
+        
+        let (punct_type, punct_char) = self.classify_delimiter(punct);
+        let keywords = self.matched_keywords(content);
+        let mut weight = self.config.weight_for(punct_type);
+        if self.mark_count(punct) > self.mark_count(&punct_char) {
+            weight = (f64::from(weight) * self.config.repeated_punctuation_multiplier).round() as u32;
+        }
+        
+        Some(SpeechAtomRef {
+            text: content,
+            punctuation: punct_type,
+            punctuation_char: punct_char,
+            weight,
+            word_count: self.written_word_count(content),
+            keyword_bonus: keywords.iter().map(|(_, bonus)| bonus).sum(),
+            keywords: keywords.into_iter().map(|(keyword, _)| keyword).collect(),
+        })
+    }
+
+    /// Configured keywords found in atom text with their bonus weight (sorted)
+    fn matched_keywords(&self, text: &str) -> Vec<(&str, u32)> {
+        if self.config.keyword_bonuses.is_empty() {
+            return Vec::new();
+        }
+        
+        let text = text.to_lowercase();
+        let mut found: Vec<(&str, u32)> = self.config.keyword_bonuses.iter()
+            .filter(|(keyword, _)| contains_keyword(&text, &keyword.to_lowercase()))
+            .map(|(keyword, bonus)| (keyword.as_str(), *bonus))
+            .collect();
+        found.sort();
+        found
+    }
+
+    /// Classify a delimiter run, resolving configured markers first
+    /// 
+    /// Markers are removed from the rendered punctuation; if several appear
+    /// in one run, the heaviest class wins, and of equally heavy ones the
+    /// marker that comes first (the longest, when they start together).
+    fn classify_delimiter<'a>(&self, punct: &'a str) -> (PunctuationType, Cow<'a, str>) {
+        let marker_type = self.config.markers.iter()
+            .filter_map(|(marker, p)| punct.find(marker.as_str()).map(|at| (at, marker, *p)))
+            .max_by_key(|(at, marker, p)| (self.config.weight_for(*p), Reverse(*at), marker.len(), Reverse(*marker)))
+            .map(|(_, _, p)| p);
+        
+        match marker_type {
+            Some(punct_type) => {
+                // Longest first, so a marker inside another is not cut out of it
+                let mut markers: Vec<&String> = self.config.markers.keys().collect();
+                markers.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+                let mut rest = punct.to_string();
+                for marker in markers {
+                    rest = rest.replace(marker.as_str(), "");
+                }
+                let (_, punct_char) = classify_punctuation(rest.trim());
+                (punct_type, Cow::Owned(punct_char.to_string()))
+            }
+            None => self.classify_locale_punctuation(punct).unwrap_or_else(|| collapse_punctuation(punct)),
+        }
+    }
+
+    /// Pausing marks in a delimiter run (an ellipsis character counts as
+    /// the three dots it stands for; line breaks and markers not at all)
+    fn mark_count(&self, punct: &str) -> usize {
+        punct.chars()
+            .map(|c| match c {
+                '…' => 3,
+                c => usize::from(self.is_mark(c)),
+            })
+            .sum()
+    }
+
+    /// Whether `c` is a pausing mark (a line break is not)
+    fn is_mark(&self, c: char) -> bool {
+        if c == '\n' {
+            return false;
+        }
+        self.config.locale.as_ref().is_some_and(|l| l.classify(c).is_some())
+            || classify_punctuation(c.encode_utf8(&mut [0; 4])).0 != PunctuationType::None
+    }
+
+    /// Build the atom for a quoted span
+    /// 
+    /// `inner` is the punctuation just inside the closing quote, which
+    /// stays in the spoken text; `punct` is whatever follows the quote. The
+    /// span pauses as `punct` if there is any, else as `inner`.
+    fn quoted_atom<'a>(&'a self, span: &'a str, inner: &'a str, punct: &'a str) -> Option<SpeechAtomRef<'a>> {
+        let mut atom = self.atom_from_match(span, punct)?;
+        if punct.is_empty() {
+            atom.punctuation = self.classify_delimiter(inner).0;
+            atom.weight = self.config.weight_for(atom.punctuation);
+        }
+        if self.config.soften_quote_pauses {
+            self.soften(&mut atom);
+        }
+        Some(atom)
+    }
+
+    /// Turn an atom's pause into a quote pause (paragraph breaks and the
+    /// end of the text are kept)
+    fn soften(&self, atom: &mut SpeechAtomRef) {
+        if !matches!(atom.punctuation, PunctuationType::Paragraph | PunctuationType::None) {
+            atom.punctuation = PunctuationType::Quote;
+            atom.weight = self.config.weight_for(PunctuationType::Quote);
+        }
+    }
+
+    /// Classify a delimiter run containing the locale's extra punctuation
+    /// 
+    /// The strongest class in the run wins and its character is rendered;
+    /// a line break still makes it a paragraph. `None` if the run has no
+    /// locale punctuation.
+    fn classify_locale_punctuation<'a>(&self, punct: &'a str) -> Option<(PunctuationType, Cow<'a, str>)> {
+        let locale = self.config.locale.as_ref()?;
+        if !punct.chars().any(|c| locale.classify(c).is_some()) {
+            return None;
+        }
+        if punct.contains('\n') {
+            return Some((PunctuationType::Paragraph, Cow::Borrowed(punct)));
+        }
+        
+        let (punct_type, c) = punct.chars()
+            .map(|c| {
+                let mut buf = [0u8; 4];
+                let class = locale.classify(c).unwrap_or_else(|| classify_punctuation(c.encode_utf8(&mut buf)).0);
+                (class, c)
+            })
+            .max_by_key(|(class, _)| class_strength(*class))?;
+        let punct_char = match punct_type {
+            PunctuationType::Ellipsis if locale.classify(c).is_none() => "...".to_string(),
+            _ => c.to_string(),
+        };
+        Some((punct_type, Cow::Owned(punct_char)))
+    }
+}
+
+// ============================================
+// Streaming Atomizer
+// ============================================
+
+/// Lazy iterator over the speech atoms of a text
+/// 
+/// Created by `MeditationPacer::atomize_iter`.
+#[derive(Debug)]
+pub struct AtomIter<'a> {
+    inner: AtomRefIter<'a>,
+}
+
+impl Iterator for AtomIter<'_> {
+    type Item = SpeechAtom;
+
+    fn next(&mut self) -> Option<SpeechAtom> {
+        self.inner.next().map(|atom| atom.to_atom())
+    }
+}
+
+/// Lazy iterator over borrowed speech atoms
+/// 
+/// Created by `MeditationPacer::atomize_ref_iter`.
+#[derive(Debug)]
+pub struct AtomRefIter<'a> {
+    pacer: &'a MeditationPacer,
+    /// Splits on punctuation while capturing the punctuation (default:
+    /// comma, semicolon, period, question, exclamation, ellipsis, em/en
+    /// dash, or newline)
+    splitter: &'a AtomSplitter,
+    text: &'a str,
+    position: usize,
+    /// Match looked at (and stepped past) while extending a delimiter run
+    peeked: Option<AtomMatch>,
+}
+
+impl<'a> AtomRefIter<'a> {
+    /// The next match of the atom pattern, stepping past it
+    fn next_match(&mut self) -> Option<AtomMatch> {
+        if let Some(found) = self.peeked.take() {
+            return Some(found);
+        }
+        if self.position > self.text.len() {
+            return None;
+        }
+        let found = self.splitter.find_at(self.text, self.position)?;
+        let whole = &found.whole;
+        
+        // Step past empty matches so custom patterns cannot stall
+        self.position = if whole.end > whole.start {
+            whole.end
+        } else {
+            whole.end + self.text[whole.end..].chars().next().map_or(1, char::len_utf8)
+        };
+        Some(found)
+    }
+
+    /// Extend the delimiter run at `start..end` over later delimiters
+    /// separated from it only by spaces (". . ."), returning the run and
+    /// where it ends
+    fn extend_run(&mut self, start: usize, end: usize) -> (&'a str, usize) {
+        let mut run_end = end;
+        while let Some(next) = self.next_match() {
+            let extends = next.whole.start == run_end
+                && next.content.as_ref().is_none_or(|m| self.text[m.clone()].trim().is_empty())
+                && next.punct.as_ref().is_some_and(|m| !m.is_empty());
+            if !extends {
+                self.peeked = Some(next);
+                break;
+            }
+            run_end = next.punct.map_or(run_end, |m| m.end);
+        }
+        (&self.text[start..run_end], run_end)
+    }
+
+    /// If `content` opens a quote that closes later in the paragraph, the
+    /// atom for the whole quoted span (`Some(None)` if it has no spoken
+    /// text); `None` to atomize `content` as usual
+    fn quoted_span(&mut self, content: Range<usize>) -> Option<Option<SpeechAtomRef<'a>>> {
+        let close = open_quote(&self.text[content.clone()])?;
+        let close_at = find_closing_quote(self.text, content.end, close)?;
+        let quote_end = close_at + close.len_utf8();
+        let span = &self.text[content.start..quote_end];
+        
+        // The punctuation just inside the closing quote
+        let before = self.text[content.start..close_at].trim_end();
+        let inner = &before[before.trim_end_matches(|c| self.pacer.is_mark(c)).len()..];
+        
+        // Delimiters right after the closing quote end the span's atom
+        let rest = &self.text[quote_end..];
+        let run_len = rest.len() - rest.trim_start_matches(|c: char| c.is_whitespace() || self.pacer.is_mark(c)).len();
+        let run = rest[..run_len].trim_end_matches(|c: char| c.is_whitespace() && c != '\n');
+        let punct = if run.trim().is_empty() && !run.contains('\n') { "" } else { run };
+        
+        self.peeked = None;
+        self.position = quote_end + punct.len();
+        let boundary = self.position;
+        let mut atom = self.pacer.quoted_atom(span, inner, punct);
+        if let Some(atom) = atom.as_mut().filter(|_| self.pacer.config.soften_quote_pauses) {
+            if opens_quote(&self.text[boundary..]) {
+                self.pacer.soften(atom);
+            }
+        }
+        Some(atom)
+    }
+}
+
+impl<'a> Iterator for AtomRefIter<'a> {
+    type Item = SpeechAtomRef<'a>;
+
+    fn next(&mut self) -> Option<SpeechAtomRef<'a>> {
+        while let Some(found) = self.next_match() {
+            if let Some(content) = found.content.clone().filter(|_| self.pacer.config.keep_quotes_intact) {
+                if let Some(quoted) = self.quoted_span(content) {
+                    match quoted {
+                        Some(atom) => return Some(atom),
+                        None => continue,
+                    }
+                }
+            }
+            
+            let mut content = found.content.map_or("", |m| &self.text[m]);
+            let (punct, boundary) = match found.punct.filter(|m| !m.is_empty()) {
+                Some(run) => {
+                    // A dash keeps the space written before it ("relax — rest")
+                    let space = if self.text[run.start..].starts_with(['—', '–']) {
+                        content.chars().next_back().filter(|c| c.is_whitespace()).map_or(0, char::len_utf8)
+                    } else {
+                        0
+                    };
+                    content = &content[..content.len() - space];
+                    self.extend_run(run.start - space, run.end)
+                }
+                None => ("", found.whole.end),
+            };
+            if let Some(mut atom) = self.pacer.atom_from_match(content, punct) {
+                if self.pacer.config.soften_quote_pauses && opens_quote(&self.text[boundary..]) {
+                    self.pacer.soften(&mut atom);
+                }
+                return Some(atom);
+            }
+        }
+        None
+    }
+}
+
+// ============================================
+// Splitting and Filtering
+// ============================================
+
+/// One match of the atom pattern, as byte ranges into the text
+#[derive(Debug, Clone)]
+pub(super) struct AtomMatch {
+    pub(super) whole: Range<usize>,
+    /// The spoken text
+    pub(super) content: Option<Range<usize>>,
+    /// The delimiter run that ends it
+    pub(super) punct: Option<Range<usize>>,
+}
+
+/// Splits text into atom pattern matches
+#[derive(Debug, Clone)]
+pub(super) enum AtomSplitter {
+    /// A custom `atom_pattern`
+    #[cfg(feature = "std")]
+    Pattern(Regex),
+    /// `DEFAULT_ATOM_PATTERN`, scanned by hand, plus locale delimiters and
+    /// marker tokens
+    Delimiters {
+        extra: Vec<char>,
+        markers: Vec<String>,
+    },
+}
+
+impl AtomSplitter {
+    /// The splitter for a config's atom pattern, locale, and markers
+    /// 
+    /// Invalid custom patterns (see `ConfigError::InvalidAtomPattern`)
+    /// fall back to the default. Without `std` custom patterns are not
+    /// available.
+    pub(super) fn for_config(config: &PacingConfig) -> Self {
+        #[cfg(feature = "std")]
+        if let Some(regex) = config.atom_pattern.as_deref()
+            .and_then(|p| Regex::new(p).ok())
+            .filter(|regex| regex.captures_len() >= 3)
+        {
+            return AtomSplitter::Pattern(regex);
+        }
+        let extra = config.locale.as_ref()
+            .map(|locale| locale.punctuation_chars().to_vec())
+            .unwrap_or_default();
+        // Longest first, so a marker wins over one it starts with
+        let mut markers: Vec<String> = config.markers.keys()
+            .filter(|marker| !marker.is_empty())
+            .cloned()
+            .collect();
+        markers.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        AtomSplitter::Delimiters { extra, markers }
+    }
+
+    /// The first match starting at or after `position`
+    pub(super) fn find_at(&self, text: &str, position: usize) -> Option<AtomMatch> {
+        match self {
+            #[cfg(feature = "std")]
+            AtomSplitter::Pattern(regex) => {
+                let cap = regex.captures_at(text, position)?;
+                Some(AtomMatch {
+                    whole: cap.get(0)?.range(),
+                    content: cap.get(1).map(|m| m.range()),
+                    punct: cap.get(2).map(|m| m.range()),
+                })
+            }
+            AtomSplitter::Delimiters { extra, markers } => {
+                let delimiter_len = |at: usize| delimiter_len(extra, markers, text, at);
+                // Delimiters before any spoken text belong to no atom
+                let mut start = position;
+                while start < text.len() {
+                    match delimiter_len(start) {
+                        0 => break,
+                        len => start += len,
+                    }
+                }
+                if start >= text.len() {
+                    return None;
+                }
+                let mut end = start;
+                while end < text.len() && delimiter_len(end) == 0 {
+                    end += text[end..].chars().next().map_or(1, char::len_utf8);
+                }
+                let mut run_end = end;
+                while run_end < text.len() {
+                    match delimiter_len(run_end) {
+                        0 => break,
+                        len => run_end += len,
+                    }
+                }
+                Some(AtomMatch {
+                    whole: start..run_end,
+                    content: Some(start..end),
+                    punct: Some(end..run_end),
+                })
+            }
+        }
+    }
+}
+
+/// Byte length of the delimiter or marker at `at` (0 if there is none)
+/// 
+/// A `.` or `,` between two digits belongs to the number.
+fn delimiter_len(extra: &[char], markers: &[String], text: &str, at: usize) -> usize {
+    let rest = &text[at..];
+    if let Some(marker) = markers.iter().find(|marker| rest.starts_with(marker.as_str())) {
+        return marker.len();
+    }
+    let in_number = |c: char| {
+        matches!(c, '.' | ',')
+            && text[..at].ends_with(|c: char| c.is_ascii_digit())
+            && rest[1..].starts_with(|c: char| c.is_ascii_digit())
+    };
+    match rest.chars().next() {
+        Some(c) if in_number(c) => 0,
+        Some(c) if DELIMITERS.contains(&c) || extra.contains(&c) => c.len_utf8(),
+        _ => 0,
+    }
+}
+
+/// A banned phrase as matched: lowercase, single-spaced
+/// 
+/// Keys of `banned_words` that normalize the same are rejected by
+/// `PacingConfig::validate`.
+pub(super) fn normalized_phrase(phrase: &str) -> String {
+    phrase.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Case-insensitive matcher for banned phrases on word boundaries
+#[derive(Debug, Clone)]
+pub(super) struct WordFilter {
+    /// Lowercase words of each phrase, longest phrase first so "deep pain"
+    /// wins over "pain"
+    phrases: Vec<Vec<String>>,
+}
+
+impl WordFilter {
+    /// The matcher for a config's banned words (`None` when nothing is
+    /// banned)
+    pub(super) fn for_config(config: &PacingConfig) -> Option<Self> {
+        let mut phrases: Vec<&str> = config.banned_words.keys()
+            .map(|phrase| phrase.trim())
+            .filter(|phrase| !phrase.is_empty())
+            .collect();
+        if phrases.is_empty() {
+            return None;
+        }
+        phrases.sort_by_key(|phrase| Reverse(phrase.len()));
+        Some(WordFilter {
+            phrases: phrases.iter()
+                .map(|phrase| phrase.split(' ').map(str::to_lowercase).collect())
+                .collect(),
+        })
+    }
+
+    /// Byte ranges of every non-overlapping match, left to right
+    /// 
+    /// Words of a phrase match across any run of whitespace.
+    fn find_iter(&self, text: &str) -> Vec<Range<usize>> {
+        let mut found = Vec::new();
+        let mut position = 0;
+        while position < text.len() {
+            let matched = is_word_boundary(text, position)
+                .then(|| self.phrases.iter().find_map(|words| match_phrase(text, position, words)))
+                .flatten();
+            match matched {
+                Some(end) => {
+                    found.push(position..end);
+                    position = end;
+                }
+                None => position += text[position..].chars().next().map_or(1, char::len_utf8),
+            }
+        }
+        found
+    }
+}
+
+/// End of `words` matched case-insensitively at `start`, if they end on a
+/// word boundary
+fn match_phrase(text: &str, start: usize, words: &[String]) -> Option<usize> {
+    let mut end = start;
+    for (i, word) in words.iter().enumerate() {
+        if i > 0 {
+            let rest = &text[end..];
+            let spaces = rest.len() - rest.trim_start().len();
+            if spaces == 0 {
+                return None;
+            }
+            end += spaces;
+        }
+        let mut expected = word.chars();
+        for c in text[end..].chars() {
+            if expected.as_str().is_empty() {
+                break;
+            }
+            if !c.to_lowercase().all(|lower| expected.next() == Some(lower)) {
+                return None;
+            }
+            end += c.len_utf8();
+        }
+        if !expected.as_str().is_empty() {
+            return None;
+        }
+    }
+    is_word_boundary(text, end).then_some(end)
+}
+
+/// Whether `at` separates a word character from a non-word character
+fn is_word_boundary(text: &str, at: usize) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    text[..at].chars().next_back().is_some_and(is_word) != text[at..].chars().next().is_some_and(is_word)
+}
+
+// ============================================
+// Helper Functions
+// ============================================
+
+/// Whether a character adds to speech time
+/// 
+/// Whitespace, combining diacritics (Latin accents, Arabic harakat, Hebrew
+/// niqqud and cantillation), the Arabic tatweel, invisible bidi and
+/// zero-width controls, Spanish inverted marks (`¿`, `¡`, which open the
+/// question or exclamation their atom ends with), and emoji and decorative
+/// symbols are written but not spoken as characters of their own.
+pub(crate) fn is_spoken_char(c: char) -> bool {
+    !(c.is_whitespace()
+        || matches!(c,
+            '\u{0300}'..='\u{036F}'
+            | '\u{0591}'..='\u{05BD}' | '\u{05BF}' | '\u{05C1}'..='\u{05C2}' | '\u{05C4}'..='\u{05C5}' | '\u{05C7}'
+            | '\u{0610}'..='\u{061A}' | '\u{0640}' | '\u{064B}'..='\u{065F}' | '\u{0670}'
+            | '\u{06D6}'..='\u{06DC}' | '\u{06DF}'..='\u{06E4}' | '\u{06E7}'..='\u{06E8}' | '\u{06EA}'..='\u{06ED}'
+            | '\u{200B}'..='\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2060}'..='\u{2069}' | '\u{FEFF}'
+            | '¿' | '¡'
+        )
+        || is_symbol_char(c))
+}
+
+/// Text as a voice reads it, with numbers and acronyms written out (the
+/// basis of speech estimation, not of word counts)
+fn spoken_text(text: &str) -> Cow<'_, str> {
+    match expand_numbers(text) {
+        Cow::Borrowed(text) => expand_acronyms(text),
+        Cow::Owned(text) => Cow::Owned(expand_acronyms(&text).into_owned()),
+    }
+}
+
+/// Spoken characters of each whitespace-separated word of `text`
+/// 
+/// Counted as in `SpeechAtom::char_count`, so they sum to the atom's count:
+/// whether acronyms are spelled out depends on the whole text.
+pub(super) fn word_char_counts(text: &str) -> Vec<usize> {
+    let spell = expand_numbers(text).contains(char::is_lowercase);
+    text.split_whitespace()
+        .map(|word| {
+            let spoken = expand_numbers(word);
+            let spoken = if spell { spell_acronyms(&spoken).into_owned() } else { spoken.into_owned() };
+            spoken.chars().filter(|c| is_spoken_char(*c)).count()
+        })
+        .collect()
+}
+
+/// Count words in a string (`WordCounting::Morphemes`, so scripts
+/// without spaces are not counted as one word)
+pub(super) fn count_words(text: &str) -> usize {
+    WordCounting::Morphemes.count_words(text)
+}
+
+/// Whether `keyword` occurs in `text` on word boundaries (both lowercase)
+fn contains_keyword(text: &str, keyword: &str) -> bool {
+    if keyword.is_empty() {
+        return false;
+    }
+    text.match_indices(keyword).any(|(start, _)| {
+        let end = start + keyword.len();
+        let before_ok = text[..start].chars().next_back().is_none_or(|c| !c.is_alphanumeric());
+        let after_ok = text[end..].chars().next().is_none_or(|c| !c.is_alphanumeric());
+        before_ok && after_ok
+    })
+}
+
+/// Estimate syllables in a string
+/// 
+/// Counts vowel clusters per word (treating `y` as a vowel), drops a silent
+/// trailing `e`, and counts at least one per word. Words without Latin
+/// letters (e.g. CJK) count one syllable per character.
+pub fn count_syllables(text: &str) -> usize {
+    text.split_whitespace().map(word_syllables).sum()
+}
+
+/// Syllables in a single whitespace-delimited word
+fn word_syllables(word: &str) -> usize {
+    let letters: Vec<char> = word.chars()
+        .filter(|c| c.is_alphabetic())
+        .flat_map(|c| c.to_lowercase())
+        .collect();
+    if letters.is_empty() {
+        return word.chars().filter(|c| c.is_alphanumeric()).count().min(1);
+    }
+    if !letters.iter().any(|c| c.is_ascii_alphabetic()) {
+        return letters.len();
+    }
+    
+    let is_vowel = |c: char| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+    let mut count = 0;
+    let mut in_cluster = false;
+    for c in &letters {
+        if is_vowel(*c) {
+            if !in_cluster {
+                count += 1;
+            }
+            in_cluster = true;
+        } else {
+            in_cluster = false;
+        }
+    }
+    
+    // Silent trailing "e" ("breathe", "release") but not "-le" ("gentle")
+    let n = letters.len();
+    if count > 1 && letters[n - 1] == 'e' && !is_vowel(letters[n - 2]) && letters[n - 2] != 'l' {
+        count -= 1;
+    }
+    count.max(1)
+}
+
+/// Precedence of a pause class when several end one atom (mirrors the
+/// order `classify_punctuation` checks them in)
+fn class_strength(punctuation: PunctuationType) -> u8 {
+    match punctuation {
+        PunctuationType::None => 0,
+        PunctuationType::Comma | PunctuationType::Quote => 1,
+        PunctuationType::Dash => 2,
+        PunctuationType::Semicolon => 3,
+        PunctuationType::SentenceEnd => 4,
+        PunctuationType::Ellipsis => 5,
+        PunctuationType::Paragraph => 6,
+    }
+}
+
+/// Classify punctuation and return type + character
+pub(super) fn classify_punctuation(punct: &str) -> (PunctuationType, &str) {
+    if punct.is_empty() {
+        return (PunctuationType::None, "");
+    }
+    
+    // Check for paragraph/newline first (higher priority)
+    if punct.contains('\n') {
+        return (PunctuationType::Paragraph, punct);
+    }
+    
+    // Check for ellipsis before plain periods
+    if punct.contains("...") || punct.contains('…') {
+        return (PunctuationType::Ellipsis, "...");
+    }
+    
+    // Check for sentence-ending punctuation
+    if punct.contains(['.', '?', '!', '؟', '۔', '׃', '।', '॥']) {
+        // Return just the first punctuation mark
+        let end = punct.chars().next().map_or(0, char::len_utf8);
+        return (PunctuationType::SentenceEnd, &punct[..end]);
+    }
+    
+    // Check for clause punctuation
+    if punct.contains(';') {
+        return (PunctuationType::Semicolon, ";");
+    }
+    if punct.contains('؛') {
+        return (PunctuationType::Semicolon, "؛");
+    }
+    
+    if let Some(start) = punct.find(['—', '–']) {
+        let end = start + punct[start..].chars().next().map_or(0, char::len_utf8);
+        return (PunctuationType::Dash, &punct[start..end]);
+    }
+    
+    // Check for comma
+    if punct.contains(',') {
+        return (PunctuationType::Comma, ",");
+    }
+    if punct.contains('،') {
+        return (PunctuationType::Comma, "،");
+    }
+    
+    (PunctuationType::None, "")
+}
+
+/// The first dash in `punct` with the space written before it, if any
+fn spaced_dash(punct: &str) -> Option<&str> {
+    let dash = punct.find(['—', '–'])?;
+    let space = punct[..dash].chars().next_back().filter(|c| c.is_whitespace())?;
+    Some(&punct[dash - space.len_utf8()..dash + '—'.len_utf8()])
+}
+
+/// Opening quote characters and the character that closes each
+const QUOTE_PAIRS: [(char, char); 6] = [
+    ('"', '"'),
+    ('“', '”'),
+    ('„', '“'),
+    ('«', '»'),
+    ('‘', '’'),
+    ('\'', '\''),
+];
+
+/// The closing quote for `c` if it opens a quote here: at the start of a
+/// word (so apostrophes in "don't" are not quotes), before spoken text
+fn quote_opened(c: char, prev: Option<char>, next: Option<char>) -> Option<char> {
+    let (_, close) = QUOTE_PAIRS.iter().find(|(open, _)| *open == c)?;
+    let at_word_start = prev.is_none_or(|p| !p.is_alphanumeric()) && next.is_some_and(|n| !n.is_whitespace());
+    at_word_start.then_some(*close)
+}
+
+/// Whether `c` closes a quote here: at the end of a word
+fn quote_closes(c: char, close: char, next: Option<char>) -> bool {
+    c == close && next.is_none_or(|n| !n.is_alphanumeric())
+}
+
+/// The closing quote `text` is waiting for, if it opens a quote it does
+/// not close
+fn open_quote(text: &str) -> Option<char> {
+    let mut open = None;
+    let mut prev = None;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let next = chars.peek().copied();
+        open = match open {
+            Some(close) if quote_closes(c, close, next) => None,
+            Some(close) => Some(close),
+            None => quote_opened(c, prev, next),
+        };
+        prev = Some(c);
+    }
+    open
+}
+
+/// Byte offset of the quote closing with `close` at or after `from`,
+/// searching no further than the end of the paragraph
+fn find_closing_quote(text: &str, from: usize, close: char) -> Option<usize> {
+    let mut chars = text[from..].char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == '\n' {
+            return None;
+        }
+        if quote_closes(c, close, chars.peek().map(|(_, n)| *n)) {
+            return Some(from + i);
+        }
+    }
+    None
+}
+
+/// Whether `text` starts (after spaces) with an opening quote
+fn opens_quote(text: &str) -> bool {
+    let mut chars = text.trim_start_matches(|c: char| c.is_whitespace() && c != '\n').chars();
+    chars.next().is_some_and(|c| quote_opened(c, None, chars.next()).is_some())
+}
+
+/// Classify a delimiter run and collapse it to a single mark
+/// 
+/// Spaces inside the run are ignored (". . ." is an ellipsis), except one
+/// written before a dash ("relax — rest" keeps " —"), and a paragraph
+/// keeps its line breaks after the collapsed mark ("Wait!!!" and a blank
+/// line render as "!" and the blank line).
+fn collapse_punctuation(punct: &str) -> (PunctuationType, Cow<'_, str>) {
+    if punct.contains(|c: char| c.is_whitespace() && c != '\n') {
+        let compact: String = punct.chars().filter(|c| *c == '\n' || !c.is_whitespace()).collect();
+        let (punct_type, punct_char) = collapse_punctuation(&compact);
+        if punct_type == PunctuationType::Dash {
+            if let Some(spaced) = spaced_dash(punct) {
+                return (punct_type, Cow::Borrowed(spaced));
+            }
+        }
+        return (punct_type, Cow::Owned(punct_char.into_owned()));
+    }
+    
+    let (punct_type, punct_char) = classify_punctuation(punct);
+    if punct_type != PunctuationType::Paragraph {
+        return (punct_type, Cow::Borrowed(punct_char));
+    }
+    let marks: String = punct.chars().filter(|c| *c != '\n').collect();
+    let collapsed = classify_punctuation(&marks).1.to_string() + &"\n".repeat(punct.matches('\n').count());
+    if collapsed == punct {
+        (punct_type, Cow::Borrowed(punct))
+    } else {
+        (punct_type, Cow::Owned(collapsed))
+    }
+}
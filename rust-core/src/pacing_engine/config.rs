@@ -0,0 +1,1215 @@
+//! Pacing Configuration
+//! 
+//! `PacingConfig` and the types it is built from: punctuation weights,
+//! speech-rate presets, pause curves, break formatting and budgets,
+//! limits, and per-atom overrides, plus config validation.
+
+use super::*;
+
+// ============================================
+// Punctuation Weights
+// ============================================
+
+/// Weight for comma pauses (short breath)
+pub(super) const WEIGHT_COMMA: u32 = 1;
+
+/// Weight for semicolons (clause pause)
+pub(super) const WEIGHT_SEMICOLON: u32 = 2;
+
+/// Weight for em/en dashes (clause pause)
+pub(super) const WEIGHT_DASH: u32 = 2;
+
+/// Weight for sentence-ending punctuation (natural pause)
+pub(super) const WEIGHT_SENTENCE: u32 = 3;
+
+/// Weight for ellipses (trailing-off pause)
+pub(super) const WEIGHT_ELLIPSIS: u32 = 4;
+
+/// Weight for paragraph breaks (long contemplative pause)
+pub(super) const WEIGHT_PARAGRAPH: u32 = 5;
+
+/// Weight for the edges of a quoted span (soft pause)
+pub(super) const WEIGHT_QUOTE: u32 = 1;
+
+// ============================================
+// Types
+// ============================================
+
+/// Shape of a pause envelope over the script
+/// 
+/// Maps progress through the script (0.0 to 1.0) onto how far the envelope
+/// has moved from its start value to its end value (also 0.0 to 1.0).
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PauseCurve {
+    /// Constant rate of change
+    Linear,
+    /// Slow change early, fast change late (quadratic)
+    EaseIn,
+    /// Fast change early, slow change late (quadratic)
+    EaseOut,
+    /// Flat at both ends with the change concentrated mid-script
+    Sigmoid,
+    /// `t^exponent` (1.0 = linear, > 1.0 = change concentrated near the end)
+    Power(f64),
+    /// Caller-supplied shape; output is clamped to 0.0..=1.0
+    /// 
+    /// Not serializable; configs using it fail to serialize.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(Arc<dyn Fn(f64) -> f64 + Send + Sync>),
+}
+
+impl PauseCurve {
+    /// Sigmoid steepness (higher = sharper transition)
+    const SIGMOID_STEEPNESS: f64 = 10.0;
+
+    /// Evaluate the curve at `progress` (clamped to 0.0..=1.0)
+    pub fn shape(&self, progress: f64) -> f64 {
+        let t = progress.clamp(0.0, 1.0);
+        let value = match self {
+            PauseCurve::Linear => t,
+            PauseCurve::EaseIn => t * t,
+            PauseCurve::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            PauseCurve::Sigmoid => {
+                // Logistic curve rescaled so it passes exactly through 0 and 1
+                let logistic = |x: f64| 1.0 / (1.0 + (-Self::SIGMOID_STEEPNESS * (x - 0.5)).exp());
+                (logistic(t) - logistic(0.0)) / (logistic(1.0) - logistic(0.0))
+            }
+            PauseCurve::Power(exponent) => t.powf(exponent.max(0.0)),
+            PauseCurve::Custom(f) => f(t),
+        };
+        value.clamp(0.0, 1.0)
+    }
+}
+
+impl fmt::Debug for PauseCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PauseCurve::Linear => write!(f, "Linear"),
+            PauseCurve::EaseIn => write!(f, "EaseIn"),
+            PauseCurve::EaseOut => write!(f, "EaseOut"),
+            PauseCurve::Sigmoid => write!(f, "Sigmoid"),
+            PauseCurve::Power(exponent) => f.debug_tuple("Power").field(exponent).finish(),
+            PauseCurve::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+/// Languages with a calibrated character-based speech rate
+/// 
+/// Rates are characters per second excluding whitespace. Scripts with
+/// dense characters (kanji, hanzi, hangul) carry far more speech per
+/// character than alphabetic scripts, so the 12 cps English constant is
+/// badly off for them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum Language {
+    /// English (production-calibrated)
+    English,
+    /// Spanish
+    Spanish,
+    /// French
+    French,
+    /// German
+    German,
+    /// Italian
+    Italian,
+    /// Portuguese
+    Portuguese,
+    /// Japanese
+    Japanese,
+    /// Mandarin Chinese
+    Mandarin,
+    /// Korean
+    Korean,
+    /// Hindi
+    Hindi,
+    /// Arabic
+    Arabic,
+}
+
+impl Language {
+    /// All languages with presets
+    pub const ALL: [Language; 11] = [
+        Language::English,
+        Language::Spanish,
+        Language::French,
+        Language::German,
+        Language::Italian,
+        Language::Portuguese,
+        Language::Japanese,
+        Language::Mandarin,
+        Language::Korean,
+        Language::Hindi,
+        Language::Arabic,
+    ];
+
+    /// Characters per second (excluding whitespace) for TTS in this language
+    pub fn chars_per_second(&self) -> f64 {
+        match self {
+            Language::English => CHARS_PER_SECOND,
+            Language::Spanish => 13.5,
+            Language::French => 13.0,
+            Language::German => 11.5,
+            Language::Italian => 13.0,
+            Language::Portuguese => 13.0,
+            Language::Japanese => 5.5,
+            Language::Mandarin => 4.5,
+            Language::Korean => 6.0,
+            Language::Hindi => 11.0,
+            Language::Arabic => 10.0,
+        }
+    }
+
+    /// ISO 639-1 code
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+            Language::French => "fr",
+            Language::German => "de",
+            Language::Italian => "it",
+            Language::Portuguese => "pt",
+            Language::Japanese => "ja",
+            Language::Mandarin => "zh",
+            Language::Korean => "ko",
+            Language::Hindi => "hi",
+            Language::Arabic => "ar",
+        }
+    }
+
+    /// Look up a language by ISO 639-1 code or BCP 47 tag (e.g. "es-MX")
+    pub fn from_code(code: &str) -> Option<Language> {
+        let primary = code.split(['-', '_']).next()?.to_ascii_lowercase();
+        Self::ALL.iter().copied().find(|l| l.code() == primary)
+    }
+}
+
+/// How speech duration is estimated from text
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum DurationModel {
+    /// Characters (excluding whitespace) at `chars_per_second`
+    #[default]
+    Characters,
+    /// Syllables (vowel-cluster heuristic) at `syllables_per_second`;
+    /// tracks long words like "consciousness" better than characters
+    Syllables,
+    /// Words at the given spoken words per minute
+    WordsPerMinute(f64),
+}
+
+/// How the silence budget is shared between atoms
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum AllocationStrategy {
+    /// Proportional to punctuation weight (plus keyword bonuses)
+    #[default]
+    Weighted,
+    /// Every sentence gets the same pause regardless of its punctuation;
+    /// mid-sentence punctuation gets none. Suits body-scan scripts
+    /// ("Notice your feet. Notice your calves.").
+    EqualPerSentence,
+}
+
+/// Curated configurations for common session types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum Preset {
+    /// Bedtime stories: slower voice, long and growing pauses
+    Sleep,
+    /// Focus sessions: near-normal delivery, short even pauses
+    Focus,
+    /// Guided breathing: every sentence gets the same pause
+    Breathwork,
+    /// Two-to-five minute resets: brisk, with a small safety buffer
+    QuickReset,
+}
+
+/// Acceptable range for the share of a session spent in silence
+/// 
+/// The ratio is the raw silence budget divided by the target duration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SilenceRatioLimits {
+    /// Lowest acceptable ratio (below this the script is too dense)
+    pub min_ratio: f64,
+    /// Highest acceptable ratio (above this the session is mostly dead air)
+    pub max_ratio: f64,
+    /// Whether `calculate_pacing_checked` rejects out-of-range sessions
+    pub strict: bool,
+}
+
+impl Default for SilenceRatioLimits {
+    fn default() -> Self {
+        Self {
+            min_ratio: MIN_SILENCE_RATIO,
+            max_ratio: MAX_SILENCE_RATIO,
+            strict: false,
+        }
+    }
+}
+
+/// Accepted range of target durations
+/// 
+/// Targets outside the range are reported as
+/// `PacingWarning::TargetOutOfRange`, and rejected by
+/// `calculate_pacing_minutes`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct TargetDurationLimits {
+    /// Shortest accepted target
+    pub min_seconds: f64,
+    /// Longest accepted target
+    pub max_seconds: f64,
+    /// Whether `calculate_pacing` paces out-of-range targets at the
+    /// nearest limit instead of as requested (non-finite targets always
+    /// are)
+    pub clamp: bool,
+}
+
+impl TargetDurationLimits {
+    /// Whether `target_seconds` is within the range
+    pub fn contains(&self, target_seconds: f64) -> bool {
+        (self.min_seconds..=self.max_seconds).contains(&target_seconds)
+    }
+}
+
+impl Default for TargetDurationLimits {
+    fn default() -> Self {
+        Self {
+            min_seconds: MIN_TARGET_SECONDS,
+            max_seconds: MAX_TARGET_SECONDS,
+            clamp: false,
+        }
+    }
+}
+
+/// Unit of the `time` attribute in rendered break tags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum BreakUnit {
+    /// `time="1.5s"`
+    #[default]
+    Seconds,
+    /// `time="1500ms"`
+    Milliseconds,
+}
+
+/// How break durations are written into SSML
+/// 
+/// The default (`{:.1}s`) rounds each tag to a tenth of a second, losing
+/// up to 50ms per break; milliseconds or more decimals keep long scripts
+/// closer to their silence budget, for providers that accept them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct BreakFormat {
+    /// Unit written after the number
+    pub unit: BreakUnit,
+    /// Decimal places of the number, in `unit` (at most 6)
+    pub decimals: u32,
+}
+
+impl BreakFormat {
+    /// Whole milliseconds (`time="850ms"`)
+    pub fn milliseconds() -> Self {
+        Self { unit: BreakUnit::Milliseconds, decimals: 0 }
+    }
+
+    /// Seconds with the given decimal places (`time="0.85s"` for 2)
+    pub fn seconds(decimals: u32) -> Self {
+        Self { unit: BreakUnit::Seconds, decimals }
+    }
+
+    /// Smallest duration step the format can express, in seconds
+    pub fn quantum_seconds(&self) -> f64 {
+        let step = 10f64.powi(-(self.decimals.min(MAX_BREAK_DECIMALS) as i32));
+        match self.unit {
+            BreakUnit::Seconds => step,
+            BreakUnit::Milliseconds => step / 1000.0,
+        }
+    }
+
+    /// Round a duration to what the format renders
+    pub fn round(&self, seconds: f64) -> f64 {
+        let quantum = self.quantum_seconds();
+        (seconds / quantum).round() * quantum
+    }
+
+    /// The `time` attribute value for a duration (e.g. `1.5s`)
+    pub fn format(&self, seconds: f64) -> String {
+        let decimals = self.decimals.min(MAX_BREAK_DECIMALS) as usize;
+        match self.unit {
+            BreakUnit::Seconds => format!("{:.*}s", decimals, seconds),
+            BreakUnit::Milliseconds => format!("{:.*}ms", decimals, seconds * 1000.0),
+        }
+    }
+}
+
+impl Default for BreakFormat {
+    fn default() -> Self {
+        Self::seconds(1)
+    }
+}
+
+/// Per-request caps some providers put on break tags
+/// 
+/// A script over the tag cap has its shortest pauses merged into the
+/// others (fewer, longer breaks); if it is still over either cap, the
+/// result carries `PacingWarning::BreakBudgetExceeded` with the number of
+/// requests to split it into. Chunked output keeps every chunk within the
+/// caps instead.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct BreakBudget {
+    /// Most break time one request may contain
+    pub max_total_seconds: Option<f64>,
+    /// Most break tags one request may contain
+    pub max_tags: Option<usize>,
+}
+
+impl BreakBudget {
+    /// Whether `tag_count` tags totalling `break_seconds` fit the caps
+    pub fn allows(&self, tag_count: usize, break_seconds: f64) -> bool {
+        self.max_tags.is_none_or(|max| tag_count <= max)
+            && self.max_total_seconds.is_none_or(|max| break_seconds <= max + 1e-9)
+    }
+
+    /// Requests needed to stay within the caps
+    pub fn requests_needed(&self, tag_count: usize, break_seconds: f64) -> usize {
+        let by_tags = self.max_tags.map_or(1, |max| tag_count.div_ceil(max.max(1)));
+        let by_seconds = self.max_total_seconds.map_or(1, |max| (break_seconds / max).ceil() as usize);
+        by_tags.max(by_seconds).max(1)
+    }
+}
+
+/// Deterministic random variation applied to individual pauses
+/// 
+/// Each weighted pause is scaled by a factor in `1.0 ± amount` before the
+/// budget is shared out, so the total silence is unchanged. The same seed
+/// and script always produce the same pauses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct PauseJitter {
+    /// Maximum relative deviation (e.g. 0.15 = ±15%)
+    pub amount: f64,
+    /// Seed for the pseudo-random sequence
+    pub seed: u64,
+}
+
+impl PauseJitter {
+    /// Create a jitter setting
+    pub fn new(amount: f64, seed: u64) -> Self {
+        Self { amount, seed }
+    }
+
+    /// Weight multiplier for the atom ending at `progress`
+    pub(super) fn factor(&self, progress: f64) -> f64 {
+        let unit = splitmix64(self.seed ^ progress.to_bits()) as f64 / u64::MAX as f64;
+        (1.0 + self.amount.clamp(0.0, 1.0) * (unit * 2.0 - 1.0)).max(0.0)
+    }
+}
+
+/// Gradual change in speaking rate over a script, rendered as
+/// `<prosody rate="..">` around each atom
+/// 
+/// Rates are fractions of normal speed (1.0 = unchanged, 0.75 = 75%). The
+/// speech estimate accounts for the slower delivery.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ProsodyRamp {
+    /// Speaking rate at the start of the script
+    pub start_rate: f64,
+    /// Speaking rate at the end of the script
+    pub end_rate: f64,
+}
+
+impl ProsodyRamp {
+    /// Slowest rate the ramp will produce
+    const MIN_RATE: f64 = 0.2;
+
+    /// Create a ramp between two rates
+    pub fn new(start_rate: f64, end_rate: f64) -> Self {
+        Self {
+            start_rate,
+            end_rate,
+        }
+    }
+
+    /// Speaking rate at `progress` (0.0 = start of script, 1.0 = end)
+    pub fn rate_at(&self, progress: f64) -> f64 {
+        let t = progress.clamp(0.0, 1.0);
+        (self.start_rate + (self.end_rate - self.start_rate) * t).max(Self::MIN_RATE)
+    }
+}
+
+/// A problem found by `PacingConfig::validate`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigError {
+    /// A speech rate (or the repeated-punctuation multiplier) is zero,
+    /// negative, or not finite
+    NonPositiveRate {
+        /// Name of the offending field
+        field: &'static str,
+        /// Its value
+        value: f64,
+    },
+    /// `max_break_seconds` is zero, negative, or not finite
+    NonPositiveMaxBreak(f64),
+    /// `min_break_seconds` is negative or not a number
+    NegativeMinBreak(f64),
+    /// A fixed pause (`intro_pause_seconds`, `outro_pause_seconds`, or a
+    /// `pause_floors` entry) is negative or not finite
+    InvalidFixedPause {
+        /// Name of the offending field
+        field: &'static str,
+        /// Its value
+        value: f64,
+    },
+    /// `min_break_seconds` is larger than `max_break_seconds`
+    MinBreakExceedsMax {
+        /// Configured minimum break
+        min_break_seconds: f64,
+        /// Configured maximum break
+        max_break_seconds: f64,
+    },
+    /// `max_break_seconds` is shorter than the smallest step `break_format`
+    /// can render, so every break would round to zero
+    MaxBreakBelowQuantum {
+        /// Configured maximum break
+        max_break_seconds: f64,
+        /// Smallest step of the break format
+        quantum_seconds: f64,
+    },
+    /// `silence_safety_buffer` would shrink the silence budget
+    SafetyBufferBelowOne(f64),
+    /// Every pausing punctuation class has weight zero
+    AllWeightsZero,
+    /// A `break_budget` cap is zero, negative, or not finite
+    InvalidBreakBudget,
+    /// `target_limits` is not a finite, non-negative, non-empty range
+    InvalidTargetLimits {
+        /// Configured shortest target
+        min_seconds: f64,
+        /// Configured longest target
+        max_seconds: f64,
+    },
+    /// Several `banned_words` keys differ only in case or spacing (the
+    /// phrase they share)
+    DuplicateBannedWord(String),
+    /// A custom `atom_pattern` does not compile or lacks the two capture
+    /// groups (the reason)
+    InvalidAtomPattern(String),
+    /// A custom `atom_pattern` does not end atoms at one of the locale's
+    /// punctuation marks
+    PatternIgnoresLocale(char),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::NonPositiveRate { field, value } => write!(f, "{} must be positive, got {}", field, value),
+            ConfigError::NonPositiveMaxBreak(v) => write!(f, "max_break_seconds must be positive, got {}", v),
+            ConfigError::NegativeMinBreak(v) => write!(f, "min_break_seconds must not be negative, got {}", v),
+            ConfigError::InvalidFixedPause { field, value } => {
+                write!(f, "{} must be finite and not negative, got {}", field, value)
+            }
+            ConfigError::MinBreakExceedsMax { min_break_seconds, max_break_seconds } => write!(
+                f,
+                "min_break_seconds ({}) exceeds max_break_seconds ({})",
+                min_break_seconds, max_break_seconds,
+            ),
+            ConfigError::MaxBreakBelowQuantum { max_break_seconds, quantum_seconds } => write!(
+                f,
+                "max_break_seconds ({}) is below the break format's step ({})",
+                max_break_seconds, quantum_seconds,
+            ),
+            ConfigError::SafetyBufferBelowOne(v) => write!(f, "silence_safety_buffer must be at least 1.0, got {}", v),
+            ConfigError::AllWeightsZero => write!(f, "all punctuation weights are zero"),
+            ConfigError::InvalidBreakBudget => write!(f, "break_budget caps must be positive"),
+            ConfigError::InvalidTargetLimits { min_seconds, max_seconds } => write!(
+                f,
+                "target_limits must span a finite, non-negative range, got {} to {}",
+                min_seconds, max_seconds,
+            ),
+            ConfigError::DuplicateBannedWord(phrase) => {
+                write!(f, "banned_words has more than one entry for \"{}\"", phrase)
+            }
+            ConfigError::InvalidAtomPattern(reason) => write!(f, "atom_pattern is invalid: {}", reason),
+            ConfigError::PatternIgnoresLocale(mark) => {
+                write!(f, "atom_pattern does not split on the locale's '{}'", mark)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ConfigError {}
+
+/// A pause envelope that grows (or shrinks) over the course of a script
+/// 
+/// Each atom's weight is scaled by a multiplier moving from
+/// `start_multiplier` to `end_multiplier` along `shape`, according to how
+/// far through the script (by characters spoken) the atom ends. The total
+/// silence budget is unchanged; only its distribution shifts.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ProgressiveCurve {
+    /// Weight multiplier at the start of the script
+    pub start_multiplier: f64,
+    /// Weight multiplier at the end of the script
+    pub end_multiplier: f64,
+    /// How the multiplier moves between the two
+    pub shape: PauseCurve,
+}
+
+impl ProgressiveCurve {
+    /// Pauses growing linearly from `start` to `end` times their base weight
+    pub fn linear(start_multiplier: f64, end_multiplier: f64) -> Self {
+        Self::shaped(start_multiplier, end_multiplier, PauseCurve::Linear)
+    }
+
+    /// Pauses moving from `start` to `end` times their base weight along `shape`
+    pub fn shaped(start_multiplier: f64, end_multiplier: f64, shape: PauseCurve) -> Self {
+        Self {
+            start_multiplier,
+            end_multiplier,
+            shape,
+        }
+    }
+
+    /// Multiplier at `progress` (0.0 = start of script, 1.0 = end)
+    pub fn multiplier_at(&self, progress: f64) -> f64 {
+        let t = self.shape.shape(progress);
+        (self.start_multiplier + (self.end_multiplier - self.start_multiplier) * t).max(0.0)
+    }
+}
+
+impl Default for ProgressiveCurve {
+    /// Short intro pauses growing to long contemplative gaps (0.5x → 2x)
+    fn default() -> Self {
+        Self::linear(0.5, 2.0)
+    }
+}
+
+/// A fixed adjustment applied to a single atom before silence distribution
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AtomOverride {
+    /// Replace the atom's silence weight
+    Weight(u32),
+    /// Pin the pause after the atom to an exact duration in seconds
+    /// 
+    /// Pinned pauses are taken out of the silence budget first; only the
+    /// remainder is distributed across the other atoms by weight.
+    Pause(f64),
+}
+
+/// Predicate used to select atoms for an override
+pub type AtomPredicate = Arc<dyn Fn(&SpeechAtom) -> bool + Send + Sync>;
+
+/// Per-atom overrides applied before rendering
+/// 
+/// Overrides can target an atom index (as returned by
+/// `MeditationPacer::atomize`) or any atom matching a predicate.
+/// Index overrides take precedence over predicate overrides, and earlier
+/// predicates take precedence over later ones.
+/// 
+/// ```rust
+/// use zenpal_core::{MeditationPacer, PacingOverrides};
+/// 
+/// let overrides = PacingOverrides::new()
+///     .pause_where(|atom| atom.text.contains("scan your body"), 15.0);
+/// let result = MeditationPacer::new().calculate_pacing_with_overrides(
+///     "Now, scan your body. Notice any tension.".to_string(),
+///     60.0,
+///     &overrides,
+/// );
+/// assert!(result.ssml.contains("<break"));
+/// ```
+#[derive(Clone, Default)]
+pub struct PacingOverrides {
+    by_index: BTreeMap<usize, AtomOverride>,
+    by_predicate: Vec<(AtomPredicate, AtomOverride)>,
+    anchors_by_index: BTreeMap<usize, f64>,
+    anchors_by_predicate: Vec<(AtomPredicate, f64)>,
+}
+
+impl PacingOverrides {
+    /// Create an empty override set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the atom at `index`
+    pub fn at(mut self, index: usize, atom_override: AtomOverride) -> Self {
+        self.by_index.insert(index, atom_override);
+        self
+    }
+
+    /// Override every atom matching `predicate`
+    pub fn matching<F>(mut self, predicate: F, atom_override: AtomOverride) -> Self
+    where
+        F: Fn(&SpeechAtom) -> bool + Send + Sync + 'static,
+    {
+        self.by_predicate.push((Arc::new(predicate), atom_override));
+        self
+    }
+
+    /// Pin the pause after the atom at `index`
+    pub fn pause_at(self, index: usize, seconds: f64) -> Self {
+        self.at(index, AtomOverride::Pause(seconds))
+    }
+
+    /// Pin the pause after every atom matching `predicate`
+    pub fn pause_where<F>(self, predicate: F, seconds: f64) -> Self
+    where
+        F: Fn(&SpeechAtom) -> bool + Send + Sync + 'static,
+    {
+        self.matching(predicate, AtomOverride::Pause(seconds))
+    }
+
+    /// Replace the weight of the atom at `index`
+    pub fn weight_at(self, index: usize, weight: u32) -> Self {
+        self.at(index, AtomOverride::Weight(weight))
+    }
+
+    /// Pin the atom at `index` to start at an absolute time in seconds
+    /// 
+    /// Silence before and after each anchor is solved independently so the
+    /// anchored atom starts exactly on time (per the speech estimate).
+    /// Anchors on the first atom are ignored since nothing precedes it.
+    pub fn anchor_at(mut self, index: usize, start_seconds: f64) -> Self {
+        self.anchors_by_index.insert(index, start_seconds);
+        self
+    }
+
+    /// Pin the first atom matching `predicate` to start at an absolute time
+    pub fn anchor_where<F>(mut self, predicate: F, start_seconds: f64) -> Self
+    where
+        F: Fn(&SpeechAtom) -> bool + Send + Sync + 'static,
+    {
+        self.anchors_by_predicate.push((Arc::new(predicate), start_seconds));
+        self
+    }
+
+    /// Whether no overrides have been set
+    pub fn is_empty(&self) -> bool {
+        self.by_index.is_empty()
+            && self.by_predicate.is_empty()
+            && self.anchors_by_index.is_empty()
+            && self.anchors_by_predicate.is_empty()
+    }
+
+    /// Resolve anchors to `(atom index, start seconds)` pairs
+    /// 
+    /// Sorted by index; anchors on atom 0, past the end, or not later than
+    /// the previous anchor are dropped.
+    pub fn resolve_anchors(&self, atoms: &[SpeechAtom]) -> Vec<(usize, f64)> {
+        let mut anchors: BTreeMap<usize, f64> = BTreeMap::new();
+        for (predicate, seconds) in &self.anchors_by_predicate {
+            if let Some(i) = atoms.iter().position(|a| predicate(a)) {
+                anchors.entry(i).or_insert(*seconds);
+            }
+        }
+        anchors.extend(self.anchors_by_index.iter().map(|(i, t)| (*i, *t)));
+        
+        let mut resolved: Vec<(usize, f64)> = Vec::new();
+        for (index, seconds) in anchors {
+            let after_previous = resolved.last().is_none_or(|(_, t)| seconds > *t);
+            if index > 0 && index < atoms.len() && seconds.is_finite() && after_previous {
+                resolved.push((index, seconds));
+            }
+        }
+        resolved
+    }
+
+    /// Resolve the override (if any) that applies to an atom
+    pub fn resolve(&self, index: usize, atom: &SpeechAtom) -> Option<AtomOverride> {
+        if let Some(o) = self.by_index.get(&index) {
+            return Some(*o);
+        }
+        self.by_predicate
+            .iter()
+            .find(|(predicate, _)| predicate(atom))
+            .map(|(_, o)| *o)
+    }
+}
+
+impl fmt::Debug for PacingOverrides {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PacingOverrides")
+            .field("by_index", &self.by_index)
+            .field("by_predicate", &self.by_predicate.len())
+            .field("anchors_by_index", &self.anchors_by_index)
+            .field("anchors_by_predicate", &self.anchors_by_predicate.len())
+            .finish()
+    }
+}
+
+/// Configuration for the pacing engine
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct PacingConfig {
+    /// Character-based speech rate (chars per second, excluding whitespace)
+    pub chars_per_second: f64,
+    /// Script language; when set, its preset rate replaces `chars_per_second`
+    pub language: Option<Language>,
+    /// Per-language punctuation and word-counting rules; its language's
+    /// rate applies when `language` is not set
+    pub locale: Option<Locale>,
+    /// Syllable-based speech rate (used by `DurationModel::Syllables`)
+    pub syllables_per_second: f64,
+    /// Which unit speech duration is estimated from
+    pub duration_model: DurationModel,
+    /// Safety buffer multiplier for silence (e.g., 1.1 = 10% extra)
+    pub silence_safety_buffer: f64,
+    /// Maximum seconds per break tag
+    pub max_break_seconds: f64,
+    /// Minimum seconds per break tag
+    pub min_break_seconds: f64,
+    /// Unit and precision of the `time` attribute in break tags
+    pub break_format: BreakFormat,
+    /// Provider caps on break time and tags per request
+    pub break_budget: BreakBudget,
+    /// Reassign time from breaks that would fall below `min_break_seconds`
+    /// to the remaining breaks, so the full budget is spent
+    pub redistribute_dropped_silence: bool,
+    /// Silence before the first atom (e.g. a few seconds to get
+    /// comfortable), counted against the target; 0 renders no opening break
+    pub intro_pause_seconds: f64,
+    /// Silence after the last atom (e.g. a fade-out at the end of a sleep
+    /// session), counted against the target; 0 renders no final break
+    pub outro_pause_seconds: f64,
+    /// Silence weight per punctuation type (missing entries weigh 0)
+    pub weights: HashMap<PunctuationType, u32>,
+    /// Keep quoted spans ("say to yourself, 'I am calm.'") in one atom
+    /// instead of splitting on the punctuation inside them
+    pub keep_quotes_intact: bool,
+    /// Pause before and after a quoted span as `PunctuationType::Quote`
+    /// (a soft pause) instead of its punctuation; paragraph breaks are kept
+    pub soften_quote_pauses: bool,
+    /// Weight multiplier for atoms ending in a repeated run such as
+    /// "Really??!" or "Relax....", which renders as a single mark; 1.0
+    /// weighs the run like the single mark
+    pub repeated_punctuation_multiplier: f64,
+    /// Minimum pause per punctuation type (e.g. paragraphs always at
+    /// least 4s), kept even when the budget is too tight for the weights
+    /// to reach it; floors may push the session past the target
+    pub pause_floors: HashMap<PunctuationType, f64>,
+    /// Custom atomization regex; `None` uses `DEFAULT_ATOM_PATTERN`
+    /// 
+    /// Must have two capture groups: the spoken text and the delimiter
+    /// that ends it; `validate` reports a pattern that does not compile
+    /// or lacks them, and pacing falls back to the default. A
+    /// custom pattern replaces the locale's punctuation and `markers`, so
+    /// with a locale it must split on the locale's marks itself.
+    pub atom_pattern: Option<String>,
+    /// Silent marker tokens (e.g. `[[pause]]`) and the pause class each
+    /// stands for. Markers are stripped from the rendered text.
+    pub markers: HashMap<String, PunctuationType>,
+    /// How the silence budget is shared between atoms
+    pub allocation: AllocationStrategy,
+    /// Optional progressive pacing (pauses lengthen as the session goes on)
+    pub progression: Option<ProgressiveCurve>,
+    /// Optional seeded jitter so pauses sound less mechanical
+    pub jitter: Option<PauseJitter>,
+    /// Optional speaking-rate ramp (e.g. slowing down for sleep content)
+    pub prosody_ramp: Option<ProsodyRamp>,
+    /// Guardrails on the silence-to-speech ratio
+    pub silence_ratio_limits: SilenceRatioLimits,
+    /// Accepted range of target durations
+    pub target_limits: TargetDurationLimits,
+    /// Bonus silence weight for atoms containing a keyword or phrase
+    /// (matched case-insensitively on word boundaries, e.g. "let go")
+    pub keyword_bonuses: HashMap<String, u32>,
+    /// Words or phrases filtered out before atomization, so they never
+    /// reach TTS (matched case-insensitively on word boundaries)
+    pub banned_words: HashMap<String, BannedWordAction>,
+    /// Reduce HTML (e.g. pasted from a CMS) to plain text before
+    /// atomization, turning `<br>` and block elements into line breaks
+    pub strip_html: bool,
+    /// What to do with emoji and decorative symbols before atomization
+    pub symbols: SymbolHandling,
+    /// Write numbers and times out in (English) words before atomization,
+    /// so the voice reads what the speech estimate counted
+    pub spell_out_numbers: bool,
+}
+
+impl PacingConfig {
+    /// Default configuration with the speech rate preset for `language`
+    pub fn for_language(language: Language) -> Self {
+        Self {
+            language: Some(language),
+            ..Self::default()
+        }
+    }
+
+    /// Default configuration with the rules of `locale`
+    pub fn for_locale(locale: Locale) -> Self {
+        Self {
+            locale: Some(locale),
+            ..Self::default()
+        }
+    }
+
+    /// The language whose preset rate applies: `language`, else the
+    /// locale's language
+    pub fn effective_language(&self) -> Option<Language> {
+        self.language.or(self.locale.as_ref().map(|locale| locale.language))
+    }
+
+    /// Characters per second actually used for estimation
+    /// 
+    /// The preset of `effective_language` if there is one, else
+    /// `chars_per_second`.
+    pub fn effective_chars_per_second(&self) -> f64 {
+        self.effective_language().map_or(self.chars_per_second, |l| l.chars_per_second())
+    }
+
+    /// The default weight table (see `PunctuationType::weight`)
+    pub fn default_weights() -> HashMap<PunctuationType, u32> {
+        PunctuationType::PAUSING
+            .iter()
+            .map(|p| (*p, p.weight()))
+            .collect()
+    }
+
+    /// Look up the silence weight for a punctuation type
+    pub fn weight_for(&self, punctuation: PunctuationType) -> u32 {
+        self.weights.get(&punctuation).copied().unwrap_or(0)
+    }
+
+    /// Set the silence weight for a punctuation type
+    pub fn set_weight(&mut self, punctuation: PunctuationType, weight: u32) {
+        self.weights.insert(punctuation, weight);
+    }
+
+    /// Look up the minimum pause for a punctuation type (0 if none)
+    pub fn pause_floor_for(&self, punctuation: PunctuationType) -> f64 {
+        self.pause_floors.get(&punctuation).copied().unwrap_or(0.0)
+    }
+
+    /// Set the minimum pause for a punctuation type
+    pub fn set_pause_floor(&mut self, punctuation: PunctuationType, seconds: f64) {
+        self.pause_floors.insert(punctuation, seconds);
+    }
+
+    /// Sleep-story pacing: pauses grow toward the end while the voice
+    /// slows from normal speed to 75%
+    pub fn sleep_story() -> Self {
+        Self {
+            progression: Some(ProgressiveCurve::shaped(0.6, 2.5, PauseCurve::EaseIn)),
+            prosody_ramp: Some(ProsodyRamp::new(1.0, 0.75)),
+            ..Self::default()
+        }
+    }
+
+    /// Curated configuration for a common session type
+    pub fn preset(preset: Preset) -> Self {
+        match preset {
+            Preset::Sleep => {
+                let mut config = Self {
+                    chars_per_second: 11.0,
+                    silence_safety_buffer: 1.15,
+                    ..Self::sleep_story()
+                };
+                config.set_weight(PunctuationType::SentenceEnd, 4);
+                config.set_weight(PunctuationType::Ellipsis, 6);
+                config.set_weight(PunctuationType::Paragraph, 8);
+                config
+            }
+            Preset::Focus => {
+                let mut config = Self {
+                    chars_per_second: 12.5,
+                    silence_safety_buffer: 1.05,
+                    ..Self::default()
+                };
+                config.set_weight(PunctuationType::Semicolon, 1);
+                config.set_weight(PunctuationType::Dash, 1);
+                config.set_weight(PunctuationType::SentenceEnd, 2);
+                config.set_weight(PunctuationType::Paragraph, 3);
+                config
+            }
+            Preset::Breathwork => Self {
+                chars_per_second: 11.0,
+                silence_safety_buffer: 1.0,
+                allocation: AllocationStrategy::EqualPerSentence,
+                ..Self::default()
+            },
+            Preset::QuickReset => Self {
+                chars_per_second: 12.5,
+                silence_safety_buffer: 1.05,
+                silence_ratio_limits: SilenceRatioLimits {
+                    max_ratio: 0.7,
+                    ..SilenceRatioLimits::default()
+                },
+                ..Self::default()
+            },
+        }
+    }
+
+    /// Split on custom marker tokens in addition to standard punctuation
+    /// 
+    /// Records each marker's pause class. The built-in splitter ends an
+    /// atom at any run of punctuation (the locale's included) and markers;
+    /// a custom `atom_pattern` replaces it, markers and all.
+    pub fn set_markers(&mut self, markers: &[(&str, PunctuationType)]) {
+        self.markers = markers
+            .iter()
+            .map(|(m, p)| (m.to_string(), *p))
+            .collect();
+    }
+
+    /// Check the config for values that cannot produce sensible output
+    /// 
+    /// Returns every problem found, not just the first.
+    pub fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = Vec::new();
+        
+        let positive = |v: f64| v.is_finite() && v > 0.0;
+        if self.effective_language().is_none() && !positive(self.chars_per_second) {
+            errors.push(ConfigError::NonPositiveRate { field: "chars_per_second", value: self.chars_per_second });
+        }
+        if !positive(self.syllables_per_second) {
+            errors.push(ConfigError::NonPositiveRate { field: "syllables_per_second", value: self.syllables_per_second });
+        }
+        if let DurationModel::WordsPerMinute(wpm) = self.duration_model {
+            if !positive(wpm) {
+                errors.push(ConfigError::NonPositiveRate { field: "duration_model", value: wpm });
+            }
+        }
+        if !positive(self.repeated_punctuation_multiplier) {
+            errors.push(ConfigError::NonPositiveRate {
+                field: "repeated_punctuation_multiplier",
+                value: self.repeated_punctuation_multiplier,
+            });
+        }
+        if !positive(self.max_break_seconds) {
+            errors.push(ConfigError::NonPositiveMaxBreak(self.max_break_seconds));
+        }
+        if self.min_break_seconds.is_nan() || self.min_break_seconds < 0.0 {
+            errors.push(ConfigError::NegativeMinBreak(self.min_break_seconds));
+        } else if self.min_break_seconds > self.max_break_seconds {
+            errors.push(ConfigError::MinBreakExceedsMax {
+                min_break_seconds: self.min_break_seconds,
+                max_break_seconds: self.max_break_seconds,
+            });
+        }
+        let quantum_seconds = self.break_format.quantum_seconds();
+        if positive(self.max_break_seconds) && self.max_break_seconds < quantum_seconds {
+            errors.push(ConfigError::MaxBreakBelowQuantum {
+                max_break_seconds: self.max_break_seconds,
+                quantum_seconds,
+            });
+        }
+        let fixed_pauses = [("intro_pause_seconds", self.intro_pause_seconds), ("outro_pause_seconds", self.outro_pause_seconds)]
+            .into_iter()
+            .chain(self.pause_floors.values().map(|value| ("pause_floors", *value)));
+        for (field, value) in fixed_pauses {
+            if !(value.is_finite() && value >= 0.0) {
+                errors.push(ConfigError::InvalidFixedPause { field, value });
+            }
+        }
+        if !self.silence_safety_buffer.is_finite() || self.silence_safety_buffer < 1.0 {
+            errors.push(ConfigError::SafetyBufferBelowOne(self.silence_safety_buffer));
+        }
+        if PunctuationType::PAUSING.iter().all(|p| self.weight_for(*p) == 0) {
+            errors.push(ConfigError::AllWeightsZero);
+        }
+        let budget = self.break_budget;
+        if budget.max_tags == Some(0) || budget.max_total_seconds.is_some_and(|max| !(max.is_finite() && max > 0.0)) {
+            errors.push(ConfigError::InvalidBreakBudget);
+        }
+        let limits = self.target_limits;
+        if !(limits.min_seconds.is_finite() && limits.min_seconds >= 0.0 && limits.max_seconds.is_finite() && limits.max_seconds >= limits.min_seconds) {
+            errors.push(ConfigError::InvalidTargetLimits {
+                min_seconds: limits.min_seconds,
+                max_seconds: limits.max_seconds,
+            });
+        }
+        let mut phrases: Vec<String> = self.banned_words.keys()
+            .map(|phrase| normalized_phrase(phrase))
+            .filter(|phrase| !phrase.is_empty())
+            .collect();
+        phrases.sort();
+        let mut duplicates: Vec<String> = phrases.windows(2)
+            .filter(|pair| pair[0] == pair[1])
+            .map(|pair| pair[0].clone())
+            .collect();
+        duplicates.dedup();
+        errors.extend(duplicates.into_iter().map(ConfigError::DuplicateBannedWord));
+        #[cfg(feature = "std")]
+        if let Some(pattern) = self.atom_pattern.as_deref() {
+            match Regex::new(pattern) {
+                Err(e) => errors.push(ConfigError::InvalidAtomPattern(e.to_string())),
+                Ok(regex) if regex.captures_len() < 3 => errors.push(ConfigError::InvalidAtomPattern(format!(
+                    "needs two capture groups, has {}",
+                    regex.captures_len() - 1,
+                ))),
+                Ok(regex) => {
+                    // Each of the locale's marks must end the atom before it
+                    let ignored = self.locale.iter()
+                        .flat_map(|locale| locale.punctuation_chars())
+                        .find(|mark| {
+                            let probe = format!("a{}", mark);
+                            !regex.captures(&probe)
+                                .and_then(|cap| cap.get(2))
+                                .is_some_and(|punct| punct.as_str().contains(*mark))
+                        });
+                    errors.extend(ignored.map(ConfigError::PatternIgnoresLocale));
+                }
+            }
+        }
+        
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Reset every field named by `errors` to its default
+    pub(super) fn repaired(mut self, errors: &[ConfigError]) -> Self {
+        let defaults = Self::default();
+        for error in errors {
+            match error {
+                ConfigError::NonPositiveRate { field: "chars_per_second", .. } => {
+                    self.chars_per_second = defaults.chars_per_second;
+                }
+                ConfigError::NonPositiveRate { field: "syllables_per_second", .. } => {
+                    self.syllables_per_second = defaults.syllables_per_second;
+                }
+                ConfigError::NonPositiveRate { field: "repeated_punctuation_multiplier", .. } => {
+                    self.repeated_punctuation_multiplier = defaults.repeated_punctuation_multiplier;
+                }
+                ConfigError::NonPositiveRate { .. } => {
+                    self.duration_model = defaults.duration_model;
+                }
+                ConfigError::NonPositiveMaxBreak(_)
+                | ConfigError::NegativeMinBreak(_)
+                | ConfigError::MinBreakExceedsMax { .. }
+                | ConfigError::MaxBreakBelowQuantum { .. } => {
+                    self.max_break_seconds = defaults.max_break_seconds;
+                    self.min_break_seconds = defaults.min_break_seconds;
+                }
+                ConfigError::InvalidFixedPause { field: "intro_pause_seconds", .. } => {
+                    self.intro_pause_seconds = defaults.intro_pause_seconds;
+                }
+                ConfigError::InvalidFixedPause { field: "pause_floors", .. } => {
+                    self.pause_floors.retain(|_, seconds| seconds.is_finite() && *seconds >= 0.0);
+                }
+                ConfigError::InvalidFixedPause { .. } => {
+                    self.outro_pause_seconds = defaults.outro_pause_seconds;
+                }
+                ConfigError::SafetyBufferBelowOne(_) => {
+                    self.silence_safety_buffer = defaults.silence_safety_buffer;
+                }
+                ConfigError::AllWeightsZero => {
+                    self.weights = defaults.weights.clone();
+                }
+                ConfigError::InvalidBreakBudget => {
+                    self.break_budget = defaults.break_budget;
+                }
+                ConfigError::InvalidTargetLimits { .. } => {
+                    self.target_limits = defaults.target_limits;
+                }
+                ConfigError::DuplicateBannedWord(phrase) => {
+                    // Keep the first spelling in sorted order
+                    let mut keys: Vec<String> = self.banned_words.keys()
+                        .filter(|key| normalized_phrase(key) == *phrase)
+                        .cloned()
+                        .collect();
+                    keys.sort();
+                    for key in keys.iter().skip(1) {
+                        self.banned_words.remove(key);
+                    }
+                }
+                ConfigError::InvalidAtomPattern(_) | ConfigError::PatternIgnoresLocale(_) => {
+                    self.atom_pattern = defaults.atom_pattern.clone();
+                }
+            }
+        }
+        self
+    }
+}
+
+impl Default for PacingConfig {
+    fn default() -> Self {
+        Self {
+            chars_per_second: CHARS_PER_SECOND,
+            language: None,
+            locale: None,
+            syllables_per_second: SYLLABLES_PER_SECOND,
+            duration_model: DurationModel::Characters,
+            silence_safety_buffer: SILENCE_SAFETY_BUFFER,
+            max_break_seconds: MAX_BREAK_SECONDS,
+            min_break_seconds: MIN_BREAK_SECONDS,
+            break_format: BreakFormat::default(),
+            break_budget: BreakBudget::default(),
+            redistribute_dropped_silence: true,
+            intro_pause_seconds: 0.0,
+            outro_pause_seconds: 0.0,
+            weights: Self::default_weights(),
+            keep_quotes_intact: true,
+            soften_quote_pauses: false,
+            repeated_punctuation_multiplier: 1.0,
+            pause_floors: HashMap::new(),
+            atom_pattern: None,
+            markers: HashMap::new(),
+            allocation: AllocationStrategy::Weighted,
+            progression: None,
+            jitter: None,
+            prosody_ramp: None,
+            silence_ratio_limits: SilenceRatioLimits::default(),
+            target_limits: TargetDurationLimits::default(),
+            keyword_bonuses: HashMap::new(),
+            banned_words: HashMap::new(),
+            strip_html: false,
+            symbols: SymbolHandling::default(),
+            spell_out_numbers: false,
+        }
+    }
+}
+
+/// What to do with a banned word or phrase (see `PacingConfig::banned_words`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum BannedWordAction {
+    /// Replace every occurrence with this text
+    Replace {
+        /// Replacement text (e.g. "discomfort" for "pain")
+        with: String,
+    },
+    /// Reject the script (`try_calculate_pacing`); the infallible API
+    /// drops the phrase instead
+    Reject,
+}
@@ -0,0 +1,759 @@
+//! Rendering
+//! 
+//! Step D of the pipeline: pauses written out as break tags (units,
+//! precision, drift compensation, provider budgets, intro and outro), and
+//! the `PacingResult` built around the SSML with its timeline, word
+//! timings, warnings, and break statistics.
+
+use super::*;
+
+// ============================================
+// Types
+// ============================================
+
+/// A condition worth surfacing to the caller about a pacing result
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum PacingWarning {
+    /// Silence makes up too large or too small a share of the session
+    SilenceRatioOutOfRange {
+        /// Actual silence ratio
+        ratio: f64,
+        /// Configured minimum
+        min_ratio: f64,
+        /// Configured maximum
+        max_ratio: f64,
+        /// Target duration that would bring this text into range
+        suggested_target_seconds: f64,
+        /// Speech length that would bring this target into range
+        suggested_speech_seconds: f64,
+        /// Word count that would bring this target into range (at the
+        /// script's own chars-per-word)
+        suggested_word_count: usize,
+    },
+    /// The text takes longer to speak than the whole target duration
+    SpeechExceedsTarget {
+        /// Estimated (or measured) speech time
+        speech_seconds: f64,
+        /// Requested session length
+        target_seconds: f64,
+    },
+    /// A pause was shorter than the minimum break and rendered as nothing
+    BreakDropped {
+        /// Index of the atom the pause follows
+        atom_index: usize,
+        /// Pause that was dropped
+        pause_seconds: f64,
+        /// Configured minimum break
+        min_break_seconds: f64,
+    },
+    /// A pause exceeded the provider's longest break and became several tags
+    BreakSplit {
+        /// Index of the atom the pause follows
+        atom_index: usize,
+        /// Total pause length
+        pause_seconds: f64,
+        /// Number of break tags it was rendered as
+        tag_count: usize,
+    },
+    /// The target duration is outside `PacingConfig::target_limits`
+    TargetOutOfRange {
+        /// Requested session length
+        target_seconds: f64,
+        /// Configured shortest target
+        min_seconds: f64,
+        /// Configured longest target
+        max_seconds: f64,
+        /// Target the session was actually paced to (the nearest limit
+        /// when clamping, else the request)
+        paced_seconds: f64,
+    },
+    /// Short pauses were merged into longer ones to stay within
+    /// `PacingConfig::break_budget`'s tag cap
+    BreaksMerged {
+        /// Pauses that were removed
+        merged_count: usize,
+        /// Break tags left
+        tag_count: usize,
+    },
+    /// The breaks exceed `PacingConfig::break_budget` even after merging;
+    /// the script needs chunked output
+    BreakBudgetExceeded {
+        /// Total break time in the SSML
+        break_seconds: f64,
+        /// Break tags in the SSML
+        tag_count: usize,
+        /// Requests the script should be split into
+        suggested_chunks: usize,
+    },
+    /// A sequence scene takes longer to speak than its dwell time, so the
+    /// scenes after it start late
+    SceneOverrun {
+        /// Name of the scene
+        scene: String,
+        /// Estimated speech time of the scene
+        speech_seconds: f64,
+        /// Dwell time the scene was given
+        dwell_seconds: f64,
+    },
+}
+
+/// Result of the pacing calculation
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct PacingResult {
+    /// The final SSML string
+    pub ssml: String,
+    /// Total character count (excluding whitespace)
+    pub total_chars: usize,
+    /// Total character count as spoken, with numbers, times and acronyms
+    /// expanded (the unit of speech estimation)
+    pub spoken_chars: usize,
+    /// Total word count
+    pub total_words: usize,
+    /// Estimated speech duration in seconds (based on char count)
+    pub estimated_speech_seconds: f64,
+    /// Raw silence budget before safety buffer
+    pub raw_silence_budget: f64,
+    /// Final silence budget after safety buffer (1.1x)
+    pub final_silence_budget: f64,
+    /// Total silence actually added in seconds
+    pub total_silence_added: f64,
+    /// Silence planned but not rendered because it fell below the minimum
+    /// break length
+    pub dropped_silence_seconds: f64,
+    /// Silence before the first atom (included in `total_silence_added`;
+    /// the timeline starts after it)
+    pub intro_pause_seconds: f64,
+    /// Target duration that was requested
+    pub target_duration_seconds: f64,
+    /// Actual estimated total duration
+    pub estimated_total_seconds: f64,
+    /// Number of speech atoms
+    pub atom_count: usize,
+    /// Duration model used for `estimated_speech_seconds`
+    pub duration_model: DurationModel,
+    /// Speech estimates under every model, for comparison
+    pub speech_estimates: SpeechEstimates,
+    /// Estimated position of every atom, in order
+    pub timeline: Vec<TimelineEntry>,
+    /// Estimated start time of every spoken word, in order
+    pub word_timings: Vec<WordTiming>,
+    /// Per-section timing (empty unless paced with sections)
+    pub sections: Vec<SectionTiming>,
+    /// Non-speech cues such as bells (empty unless scheduled)
+    pub cues: Vec<Cue>,
+    /// Banned words replaced or dropped before pacing
+    pub replacements: Vec<WordReplacement>,
+    /// Distribution of rendered break lengths
+    pub break_stats: BreakStats,
+    /// Conditions the caller may want to surface (see `PacingWarning`)
+    pub warnings: Vec<PacingWarning>,
+}
+
+/// Estimated playback position of one atom and the pause after it
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct TimelineEntry {
+    /// Index of the atom in the paced text
+    pub atom_index: usize,
+    /// Spoken text of the atom
+    pub text: String,
+    /// Punctuation characters that end the atom, as written
+    pub punctuation: String,
+    /// Punctuation weight of the atom (including keyword bonuses)
+    pub weight: u32,
+    /// Estimated offset where the atom starts, in seconds
+    pub start_seconds: f64,
+    /// Estimated time spent speaking the atom
+    pub speech_seconds: f64,
+    /// Rendered silence following the atom
+    pub pause_seconds: f64,
+}
+
+impl TimelineEntry {
+    /// Offset where the atom's speech ends (before its pause)
+    pub fn speech_end_seconds(&self) -> f64 {
+        self.start_seconds + self.speech_seconds
+    }
+    
+    /// Offset where the following pause ends
+    pub fn end_seconds(&self) -> f64 {
+        self.speech_end_seconds() + self.pause_seconds
+    }
+}
+
+/// Estimated playback position of a single word
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct WordTiming {
+    /// The word as written (including any attached symbols)
+    pub word: String,
+    /// Index of the atom the word belongs to
+    pub atom_index: usize,
+    /// Estimated offset where the word starts, in seconds
+    pub start_seconds: f64,
+    /// Estimated time spent speaking the word
+    pub duration_seconds: f64,
+}
+
+/// Timing report for one named section of a sectioned script
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SectionTiming {
+    /// Section name (e.g. "intro")
+    pub name: String,
+    /// Estimated offset where the section starts, in seconds
+    pub start_seconds: f64,
+    /// Estimated speech duration within the section
+    pub speech_seconds: f64,
+    /// Silence rendered in the section (including the pause after it, and
+    /// the intro pause for the first section)
+    pub silence_seconds: f64,
+    /// Number of atoms in the section
+    pub atom_count: usize,
+}
+
+/// A non-speech sound placed in a pause (e.g. an interval bell)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct Cue {
+    /// Cue name (e.g. "bell")
+    pub name: String,
+    /// Index of the atom whose pause holds the cue
+    pub after_atom: usize,
+    /// Estimated offset where the cue starts, in seconds
+    pub start_seconds: f64,
+    /// How long the cue sounds
+    pub duration_seconds: f64,
+    /// Audio played for the cue, if it is rendered into the SSML
+    pub audio_src: Option<String>,
+}
+
+/// Upper edges (seconds) of the `BreakStats::histogram` buckets; the last
+/// bucket holds everything from the final edge up
+pub const BREAK_HISTOGRAM_EDGES: [f64; 5] = [0.5, 1.0, 2.0, 5.0, 10.0];
+
+/// Summary of the rendered break lengths in a result, for monitoring
+/// pacing quality over time
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct BreakStats {
+    /// Number of rendered pauses
+    pub count: usize,
+    /// Shortest rendered pause (0 when there are none)
+    pub min_seconds: f64,
+    /// Longest rendered pause
+    pub max_seconds: f64,
+    /// Mean rendered pause
+    pub mean_seconds: f64,
+    /// Median rendered pause
+    pub p50_seconds: f64,
+    /// 90th-percentile rendered pause
+    pub p90_seconds: f64,
+    /// Pauses dropped for falling below the minimum break
+    pub dropped_count: usize,
+    /// Pauses split into several break tags
+    pub split_count: usize,
+    /// Rendered pauses per bucket of `BREAK_HISTOGRAM_EDGES`
+    pub histogram: Vec<usize>,
+}
+
+impl BreakStats {
+    /// Summarize rendered pauses, counting drops and splits from `warnings`
+    pub fn from_pauses(pauses: &[f64], warnings: &[PacingWarning]) -> Self {
+        let mut sorted: Vec<f64> = pauses.iter().copied().filter(|p| *p > 0.0).collect();
+        sorted.sort_by(f64::total_cmp);
+        
+        let mut histogram = vec![0; BREAK_HISTOGRAM_EDGES.len() + 1];
+        for pause in &sorted {
+            histogram[BREAK_HISTOGRAM_EDGES.iter().take_while(|edge| *pause >= **edge).count()] += 1;
+        }
+        // Nearest-rank percentile
+        let percentile = |p: f64| {
+            let rank = ((p * sorted.len() as f64).ceil() as usize).clamp(1, sorted.len().max(1));
+            sorted.get(rank - 1).copied().unwrap_or(0.0)
+        };
+        
+        Self {
+            count: sorted.len(),
+            min_seconds: sorted.first().copied().unwrap_or(0.0),
+            max_seconds: sorted.last().copied().unwrap_or(0.0),
+            mean_seconds: if sorted.is_empty() { 0.0 } else { sorted.iter().sum::<f64>() / sorted.len() as f64 },
+            p50_seconds: percentile(0.5),
+            p90_seconds: percentile(0.9),
+            dropped_count: warnings.iter().filter(|w| matches!(w, PacingWarning::BreakDropped { .. })).count(),
+            split_count: warnings.iter().filter(|w| matches!(w, PacingWarning::BreakSplit { .. })).count(),
+            histogram,
+        }
+    }
+}
+
+impl Cue {
+    /// Offset where the cue ends
+    pub fn end_seconds(&self) -> f64 {
+        self.start_seconds + self.duration_seconds
+    }
+}
+
+impl PacingResult {
+    /// Characters a TTS provider bills for this session
+    /// 
+    /// ElevenLabs counts every character of the submitted SSML, tags
+    /// included, so long sessions with many split breaks cost noticeably
+    /// more than their spoken text alone. On standard models one
+    /// character is one credit.
+    pub fn billing_characters(&self) -> usize {
+        self.ssml.chars().count()
+    }
+
+    /// One-line summary for logs (same as `Display`)
+    pub fn summary(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for PacingResult {
+    /// e.g. `12 atoms, 58 words: 24.3s speech + 37.2s silence = 61.5s (target 60.0s, +1.5s), 1 warning`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} atoms, {} words: {:.1}s speech + {:.1}s silence = {:.1}s (target {:.1}s, {:+.1}s)",
+            self.atom_count,
+            self.total_words,
+            self.estimated_speech_seconds,
+            self.total_silence_added,
+            self.estimated_total_seconds,
+            self.target_duration_seconds,
+            self.estimated_total_seconds - self.target_duration_seconds,
+        )?;
+        match self.warnings.len() {
+            0 => Ok(()),
+            1 => write!(f, ", 1 warning"),
+            n => write!(f, ", {} warnings", n),
+        }
+    }
+}
+
+impl SectionTiming {
+    /// Estimated total duration of the section
+    pub fn duration_seconds(&self) -> f64 {
+        self.speech_seconds + self.silence_seconds
+    }
+}
+
+// ============================================
+// Rendering
+// ============================================
+
+impl MeditationPacer {
+    /// Render atoms with their pauses and fill in a `PacingResult`
+    /// 
+    /// Shared by every pacing path (plain text, sections, generators) so
+    /// the result is always populated the same way.
+    /// 
+    /// `pauses` and `speech` are parallel to `atoms` (seconds of silence
+    /// after, and seconds of speech for, each atom).
+    pub(crate) fn assemble_result(
+        &self,
+        atoms: &[SpeechAtom],
+        pauses: &[f64],
+        speech: &[f64],
+        target_duration_seconds: f64,
+        raw_silence_budget: f64,
+        final_silence_budget: f64,
+    ) -> PacingResult {
+        let (pauses, budget_warnings) = self.fit_break_budget(pauses);
+        let pauses = &pauses;
+        let (ssml, total_silence_added, dropped_silence_seconds) = self.render_ssml(atoms, pauses);
+        let estimated_speech_seconds: f64 = speech.iter().sum();
+        let rendered = self.rendered_pauses(pauses);
+        
+        // Timeline: atoms play back to back with their pauses, and words
+        // share their atom's speech time by character count
+        let mut timeline = Vec::with_capacity(atoms.len());
+        let mut word_timings = Vec::new();
+        let intro_pause_seconds = if atoms.is_empty() { 0.0 } else { self.intro_pause() };
+        let mut offset = intro_pause_seconds;
+        for (i, atom) in atoms.iter().enumerate() {
+            timeline.push(TimelineEntry {
+                atom_index: i,
+                text: atom.text.clone(),
+                punctuation: atom.punctuation_char.clone(),
+                weight: atom.effective_weight(),
+                start_seconds: offset,
+                speech_seconds: speech[i],
+                pause_seconds: rendered[i],
+            });
+            
+            let atom_chars = atom.char_count().max(1) as f64;
+            let mut chars_before = 0;
+            for (word, word_chars) in atom.text.split_whitespace().zip(word_char_counts(&atom.text)) {
+                word_timings.push(WordTiming {
+                    word: word.to_string(),
+                    atom_index: i,
+                    start_seconds: offset + speech[i] * chars_before as f64 / atom_chars,
+                    duration_seconds: speech[i] * word_chars as f64 / atom_chars,
+                });
+                chars_before += word_chars;
+            }
+            offset += speech[i] + rendered[i];
+        }
+        let mut warnings = self.render_warnings(pauses, estimated_speech_seconds, target_duration_seconds);
+        warnings.extend(budget_warnings);
+        
+        PacingResult {
+            ssml,
+            total_chars: atoms.iter().map(|a| a.written_char_count()).sum(),
+            spoken_chars: atoms.iter().map(|a| a.char_count()).sum(),
+            total_words: atoms.iter().map(|a| a.word_count).sum(),
+            estimated_speech_seconds,
+            raw_silence_budget,
+            final_silence_budget,
+            total_silence_added,
+            dropped_silence_seconds,
+            intro_pause_seconds,
+            target_duration_seconds,
+            estimated_total_seconds: estimated_speech_seconds + total_silence_added,
+            atom_count: atoms.len(),
+            duration_model: self.config.duration_model,
+            speech_estimates: self.speech_estimates(atoms),
+            timeline,
+            word_timings,
+            sections: Vec::new(),
+            cues: Vec::new(),
+            replacements: Vec::new(),
+            break_stats: BreakStats::from_pauses(&rendered, &warnings),
+            warnings,
+        }
+    }
+
+    /// Warnings about how a pause plan renders (before any ratio check)
+    fn render_warnings(
+        &self,
+        pauses: &[f64],
+        speech_seconds: f64,
+        target_seconds: f64,
+    ) -> Vec<PacingWarning> {
+        let mut warnings = Vec::new();
+        if speech_seconds > target_seconds {
+            warnings.push(PacingWarning::SpeechExceedsTarget {
+                speech_seconds,
+                target_seconds,
+            });
+        }
+        
+        let last = pauses.len().saturating_sub(1);
+        for (i, pause) in pauses.iter().copied().enumerate().take(last) {
+            if pause <= 0.0 {
+                continue;
+            }
+            if pause < self.config.min_break_seconds {
+                warnings.push(PacingWarning::BreakDropped {
+                    atom_index: i,
+                    pause_seconds: pause,
+                    min_break_seconds: self.config.min_break_seconds,
+                });
+                continue;
+            }
+            let tag_count = self.break_chunks(pause).len();
+            if tag_count > 1 {
+                warnings.push(PacingWarning::BreakSplit {
+                    atom_index: i,
+                    pause_seconds: pause,
+                    tag_count,
+                });
+            }
+        }
+        warnings
+    }
+
+    /// Check the silence ratio of a session against the configured limits
+    /// 
+    /// Returns a warning with suggestions (more/less text, or a different
+    /// target) when the ratio is out of range.
+    pub fn check_silence_ratio(
+        &self,
+        total_words: usize,
+        estimated_speech_seconds: f64,
+        target_duration_seconds: f64,
+    ) -> Option<PacingWarning> {
+        if target_duration_seconds <= 0.0 {
+            return None;
+        }
+        let limits = &self.config.silence_ratio_limits;
+        let silence = (target_duration_seconds - estimated_speech_seconds).max(0.0);
+        let ratio = silence / target_duration_seconds;
+        
+        let bound = if ratio > limits.max_ratio {
+            limits.max_ratio
+        } else if ratio < limits.min_ratio {
+            limits.min_ratio
+        } else {
+            return None;
+        };
+        
+        let suggested_speech_seconds = target_duration_seconds * (1.0 - bound);
+        let words_per_second = if estimated_speech_seconds > 0.0 {
+            total_words as f64 / estimated_speech_seconds
+        } else {
+            TARGET_WORDS_PER_MINUTE / 60.0
+        };
+        
+        Some(PacingWarning::SilenceRatioOutOfRange {
+            ratio,
+            min_ratio: limits.min_ratio,
+            max_ratio: limits.max_ratio,
+            suggested_target_seconds: estimated_speech_seconds / (1.0 - bound).max(f64::EPSILON),
+            suggested_speech_seconds,
+            suggested_word_count: (suggested_speech_seconds * words_per_second).round() as usize,
+        })
+    }
+
+    /// The pause that actually follows each atom once rendered
+    /// 
+    /// Zero for pauses below the minimum break length. The last atom is
+    /// followed by the outro pause, if any.
+    pub(crate) fn rendered_pauses(&self, pauses: &[f64]) -> Vec<f64> {
+        let last = pauses.len().saturating_sub(1);
+        pauses.iter()
+            .enumerate()
+            .map(|(i, p)| {
+                if i == last {
+                    self.outro_pause()
+                } else if *p >= self.config.min_break_seconds {
+                    *p
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    /// The configured intro pause, or zero if it is below the minimum break
+    pub(crate) fn intro_pause(&self) -> f64 {
+        let intro = self.config.intro_pause_seconds;
+        if intro >= self.config.min_break_seconds { intro } else { 0.0 }
+    }
+
+    /// The configured outro pause, or zero if it is below the minimum break
+    pub(crate) fn outro_pause(&self) -> f64 {
+        let outro = self.config.outro_pause_seconds;
+        if outro >= self.config.min_break_seconds { outro } else { 0.0 }
+    }
+
+    /// Fixed silence around the script (intro plus outro), taken out of
+    /// the target before the rest is distributed
+    pub(crate) fn framing_pauses(&self) -> f64 {
+        self.intro_pause() + self.outro_pause()
+    }
+
+    /// Render atoms and their following pauses into SSML
+    /// 
+    /// Returns the SSML, the total silence actually emitted, and the silence
+    /// dropped for falling below the minimum break length.
+    pub(crate) fn render_ssml(&self, atoms: &[SpeechAtom], pauses: &[f64]) -> (String, f64, f64) {
+        let ssml = self.render_fragments(atoms, pauses).join(" ");
+        let intro = if atoms.is_empty() { 0.0 } else { self.intro_pause() };
+        let total_silence_added = intro + self.rendered_pauses(pauses).iter().sum::<f64>();
+        let dropped_silence: f64 = pauses.iter()
+            .take(pauses.len().saturating_sub(1))
+            .filter(|p| **p > 0.0 && **p < self.config.min_break_seconds)
+            .sum();
+        
+        (ssml, total_silence_added, dropped_silence)
+    }
+
+    /// Render each atom, with the breaks that follow it, as its own fragment
+    /// 
+    /// Joining the fragments with single spaces gives the full SSML; chunked
+    /// output splits between fragments.
+    pub(crate) fn render_fragments(&self, atoms: &[SpeechAtom], pauses: &[f64]) -> Vec<String> {
+        let progress = atom_start_progress(atoms);
+        let break_tags = self.break_tag_durations(pauses);
+        
+        atoms.iter().enumerate().map(|(i, atom)| {
+            let mut fragment = String::with_capacity((atom.text.len() + atom.punctuation_char.len()) * 2);
+            
+            // The intro pause opens the first fragment
+            if i == 0 {
+                for duration in self.intro_break_tags() {
+                    fragment.push_str(&self.format_break_tag(duration));
+                }
+            }
+            
+            // Add the text, slowed down if the prosody ramp calls for it
+            let rate_percent = self.config.prosody_ramp
+                .map_or(100, |ramp| (ramp.rate_at(progress[i]) * 100.0).round() as u32);
+            if rate_percent != 100 {
+                fragment.push_str(&format!("<prosody rate=\"{}%\">", rate_percent));
+            }
+            fragment.push_str(&atom.text);
+            fragment.push_str(&atom.punctuation_char);
+            if rate_percent != 100 {
+                fragment.push_str("</prosody>");
+            }
+            
+            // No break after the very last atom or below the minimum
+            // threshold (`break_tag_durations` leaves those empty)
+            for duration in &break_tags[i] {
+                fragment.push_str(&self.format_break_tag(*duration));
+            }
+            fragment
+        }).collect()
+    }
+
+    /// Fit pauses to the provider's break budget
+    /// 
+    /// Over the tag cap, the shortest rendered pause is dropped and its
+    /// silence shared among the others in proportion to their length,
+    /// repeatedly, while that lowers the tag count. Still over either cap
+    /// afterwards, the pauses are kept and the overrun reported.
+    pub(crate) fn fit_break_budget(&self, pauses: &[f64]) -> (Vec<f64>, Vec<PacingWarning>) {
+        let budget = self.config.break_budget;
+        let mut pauses = pauses.to_vec();
+        let mut warnings = Vec::new();
+        if budget == BreakBudget::default() {
+            return (pauses, warnings);
+        }
+        
+        let intro_tags = self.intro_break_tags();
+        let tag_count = |pauses: &[f64]| {
+            intro_tags.len() + self.break_tag_durations(pauses).iter().map(Vec::len).sum::<usize>()
+        };
+        let mut tags = tag_count(&pauses);
+        let mut merged_count = 0;
+        let last = pauses.len().saturating_sub(1);
+        while budget.max_tags.is_some_and(|max| tags > max) {
+            let rendered: Vec<usize> = (0..last).filter(|i| pauses[*i] >= self.config.min_break_seconds).collect();
+            let Some(&shortest) = rendered.iter().min_by(|a, b| pauses[**a].total_cmp(&pauses[**b])) else {
+                break;
+            };
+            let rest: f64 = rendered.iter().filter(|i| **i != shortest).map(|i| pauses[*i]).sum();
+            if rest <= 0.0 {
+                break;
+            }
+            let mut candidate = pauses.clone();
+            let freed = core::mem::take(&mut candidate[shortest]);
+            for i in rendered.iter().filter(|i| **i != shortest) {
+                candidate[*i] += freed * pauses[*i] / rest;
+            }
+            let candidate_tags = tag_count(&candidate);
+            if candidate_tags >= tags {
+                break;
+            }
+            pauses = candidate;
+            tags = candidate_tags;
+            merged_count += 1;
+        }
+        if merged_count > 0 {
+            warnings.push(PacingWarning::BreaksMerged { merged_count, tag_count: tags });
+        }
+        
+        let break_seconds: f64 = intro_tags.iter().chain(self.break_tag_durations(&pauses).iter().flatten()).sum();
+        if !budget.allows(tags, break_seconds) {
+            warnings.push(PacingWarning::BreakBudgetExceeded {
+                break_seconds,
+                tag_count: tags,
+                suggested_chunks: budget.requests_needed(tags, break_seconds),
+            });
+        }
+        (pauses, warnings)
+    }
+
+    /// Durations of the break tags after each atom, with rounding drift
+    /// compensated
+    /// 
+    /// Tags are rounded to the break format on the running total of
+    /// silence rather than one at a time, so the error a rounded tag (or a
+    /// split's tail below the minimum break) introduces is made up on the
+    /// following tags. Over a whole script the tags add up to the rendered
+    /// pauses within one formatting quantum, however many there are. The
+    /// running total starts after the intro tags, and the last atom gets
+    /// tags only for the outro pause.
+    pub(crate) fn break_tag_durations(&self, pauses: &[f64]) -> Vec<Vec<f64>> {
+        let last = pauses.len().saturating_sub(1);
+        let mut planned = self.intro_pause();
+        let mut emitted: f64 = self.intro_break_tags().iter().sum();
+        
+        pauses.iter().enumerate().map(|(i, &pause)| {
+            let pause = if i == last { self.outro_pause() } else { pause };
+            self.carried_break_tags(pause, &mut planned, &mut emitted)
+        }).collect()
+    }
+
+    /// Durations of the break tags that open the script (empty without an
+    /// intro pause)
+    pub(crate) fn intro_break_tags(&self) -> Vec<f64> {
+        self.carried_break_tags(self.intro_pause(), &mut 0.0, &mut 0.0)
+    }
+
+    /// Split one pause into tags, rounding on the running totals of
+    /// `planned` and `emitted` silence
+    fn carried_break_tags(&self, pause: f64, planned: &mut f64, emitted: &mut f64) -> Vec<f64> {
+        let format = self.config.break_format;
+        let min_break = self.config.min_break_seconds;
+        let mut tags = Vec::new();
+        if pause <= 0.0 || pause < min_break {
+            return tags;
+        }
+        *planned += pause;
+        let mut remaining = format.round(*planned) - *emitted;
+        // (the margin keeps float noise in the running totals from
+        // turning an exact minimum-length tail into a tag)
+        while remaining > min_break + 1e-9 {
+            let duration = format.round(remaining.min(self.config.max_break_seconds));
+            if duration <= 0.0 {
+                break;
+            }
+            tags.push(duration);
+            remaining -= duration;
+            *emitted += duration;
+        }
+        tags
+    }
+
+    /// Render atoms and the pause after each into SSML
+    /// 
+    /// `pauses` must have one entry per atom, as `distribute_pauses`
+    /// returns.
+    pub fn render_atoms(&self, atoms: &[SpeechAtom], pauses: &[f64]) -> String {
+        self.render_ssml(atoms, pauses).0
+    }
+
+    /// Format a break duration into an SSML break tag
+    /// 
+    /// Since ElevenLabs has a max of 3 seconds per break, longer pauses
+    /// are split into several tags by `break_tag_durations`.
+    pub(super) fn format_break_tag(&self, seconds: f64) -> String {
+        format!("<break time=\"{}\"/>", self.config.break_format.format(seconds))
+    }
+
+    /// Split a pause into per-tag durations (each at most `max_break_seconds`)
+    fn break_chunks(&self, total_seconds: f64) -> Vec<f64> {
+        let mut chunks = Vec::new();
+        let mut remaining = total_seconds;
+        
+        while remaining > self.config.min_break_seconds {
+            let break_duration = remaining.min(self.config.max_break_seconds);
+            chunks.push(break_duration);
+            remaining -= break_duration;
+        }
+        
+        chunks
+    }
+}
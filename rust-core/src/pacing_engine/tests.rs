@@ -0,0 +1,37 @@
+//! Pacing Engine Tests
+//! 
+//! One file per feature area; the break-tag helpers are shared.
+
+use super::*;
+
+mod basics;
+mod atomization;
+mod punctuation;
+mod locale;
+mod duration_models;
+mod distribution;
+mod overrides;
+mod targets;
+mod break_tags;
+mod framing;
+mod results;
+mod api;
+
+/// Break tags for a single pause
+fn format_break_tags(pacer: &MeditationPacer, seconds: f64) -> String {
+    pacer.break_tag_durations(&[seconds, 0.0])[0]
+        .iter()
+        .map(|d| pacer.format_break_tag(*d))
+        .collect()
+}
+
+/// Durations of the break tags in some SSML, in seconds
+fn break_tag_seconds(ssml: &str) -> Vec<f64> {
+    ssml.split("<break time=\"").skip(1).map(|tag| {
+        let value = &tag[..tag.find('"').unwrap()];
+        match value.strip_suffix("ms") {
+            Some(ms) => ms.parse::<f64>().unwrap() / 1000.0,
+            None => value.trim_end_matches('s').parse::<f64>().unwrap(),
+        }
+    }).collect()
+}
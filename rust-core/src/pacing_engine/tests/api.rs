@@ -0,0 +1,155 @@
+//! API Surface Tests
+//! 
+//! Batches, fallible entry points, config validation, presets, mutable
+//! config, serde, and the JSON entry points.
+
+use super::*;
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    let mut config = PacingConfig::sleep_story();
+    config.set_weight(PunctuationType::Comma, 2);
+    
+    let json = serde_json::to_string(&config).unwrap();
+    let restored: PacingConfig = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.weight_for(PunctuationType::Comma), 2);
+    assert_eq!(restored.prosody_ramp, config.prosody_ramp);
+    
+    // Missing fields fall back to defaults
+    let partial: PacingConfig = serde_json::from_str(r#"{"chars_per_second": 10.0}"#).unwrap();
+    assert_eq!(partial.chars_per_second, 10.0);
+    assert_eq!(partial.max_break_seconds, MAX_BREAK_SECONDS);
+    
+    let result = MeditationPacer::with_config(config)
+        .calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+    let json = serde_json::to_string(&result).unwrap();
+    let restored: PacingResult = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.ssml, result.ssml);
+    assert_eq!(restored.timeline, result.timeline);
+}
+
+#[test]
+fn test_calculate_batch() {
+    let pacer = MeditationPacer::new();
+    let scripts = vec![
+        ("Breathe in. Let go.".to_string(), 20.0),
+        ("Welcome, friend. Settle in... Rest.".to_string(), 45.0),
+    ];
+    let results = pacer.calculate_batch(&scripts);
+    
+    assert_eq!(results.len(), 2);
+    for (result, (text, target)) in results.iter().zip(&scripts) {
+        assert_eq!(result.ssml, pacer.calculate_pacing(text.clone(), *target).ssml);
+    }
+}
+
+#[test]
+fn test_try_calculate_pacing() {
+    let pacer = MeditationPacer::new();
+    let text = "Breathe in. Let go.".to_string();
+    
+    assert!(pacer.try_calculate_pacing(text.clone(), 20.0).is_ok());
+    assert_eq!(pacer.try_calculate_pacing(" ... ".to_string(), 20.0).unwrap_err(), PacingError::EmptyText);
+    assert_eq!(
+        pacer.try_calculate_pacing(text.clone(), f64::NAN).unwrap_err().to_string(),
+        "target duration NaN is not finite",
+    );
+    assert_eq!(pacer.try_calculate_pacing(text.clone(), -5.0).unwrap_err(), PacingError::NegativeDuration(-5.0));
+    assert!(matches!(
+        pacer.try_calculate_pacing(text.clone(), 0.5),
+        Err(PacingError::TextTooLongForTarget { .. })
+    ));
+    
+    let config = PacingConfig {
+        chars_per_second: 0.0,
+        ..PacingConfig::default()
+    };
+    assert!(matches!(
+        MeditationPacer::with_config(config).try_calculate_pacing(text, 20.0),
+        Err(PacingError::InvalidConfig(_))
+    ));
+}
+
+#[test]
+fn test_config_validation() {
+    assert!(PacingConfig::default().validate().is_ok());
+    
+    let mut weights = PacingConfig::default_weights();
+    weights.values_mut().for_each(|w| *w = 0);
+    let config = PacingConfig {
+        chars_per_second: 0.0,
+        min_break_seconds: 4.0,
+        silence_safety_buffer: 0.9,
+        weights,
+        ..PacingConfig::default()
+    };
+    let errors = config.validate().unwrap_err();
+    assert_eq!(errors, vec![
+        ConfigError::NonPositiveRate { field: "chars_per_second", value: 0.0 },
+        ConfigError::MinBreakExceedsMax { min_break_seconds: 4.0, max_break_seconds: MAX_BREAK_SECONDS },
+        ConfigError::SafetyBufferBelowOne(0.9),
+        ConfigError::AllWeightsZero,
+    ]);
+    assert!(MeditationPacer::try_with_config(config.clone()).is_err());
+    
+    // The infallible constructor repairs the config and still paces
+    let pacer = MeditationPacer::with_config(config);
+    assert_eq!(pacer.config_errors().len(), 4);
+    let result = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+    assert!(result.estimated_speech_seconds.is_finite());
+    assert!(result.ssml.contains("<break"));
+}
+
+#[test]
+fn test_presets() {
+    let presets = [Preset::Sleep, Preset::Focus, Preset::Breathwork, Preset::QuickReset];
+    for preset in presets {
+        assert!(PacingConfig::preset(preset).validate().is_ok(), "{:?}", preset);
+    }
+    
+    let sleep = PacingConfig::preset(Preset::Sleep);
+    assert!(sleep.prosody_ramp.is_some());
+    assert!(sleep.weight_for(PunctuationType::Paragraph) > WEIGHT_PARAGRAPH);
+    
+    let breathwork = PacingConfig::preset(Preset::Breathwork);
+    assert_eq!(breathwork.allocation, AllocationStrategy::EqualPerSentence);
+}
+
+#[test]
+fn test_mutable_config() {
+    let mut pacer = MeditationPacer::with_config(PacingConfig::for_language(Language::Japanese));
+    let text = "Breathe in. Let go.".to_string();
+    let before = pacer.calculate_pacing(text.clone(), 20.0).estimated_speech_seconds;
+    
+    pacer.set_chars_per_second(6.0);
+    assert_eq!(pacer.config().language, None);
+    assert_eq!(pacer.config().effective_chars_per_second(), 6.0);
+    assert!(pacer.calculate_pacing(text.clone(), 20.0).estimated_speech_seconds != before);
+    
+    // Invalid values are recorded and repaired, as with `with_config`
+    pacer.set_break_limits(2.0, 1.0);
+    assert_eq!(pacer.config_errors().len(), 1);
+    assert_eq!(pacer.config().max_break_seconds, MAX_BREAK_SECONDS);
+    assert_eq!(pacer.config().chars_per_second, 6.0);
+    
+    pacer.set_break_limits(0.2, 2.0);
+    assert!(pacer.config_errors().is_empty());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_json_entry_points() {
+    let text = "Breathe in. Let go.".to_string();
+    let json = calculate_pacing_json(text.clone(), 20.0);
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["ssml"], calculate_pacing_details(text.clone(), 20.0).ssml.as_str());
+    
+    let json = calculate_pacing_json_with_config(text.clone(), 20.0, r#"{"chars_per_second": 6.0}"#.to_string());
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert!((value["estimated_speech_seconds"].as_f64().unwrap() - 14.0 / 6.0).abs() < 1e-9);
+    
+    let json = calculate_pacing_json_with_config(text, 20.0, "not json".to_string());
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert!(value["error"].as_str().unwrap().starts_with("could not parse config"));
+}
@@ -0,0 +1,224 @@
+//! Atomization Tests
+//! 
+//! Custom patterns and markers, the no_std delimiter scanner, the
+//! streaming and borrowed atomizers, and the banned-word filter.
+
+use super::*;
+
+#[test]
+#[cfg(feature = "std")]
+fn test_default_pattern_matches_delimiters() {
+    assert_eq!(DEFAULT_ATOM_PATTERN, format!("((?:{1}|[^{0}])+)([{0}]*)", PUNCTUATION_CLASS, NUMBER_PATTERN));
+    assert_eq!(PUNCTUATION_CLASS.replace(r"\?", "?").replace(r"\n", "\n"), DELIMITERS.iter().collect::<String>());
+}
+
+#[test]
+fn test_custom_markers() {
+    let mut config = PacingConfig::default();
+    config.set_markers(&[("[[long]]", PunctuationType::Paragraph)]);
+    let pacer = MeditationPacer::with_config(config);
+    
+    let atoms = pacer.atomize("Breathe in[[long]]Breathe out. Rest.[[long]] Stay");
+    assert_eq!(atoms.len(), 4);
+    assert_eq!(atoms[0].text, "Breathe in");
+    assert_eq!(atoms[0].punctuation, PunctuationType::Paragraph);
+    assert_eq!(atoms[0].punctuation_char, "");
+    assert_eq!(atoms[1].punctuation, PunctuationType::SentenceEnd);
+    assert_eq!(atoms[2].punctuation, PunctuationType::Paragraph);
+    assert_eq!(atoms[2].punctuation_char, ".");
+    assert_eq!(atoms[3].text, "Stay");
+    
+    let ssml = pacer.format_meditation_ssml("Breathe in[[long]]Breathe out.".to_string(), 20.0);
+    assert!(!ssml.contains("[[long]]"));
+}
+
+#[test]
+fn test_equal_weight_markers_resolve_in_order() {
+    // Semicolons and dashes weigh the same by default; the first marker
+    // in the run wins however the marker map iterates
+    for _ in 0..20 {
+        let mut config = PacingConfig::default();
+        config.set_markers(&[("[a]", PunctuationType::Semicolon), ("[b]", PunctuationType::Dash), ("[bb]", PunctuationType::Comma)]);
+        let pacer = MeditationPacer::with_config(config);
+        let atoms = pacer.atomize("One[b][a]Two[a][b]Three[bb]");
+        assert_eq!(atoms[0].punctuation, PunctuationType::Dash);
+        assert_eq!(atoms[1].punctuation, PunctuationType::Semicolon);
+        assert_eq!(atoms[2].punctuation, PunctuationType::Comma);
+        assert!(atoms.iter().all(|atom| atom.punctuation_char.is_empty()));
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_custom_atom_pattern() {
+    let config = PacingConfig {
+        atom_pattern: Some(r"([^|]+)(\|*)".to_string()),
+        ..PacingConfig::default()
+    };
+    let pacer = MeditationPacer::with_config(config);
+    
+    // Commas no longer split; the pipe is unclassified so weighs nothing
+    let atoms = pacer.atomize("Hello, friend|Welcome");
+    assert_eq!(atoms.len(), 2);
+    assert_eq!(atoms[0].text, "Hello, friend");
+}
+
+#[test]
+fn test_scanner_matches_default_pattern() {
+    let scanner = MeditationPacer::new();
+    let regex = MeditationPacer::with_config(PacingConfig {
+        atom_pattern: Some(DEFAULT_ATOM_PATTERN.to_string()),
+        ..PacingConfig::default()
+    });
+    let mut scripts: Vec<String> = crate::corpus::bench_corpus().into_iter().map(|s| s.text).collect();
+    scripts.push("...Begin. Pause ?! — and rest…\n\nתנשום׃ ,;".to_string());
+    scripts.push("Feel 4.5 breaths, 1,500 steps, v1.2.3, .5 and 3. then 7,".to_string());
+    for script in &scripts {
+        assert_eq!(scanner.atomize_ref(script), regex.atomize_ref(script));
+    }
+
+    // Without `std` markers split through the scanner itself
+    let splitter = AtomSplitter::Delimiters { extra: Vec::new(), markers: vec!["[[bell]]".to_string()] };
+    let found = splitter.find_at("Ring[[bell]] Rest.", 0).unwrap();
+    assert_eq!((found.content, found.punct), (Some(0..4), Some(4..12)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_atom_pattern_recompiled_on_config_change() {
+    let mut pacer = MeditationPacer::new();
+    assert_eq!(pacer.atomize("Hello, friend|Welcome").len(), 2);
+    
+    pacer.set_config(PacingConfig {
+        atom_pattern: Some(r"([^|]+)(\|*)".to_string()),
+        ..PacingConfig::default()
+    });
+    assert_eq!(pacer.atomize("Hello, friend|Welcome")[0].text, "Hello, friend");
+    
+    // Invalid patterns fall back to the default
+    pacer.set_config(PacingConfig {
+        atom_pattern: Some("(".to_string()),
+        ..PacingConfig::default()
+    });
+    assert_eq!(pacer.atomize("Hello, friend|Welcome")[0].text, "Hello");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_invalid_atom_pattern_reported() {
+    for pattern in ["(", r"[^|]+\|*", r"([^|]+)\|*"] {
+        let config = PacingConfig {
+            atom_pattern: Some(pattern.to_string()),
+            ..PacingConfig::default()
+        };
+        let errors = config.validate().unwrap_err();
+        assert!(matches!(errors[..], [ConfigError::InvalidAtomPattern(_)]), "{}", pattern);
+        assert!(errors[0].to_string().starts_with("atom_pattern is invalid: "));
+        
+        assert_eq!(MeditationPacer::try_with_config(config.clone()).unwrap_err(), errors);
+        let pacer = MeditationPacer::with_config(config);
+        assert_eq!(
+            pacer.try_calculate_pacing("Hello, friend".to_string(), 20.0).unwrap_err(),
+            PacingError::InvalidConfig(errors)
+        );
+        // Pacing itself falls back to the default splitter
+        assert_eq!(pacer.atomize("Hello, friend|Welcome")[0].text, "Hello");
+    }
+}
+
+#[test]
+fn test_banned_words_replaced_and_rejected() {
+    let mut config = PacingConfig::default();
+    config.banned_words.insert("pain".to_string(), BannedWordAction::Replace { with: "discomfort".to_string() });
+    config.banned_words.insert("panic attack".to_string(), BannedWordAction::Reject);
+    let pacer = MeditationPacer::with_config(config);
+    
+    let result = pacer.calculate_pacing("Notice any Pain. Breathe in.".to_string(), 20.0);
+    assert!(result.ssml.starts_with("Notice any discomfort."));
+    assert_eq!(result.replacements, vec![WordReplacement {
+        phrase: "pain".to_string(),
+        original: "Pain".to_string(),
+        replacement: "discomfort".to_string(),
+    }]);
+    // Word boundaries: "painting" is left alone
+    assert!(pacer.calculate_pacing("A painting.".to_string(), 10.0).replacements.is_empty());
+    
+    let text = "If a panic  attack comes, breathe.".to_string();
+    assert_eq!(
+        pacer.try_calculate_pacing(text.clone(), 20.0).unwrap_err(),
+        PacingError::BannedWord { phrase: "panic attack".to_string(), original: "panic  attack".to_string() },
+    );
+    let dropped = pacer.calculate_pacing(text, 20.0);
+    assert!(dropped.ssml.starts_with("If a comes,"), "{}", dropped.ssml);
+    assert_eq!(dropped.replacements[0].replacement, "");
+}
+
+#[test]
+fn test_duplicate_banned_words_rejected() {
+    let mut config = PacingConfig::default();
+    config.banned_words.insert("let  go".to_string(), BannedWordAction::Reject);
+    config.banned_words.insert("Let go".to_string(), BannedWordAction::Replace { with: "release".to_string() });
+    config.banned_words.insert("pain".to_string(), BannedWordAction::Reject);
+    assert_eq!(config.validate(), Err(vec![ConfigError::DuplicateBannedWord("let go".to_string())]));
+    assert!(MeditationPacer::try_with_config(config.clone()).is_err());
+    
+    // Repaired, the first spelling in sorted order is kept every time
+    for _ in 0..20 {
+        let pacer = MeditationPacer::with_config(config.clone());
+        assert_eq!(pacer.config().banned_words.len(), 2);
+        let result = pacer.calculate_pacing("Now let go. Rest.".to_string(), 20.0);
+        assert!(result.ssml.starts_with("Now release."), "{}", result.ssml);
+    }
+}
+
+#[test]
+fn test_atomize_iter_matches_atomize() {
+    let pacer = MeditationPacer::new();
+    let text = "Welcome... Breathe in, slowly; hold it — and release.\n\nRest here.";
+    
+    let eager: Vec<String> = pacer.atomize(text).into_iter().map(|a| a.text).collect();
+    let lazy: Vec<String> = pacer.atomize_iter(text).map(|a| a.text).collect();
+    assert_eq!(lazy, eager);
+    
+    // Consuming part of the iterator only scans part of the text
+    let first = pacer.atomize_iter(text).next().unwrap();
+    assert_eq!(first.punctuation, PunctuationType::Ellipsis);
+
+    // Raw text is split as given; prepared text matches `atomize`
+    let mut config = PacingConfig { strip_html: true, ..PacingConfig::default() };
+    config.banned_words.insert("pain".to_string(), BannedWordAction::Replace { with: "tension".to_string() });
+    let pacer = MeditationPacer::with_config(config);
+    let html = "<p>Breathe in,\r\nrelease the pain.</p><p>Rest&nbsp;here.</p>";
+    let eager: Vec<String> = pacer.atomize(html).into_iter().map(|a| a.text).collect();
+    let prepared = pacer.prepare_text(html);
+    let lazy: Vec<String> = pacer.atomize_iter(&prepared).map(|a| a.text).collect();
+    assert_eq!(lazy, eager);
+    assert_eq!(eager[1], "release the tension");
+    assert!(pacer.atomize_iter(html).any(|a| a.text.contains("<p>")));
+    assert!(matches!(pacer.prepare_text("Rest here."), Cow::Borrowed(_)));
+}
+
+#[test]
+fn test_atomize_ref_borrows_input() {
+    let mut config = PacingConfig::default();
+    config.keyword_bonuses.insert("breathe".to_string(), 2);
+    let pacer = MeditationPacer::with_config(config);
+    let text = "Welcome... Breathe in, slowly; hold it — and release.\n\nRest here.";
+    
+    let borrowed = pacer.atomize_ref(text);
+    let owned = pacer.atomize(text);
+    assert_eq!(borrowed.len(), owned.len());
+    for (atom_ref, atom) in borrowed.iter().zip(&owned) {
+        let copy = atom_ref.to_atom();
+        assert_eq!(copy.text, atom.text);
+        assert_eq!(copy.punctuation_char, atom.punctuation_char);
+        assert_eq!(copy.effective_weight(), atom.effective_weight());
+        assert_eq!(copy.keywords, atom.keywords);
+    }
+    
+    // Text is a slice of the input, not a copy
+    let range = text.as_bytes().as_ptr_range();
+    assert!(range.contains(&borrowed[1].text.as_ptr()));
+    assert!(matches!(borrowed[3].punctuation_char, Cow::Borrowed(" —")));
+    assert_eq!(borrowed[1].keywords, vec!["breathe"]);
+}
@@ -0,0 +1,223 @@
+//! Core Pacing Tests
+//! 
+//! The original engine behavior: atomization, character-based speech
+//! estimates, the silence budget, and break-tag splitting.
+
+use super::*;
+
+#[test]
+fn test_word_count() {
+    assert_eq!(count_words("hello world"), 2);
+    assert_eq!(count_words("one"), 1);
+    assert_eq!(count_words("  spaces  between  "), 2);
+    assert_eq!(count_words(""), 0);
+}
+
+#[test]
+fn test_punctuation_classification() {
+    assert_eq!(classify_punctuation(".").0, PunctuationType::SentenceEnd);
+    assert_eq!(classify_punctuation("?").0, PunctuationType::SentenceEnd);
+    assert_eq!(classify_punctuation("!").0, PunctuationType::SentenceEnd);
+    assert_eq!(classify_punctuation(",").0, PunctuationType::Comma);
+    assert_eq!(classify_punctuation("\n").0, PunctuationType::Paragraph);
+    assert_eq!(classify_punctuation("").0, PunctuationType::None);
+    assert_eq!(classify_punctuation(";").0, PunctuationType::Semicolon);
+    assert_eq!(classify_punctuation("—").0, PunctuationType::Dash);
+    assert_eq!(classify_punctuation("...").0, PunctuationType::Ellipsis);
+    assert_eq!(classify_punctuation("…").0, PunctuationType::Ellipsis);
+}
+
+#[test]
+fn test_atomize_simple() {
+    let pacer = MeditationPacer::new();
+    let atoms = pacer.atomize_text("Hello, world.");
+    
+    assert_eq!(atoms.len(), 2);
+    assert_eq!(atoms[0].text, "Hello");
+    assert_eq!(atoms[0].punctuation, PunctuationType::Comma);
+    assert_eq!(atoms[1].text, "world");
+    assert_eq!(atoms[1].punctuation, PunctuationType::SentenceEnd);
+}
+
+#[test]
+fn test_break_tag_splitting() {
+    let pacer = MeditationPacer::new();
+    
+    // 2 seconds should be single tag
+    let tags = format_break_tags(&pacer, 2.0);
+    assert_eq!(tags, "<break time=\"2.0s\"/>");
+    
+    // 5 seconds should be two tags (3.0 + 2.0)
+    let tags = format_break_tags(&pacer, 5.0);
+    assert_eq!(tags, "<break time=\"3.0s\"/><break time=\"2.0s\"/>");
+    
+    // 9 seconds should be three tags
+    let tags = format_break_tags(&pacer, 9.0);
+    assert_eq!(tags, "<break time=\"3.0s\"/><break time=\"3.0s\"/><break time=\"3.0s\"/>");
+}
+
+#[test]
+fn test_basic_pacing() {
+    let pacer = MeditationPacer::new();
+    let result = pacer.calculate_pacing(
+        "Welcome. Take a deep breath.".to_string(),
+        60.0
+    );
+    
+    // Should have 2 atoms (two sentences)
+    assert_eq!(result.atom_count, 2);
+    
+    // Should have 5 words total
+    assert_eq!(result.total_words, 5);
+    
+    // SSML should contain break tags
+    assert!(result.ssml.contains("<break"));
+    
+    // Estimated total should be close to target
+    assert!(result.estimated_total_seconds > 0.0);
+}
+
+#[test]
+fn test_no_overflow_when_speech_exceeds_target() {
+    let pacer = MeditationPacer::new();
+    
+    // Very short target with lots of text
+    let long_text = "This is a very long meditation script that contains many many words and will definitely take longer than five seconds to speak aloud.".to_string();
+    let result = pacer.calculate_pacing(long_text, 5.0);
+    
+    // Should not add negative silence
+    assert!(result.total_silence_added >= 0.0);
+    assert!(result.raw_silence_budget >= 0.0);
+    
+    // Should still produce valid SSML
+    assert!(!result.ssml.is_empty());
+}
+
+#[test]
+fn test_empty_text() {
+    let pacer = MeditationPacer::new();
+    let result = pacer.calculate_pacing("".to_string(), 60.0);
+    
+    assert_eq!(result.total_words, 0);
+    assert_eq!(result.total_chars, 0);
+    assert_eq!(result.atom_count, 0);
+}
+
+#[test]
+fn test_character_based_estimation() {
+    let pacer = MeditationPacer::new();
+    // "Welcome" = 7 chars, "Take" = 4, "a" = 1, "deep" = 4, "breath" = 6
+    // Total: 7 + 4 + 1 + 4 + 6 = 22 chars (excluding whitespace)
+    // Estimated speech = 22/12 = 1.833... seconds
+    let result = pacer.calculate_pacing(
+        "Welcome. Take a deep breath.".to_string(),
+        60.0
+    );
+    
+    // Check character count (excluding whitespace)
+    assert_eq!(result.total_chars, 22);
+    
+    // Estimated speech should be ~1.833 seconds (22 chars / 12 cps)
+    let expected_speech = 22.0 / 12.0;
+    assert!((result.estimated_speech_seconds - expected_speech).abs() < 0.01);
+    
+    // Safety buffer should be applied (1.1x)
+    let expected_raw = 60.0 - expected_speech;
+    assert!((result.raw_silence_budget - expected_raw).abs() < 0.01);
+    assert!((result.final_silence_budget - expected_raw * 1.1).abs() < 0.01);
+}
+
+#[test]
+fn test_no_break_after_last_atom() {
+    let pacer = MeditationPacer::new();
+    let result = pacer.calculate_pacing(
+        "First sentence. Second sentence.".to_string(),
+        60.0
+    );
+    
+    // SSML should NOT end with a break tag
+    assert!(!result.ssml.trim_end().ends_with("/>"));
+    
+    // Should end with the punctuation of the last sentence
+    assert!(result.ssml.trim_end().ends_with("."));
+}
+
+#[test]
+fn test_target_words_for_prompt() {
+    // 1 minute = 70 words
+    assert_eq!(calculate_target_words_for_prompt(60.0), 70);
+    
+    // 2 minutes = 140 words
+    assert_eq!(calculate_target_words_for_prompt(120.0), 140);
+    
+    // 5 minutes = 350 words
+    assert_eq!(calculate_target_words_for_prompt(300.0), 350);
+    
+    // 30 seconds = 35 words
+    assert_eq!(calculate_target_words_for_prompt(30.0), 35);
+}
+
+#[test]
+fn test_custom_words_per_minute() {
+    // 60 seconds at 100 wpm = 100 words
+    assert_eq!(calculate_target_words_custom(60.0, 100.0), 100);
+    
+    // 120 seconds at 50 wpm = 100 words
+    assert_eq!(calculate_target_words_custom(120.0, 50.0), 100);
+}
+
+#[test]
+fn test_production_calibration() {
+    // Test with production-like data
+    // Observed: ~60 words = ~310 characters = 26 seconds of speech
+    // Our constant: 12 chars/sec -> 310/12 = 25.83 sec (close to observed 26s)
+    let pacer = MeditationPacer::new();
+    
+    // Generate a meditation script with roughly 310 characters
+    let meditation_text = "Welcome to this moment of peace. \
+        Close your eyes gently. \
+        Take a slow, deep breath in. \
+        Feel the air fill your lungs completely. \
+        Now exhale slowly, releasing all tension. \
+        Notice how your body begins to relax. \
+        Each breath brings you deeper into calm. \
+        Let go of any thoughts that arise. \
+        Simply be present in this moment. \
+        You are safe. You are at peace.".to_string();
+    
+    // Count chars (for validation)
+    let char_count: usize = meditation_text.chars().filter(|c| !c.is_whitespace()).count();
+    println!("Test meditation char count: {}", char_count);
+    
+    // Target: 60 second meditation
+    let result = pacer.calculate_pacing(meditation_text, 60.0);
+    
+    // Estimated speech time should be roughly 26 seconds (within 5 seconds)
+    // 310 chars / 12 cps = ~25.8 seconds
+    assert!(result.estimated_speech_seconds > 20.0);
+    assert!(result.estimated_speech_seconds < 35.0);
+    
+    // With 60s target and ~26s speech, we should have ~34s raw silence
+    // With 1.1x buffer, final silence budget should be ~37.4s
+    assert!(result.final_silence_budget > result.raw_silence_budget);
+    
+    // Total estimated should overshoot target slightly (safety buffer)
+    assert!(result.estimated_total_seconds >= 60.0);
+    
+    // Should NOT have a break at the very end
+    assert!(!result.ssml.ends_with("/>"));
+}
+
+#[test]
+fn test_density_for_five_minute_meditation() {
+    // For a 5-minute meditation at 70 words/minute density
+    let target_words = calculate_target_words_for_prompt(300.0);
+    assert_eq!(target_words, 350); // 5 minutes * 70 wpm
+    
+    // This should give us a 50/50 speech-to-silence ratio
+    // 350 words at ~5.2 chars/word = ~1820 chars
+    // 1820 chars at 12 cps = ~151.7 seconds of speech
+    // 300 - 151.7 = 148.3 seconds of raw silence
+    // 148.3 * 1.1 = 163 seconds of final silence budget
+    // Total: 151.7 + 163 = 314.7 seconds (~5:15 total, slightly over)
+}
@@ -0,0 +1,108 @@
+//! Break Tags Tests
+//! 
+//! Break formatting, rounding-drift compensation, and provider break
+//! budgets.
+
+use super::*;
+
+#[test]
+fn test_break_format() {
+    let config = PacingConfig {
+        break_format: BreakFormat::milliseconds(),
+        ..PacingConfig::default()
+    };
+    let pacer = MeditationPacer::with_config(config);
+    assert_eq!(format_break_tags(&pacer, 0.8504), "<break time=\"850ms\"/>");
+    assert_eq!(format_break_tags(&pacer, 4.25), "<break time=\"3000ms\"/><break time=\"1250ms\"/>");
+    assert!((pacer.break_tag_durations(&[0.8504, 0.0])[0][0] - 0.85).abs() < 1e-12);
+    
+    assert_eq!(BreakFormat::seconds(2).format(0.853), "0.85s");
+    assert_eq!(BreakFormat::default().quantum_seconds(), 0.1);
+    assert_eq!(BreakFormat::milliseconds().quantum_seconds(), 0.001);
+    
+    let result = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+    assert!(result.ssml.contains("ms\"/>"));
+    let rendered: f64 = break_tag_seconds(&result.ssml).iter().sum();
+    assert!((rendered - result.total_silence_added).abs() <= 0.001);
+}
+
+#[test]
+fn test_max_break_below_quantum() {
+    let config = PacingConfig {
+        max_break_seconds: 0.5,
+        min_break_seconds: 0.1,
+        break_format: BreakFormat::seconds(0),
+        ..PacingConfig::default()
+    };
+    assert_eq!(
+        config.validate(),
+        Err(vec![ConfigError::MaxBreakBelowQuantum { max_break_seconds: 0.5, quantum_seconds: 1.0 }])
+    );
+    assert!(MeditationPacer::try_with_config(config.clone()).is_err());
+    
+    // Repaired, breaks are still rendered
+    let pacer = MeditationPacer::with_config(config);
+    assert_eq!(pacer.config().max_break_seconds, MAX_BREAK_SECONDS);
+    let result = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+    assert!(result.ssml.contains("<break time=\"3s\"/>"));
+    
+    let fine = PacingConfig { max_break_seconds: 0.5, min_break_seconds: 0.1, ..PacingConfig::default() };
+    assert!(fine.validate().is_ok());
+}
+
+#[test]
+fn test_rounding_drift_compensated() {
+    // Rounded one at a time, a hundred pauses would drift by seconds
+    let text = "Breathe in. ".repeat(101);
+    for (format, quantum) in [(BreakFormat::default(), 0.1), (BreakFormat::milliseconds(), 0.001)] {
+        let config = PacingConfig {
+            break_format: format,
+            ..PacingConfig::default()
+        };
+        let result = MeditationPacer::with_config(config).calculate_pacing(text.clone(), 137.0);
+        let tags = break_tag_seconds(&result.ssml);
+        assert!(tags.len() >= 100);
+        let rendered: f64 = tags.iter().sum();
+        assert!((rendered - result.total_silence_added).abs() <= quantum, "{:?}", format);
+        assert!((result.total_silence_added - result.final_silence_budget).abs() < 1e-6);
+    }
+    
+    // The tail a split leaves below the minimum break moves on too
+    let pacer = MeditationPacer::new();
+    let tags = pacer.break_tag_durations(&[3.08, 1.0, 0.0]);
+    assert_eq!(tags[0], vec![3.0]);
+    assert!((tags[1][0] - 1.1).abs() < 1e-9);
+}
+
+#[test]
+fn test_break_budget() {
+    let text = "Breathe in, slowly, and hold. Let go, and rest. Soften, release.".to_string();
+    let unlimited = MeditationPacer::new().calculate_pacing(text.clone(), 14.0);
+    let unlimited_tags = unlimited.ssml.matches("<break").count();
+    
+    // A tag cap merges the shortest pauses into the rest
+    let config = PacingConfig {
+        break_budget: BreakBudget { max_total_seconds: None, max_tags: Some(4) },
+        ..PacingConfig::default()
+    };
+    let result = MeditationPacer::with_config(config).calculate_pacing(text.clone(), 14.0);
+    let tags = result.ssml.matches("<break").count();
+    assert!(unlimited_tags > 4 && tags <= 4);
+    assert!(result.warnings.contains(&PacingWarning::BreaksMerged {
+        merged_count: unlimited.break_stats.count - result.break_stats.count,
+        tag_count: tags,
+    }));
+    assert!((result.total_silence_added - unlimited.total_silence_added).abs() < 1e-9);
+    
+    // A total cap cannot be met by merging, so chunking is suggested
+    let config = PacingConfig {
+        break_budget: BreakBudget { max_total_seconds: Some(10.0), max_tags: None },
+        ..PacingConfig::default()
+    };
+    let result = MeditationPacer::with_config(config).calculate_pacing(text, 30.0);
+    assert!(result.warnings.iter().any(|w| matches!(
+        w,
+        PacingWarning::BreakBudgetExceeded { break_seconds, suggested_chunks, .. }
+            if *break_seconds > 10.0 && *suggested_chunks == (*break_seconds / 10.0).ceil() as usize
+    )));
+}
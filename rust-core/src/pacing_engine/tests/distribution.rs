@@ -0,0 +1,167 @@
+//! Silence Distribution Tests
+//! 
+//! Pause curves, allocation strategies, seeded jitter, the sleep-story
+//! slowdown, dropped-silence redistribution, and pause floors.
+
+use super::*;
+
+#[test]
+fn test_progressive_curve() {
+    let curve = ProgressiveCurve::linear(0.5, 2.0);
+    assert!((curve.multiplier_at(0.0) - 0.5).abs() < 1e-9);
+    assert!((curve.multiplier_at(1.0) - 2.0).abs() < 1e-9);
+    assert!((curve.multiplier_at(0.5) - 1.25).abs() < 1e-9);
+    
+    let config = PacingConfig {
+        progression: Some(curve),
+        ..PacingConfig::default()
+    };
+    let pacer = MeditationPacer::with_config(config);
+    let atoms = pacer.atomize("Rest now. Rest now. Rest now. Rest now. Rest now.");
+    let pauses = pacer.distribute_silence(&atoms, 40.0, &PacingOverrides::new());
+    
+    // Pauses grow monotonically while spending the same budget
+    assert!(pauses[0] < pauses[1] && pauses[1] < pauses[2] && pauses[2] < pauses[3]);
+    assert!((pauses.iter().sum::<f64>() - 40.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_pause_curve_shapes() {
+    for curve in [PauseCurve::Linear, PauseCurve::EaseIn, PauseCurve::EaseOut, PauseCurve::Sigmoid] {
+        assert!(curve.shape(0.0).abs() < 1e-9, "{:?}", curve);
+        assert!((curve.shape(1.0) - 1.0).abs() < 1e-9, "{:?}", curve);
+    }
+    assert!(PauseCurve::EaseIn.shape(0.5) < 0.5);
+    assert!(PauseCurve::EaseOut.shape(0.5) > 0.5);
+    assert!((PauseCurve::Sigmoid.shape(0.5) - 0.5).abs() < 1e-9);
+    assert!(PauseCurve::Sigmoid.shape(0.1) < PauseCurve::Linear.shape(0.1));
+    
+    // Custom output is clamped
+    let custom = PauseCurve::Custom(Arc::new(|t| t * 3.0));
+    assert_eq!(custom.shape(0.9), 1.0);
+    
+    // Shrinking envelope: long pauses up front, short at the end
+    let config = PacingConfig {
+        progression: Some(ProgressiveCurve::shaped(2.0, 0.5, PauseCurve::EaseOut)),
+        ..PacingConfig::default()
+    };
+    let pacer = MeditationPacer::with_config(config);
+    let atoms = pacer.atomize("Rest now. Rest now. Rest now. Rest now.");
+    let pauses = pacer.distribute_silence(&atoms, 30.0, &PacingOverrides::new());
+    assert!(pauses[0] > pauses[1] && pauses[1] > pauses[2]);
+}
+
+#[test]
+fn test_equal_per_sentence_allocation() {
+    let config = PacingConfig {
+        allocation: AllocationStrategy::EqualPerSentence,
+        ..PacingConfig::default()
+    };
+    let pacer = MeditationPacer::with_config(config);
+    let atoms = pacer.atomize("Notice your feet, toes, and heels.\nNotice your calves... Notice your knees. Rest.");
+    let pauses = pacer.distribute_silence(&atoms, 30.0, &PacingOverrides::new());
+    
+    // Commas get nothing; paragraph, ellipsis, and period share equally
+    assert_eq!(pauses[0], 0.0);
+    assert_eq!(pauses[1], 0.0);
+    assert!((pauses[2] - 10.0).abs() < 1e-9);
+    assert!((pauses[3] - 10.0).abs() < 1e-9);
+    assert!((pauses[4] - 10.0).abs() < 1e-9);
+    assert_eq!(pauses[5], 0.0);
+}
+
+#[test]
+fn test_dropped_silence_redistributed() {
+    // 11 commas and one sentence end: commas would get 0.05s each
+    let text = "a, b, c, d, e, f, g, h, i, j, k, l. m";
+    let atoms = MeditationPacer::new().atomize(text);
+    
+    let pacer = MeditationPacer::new();
+    let pauses = pacer.distribute_silence(&atoms, 0.7, &PacingOverrides::new());
+    assert!(pauses.iter().all(|p| *p == 0.0 || *p >= MIN_BREAK_SECONDS));
+    assert!((pauses.iter().sum::<f64>() - 0.7).abs() < 1e-9);
+    
+    // With redistribution off, the comma breaks are lost at render time
+    let config = PacingConfig {
+        redistribute_dropped_silence: false,
+        ..PacingConfig::default()
+    };
+    let legacy = MeditationPacer::with_config(config);
+    let pauses = legacy.distribute_silence(&atoms, 0.7, &PacingOverrides::new());
+    let (_, added, dropped) = legacy.render_ssml(&atoms, &pauses);
+    assert!(dropped > 0.5);
+    assert!((added + dropped - 0.7).abs() < 1e-9);
+}
+
+#[test]
+fn test_seeded_jitter() {
+    let text = "Rest now. Rest now. Rest now. Rest now. Rest now. Rest now.";
+    let jittered = |seed| {
+        let config = PacingConfig {
+            jitter: Some(PauseJitter::new(0.2, seed)),
+            ..PacingConfig::default()
+        };
+        let pacer = MeditationPacer::with_config(config);
+        let atoms = pacer.atomize(text);
+        pacer.distribute_silence(&atoms, 50.0, &PacingOverrides::new())
+    };
+    
+    let a = jittered(7);
+    assert_eq!(a, jittered(7));
+    assert_ne!(a, jittered(8));
+    
+    // Budget conserved, every pause within ±20% relative spread
+    assert!((a.iter().sum::<f64>() - 50.0).abs() < 1e-9);
+    let breaks = &a[..5];
+    let max = breaks.iter().cloned().fold(f64::MIN, f64::max);
+    let min = breaks.iter().cloned().fold(f64::MAX, f64::min);
+    assert!(max > min);
+    assert!(max / min <= 1.2 / 0.8 + 1e-9);
+}
+
+#[test]
+fn test_sleep_story_mode() {
+    let text = "The forest is quiet. The path is soft. The stars come out. You drift. You rest.";
+    let normal = MeditationPacer::new().calculate_pacing(text.to_string(), 120.0);
+    let pacer = MeditationPacer::with_config(PacingConfig::sleep_story());
+    let result = pacer.calculate_pacing(text.to_string(), 120.0);
+    
+    // Opening at normal speed, closing near 75%
+    assert!(result.ssml.starts_with("The forest is quiet."));
+    assert!(result.ssml.ends_with("<prosody rate=\"78%\">You rest.</prosody>"));
+    
+    // Slower speech leaves less silence for the same target
+    assert!(result.estimated_speech_seconds > normal.estimated_speech_seconds);
+    
+    // Pauses grow toward the end
+    let atoms = pacer.atomize(text);
+    let pauses = pacer.distribute_silence(&atoms, 60.0, &PacingOverrides::new());
+    assert!(pauses[0] < pauses[3]);
+}
+
+#[test]
+fn test_pause_floors() {
+    let text = "Breathe in, slowly, and hold it. Let go.\n\nRest here, softly. Now release, and wait.\n\nSleep.";
+    let mut config = PacingConfig::default();
+    config.set_pause_floor(PunctuationType::Paragraph, 4.0);
+    let floored = MeditationPacer::with_config(config);
+    let plain = MeditationPacer::new();
+    let atoms = plain.atomize(text);
+    let target = plain.speech_seconds(&atoms).iter().sum::<f64>() + 12.0;
+    
+    let before = plain.distribute_pauses(&atoms, target);
+    let after = floored.distribute_pauses(&atoms, target);
+    let paragraphs: Vec<usize> = (0..atoms.len()).filter(|i| atoms[*i].punctuation == PunctuationType::Paragraph).collect();
+    assert_eq!(paragraphs.len(), 2);
+    for i in paragraphs {
+        assert!(before[i] < 4.0);
+        assert_eq!(after[i], 4.0);
+    }
+    
+    // The budget is unchanged; the other pauses give up the difference
+    assert!((before.iter().sum::<f64>() - after.iter().sum::<f64>()).abs() < 1e-9);
+    
+    // A floor holds even when it exceeds the whole budget
+    let tight = floored.distribute_pauses(&atoms, target - 10.0);
+    assert_eq!(tight.iter().filter(|p| **p == 4.0).count(), 2);
+}
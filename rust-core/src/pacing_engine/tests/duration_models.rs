@@ -0,0 +1,67 @@
+//! Duration Models Tests
+//! 
+//! Syllable and words-per-minute speech estimates, and language
+//! speech-rate presets.
+
+use super::*;
+
+#[test]
+fn test_syllable_count() {
+    assert_eq!(count_syllables("calm"), 1);
+    assert_eq!(count_syllables("breathe"), 1);
+    assert_eq!(count_syllables("gentle"), 2);
+    assert_eq!(count_syllables("relax"), 2);
+    assert_eq!(count_syllables("consciousness"), 3);
+    assert_eq!(count_syllables("Notice your body"), 5);
+    assert_eq!(count_syllables(""), 0);
+}
+
+#[test]
+fn test_syllable_duration_model() {
+    let config = PacingConfig {
+        duration_model: DurationModel::Syllables,
+        ..PacingConfig::default()
+    };
+    let pacer = MeditationPacer::with_config(config);
+    let result = pacer.calculate_pacing("Calm. Consciousness.".to_string(), 30.0);
+    
+    // 1 + 3 syllables at 4 per second
+    assert!((result.estimated_speech_seconds - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_words_per_minute_model() {
+    let config = PacingConfig {
+        duration_model: DurationModel::WordsPerMinute(120.0),
+        ..PacingConfig::default()
+    };
+    let pacer = MeditationPacer::with_config(config);
+    let result = pacer.calculate_pacing("Welcome. Take a deep breath.".to_string(), 30.0);
+    
+    // 5 words at 2 words per second
+    assert_eq!(result.duration_model, DurationModel::WordsPerMinute(120.0));
+    assert!((result.estimated_speech_seconds - 2.5).abs() < 1e-9);
+    assert!((result.speech_estimates.words - 2.5).abs() < 1e-9);
+    assert!((result.speech_estimates.characters - 22.0 / 12.0).abs() < 1e-9);
+    
+    // Default model still reports the word-based comparison
+    let default = MeditationPacer::new().calculate_pacing("Welcome. Take a deep breath.".to_string(), 30.0);
+    assert_eq!(default.duration_model, DurationModel::Characters);
+    assert!((default.speech_estimates.words - 5.0 * 60.0 / 140.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_language_presets() {
+    assert_eq!(Language::from_code("es-MX"), Some(Language::Spanish));
+    assert_eq!(Language::from_code("JA"), Some(Language::Japanese));
+    assert_eq!(Language::from_code("xx"), None);
+    
+    let config = PacingConfig::for_language(Language::Japanese);
+    assert_eq!(config.effective_chars_per_second(), 5.5);
+    let pacer = MeditationPacer::with_config(config);
+    let result = pacer.calculate_pacing("深呼吸をしましょう".to_string(), 60.0);
+    assert!((result.estimated_speech_seconds - 9.0 / 5.5).abs() < 1e-9);
+    
+    // No language: the explicit rate applies
+    assert_eq!(PacingConfig::default().effective_chars_per_second(), CHARS_PER_SECOND);
+}
@@ -0,0 +1,56 @@
+//! Intro and Outro Tests
+//! 
+//! The leading settle-in pause and the trailing outro pause.
+
+use super::*;
+
+#[test]
+fn test_outro_pause() {
+    let text = "Breathe in. Let go. Sleep now.".to_string();
+    let config = PacingConfig { outro_pause_seconds: 10.0, ..PacingConfig::default() };
+    let result = MeditationPacer::with_config(config).calculate_pacing(text.clone(), 60.0);
+    
+    assert_eq!(result.timeline.last().unwrap().pause_seconds, 10.0);
+    assert!(result.ssml.trim_end_matches("</speak>").ends_with("/>"));
+    assert!((break_tag_seconds(&result.ssml).iter().sum::<f64>() - result.total_silence_added).abs() < 0.1);
+    // The outro comes out of the target before the safety buffer
+    let between = result.total_silence_added - 10.0;
+    let buffer = PacingConfig::default().silence_safety_buffer;
+    assert!((between - (60.0 - result.estimated_speech_seconds - 10.0) * buffer).abs() < 1e-9);
+    
+    // Without an outro the script ends on the last atom
+    let plain = MeditationPacer::new().calculate_pacing(text, 60.0);
+    assert_eq!(plain.timeline.last().unwrap().pause_seconds, 0.0);
+    assert!((plain.total_silence_added - between - 10.0 * buffer).abs() < 1e-9);
+    
+    let config = PacingConfig { outro_pause_seconds: -1.0, ..PacingConfig::default() };
+    assert!(config.validate().unwrap_err().contains(&ConfigError::InvalidFixedPause {
+        field: "outro_pause_seconds",
+        value: -1.0,
+    }));
+}
+
+#[test]
+fn test_intro_pause() {
+    let text = "Breathe in. Let go. Rest.".to_string();
+    let config = PacingConfig { intro_pause_seconds: 5.0, ..PacingConfig::default() };
+    let pacer = MeditationPacer::with_config(config);
+    let result = pacer.calculate_pacing(text.clone(), 60.0);
+    
+    // The intro opens the SSML and delays the whole timeline
+    let intro = pacer.intro_break_tags();
+    assert_eq!(intro.iter().sum::<f64>(), 5.0);
+    assert_eq!(break_tag_seconds(&result.ssml)[..intro.len()], intro[..]);
+    assert!(result.ssml.find("<break").unwrap() < result.ssml.find("Breathe").unwrap());
+    assert_eq!(result.intro_pause_seconds, 5.0);
+    assert_eq!(result.timeline[0].start_seconds, 5.0);
+    assert_eq!(result.word_timings[0].start_seconds, 5.0);
+    assert!((break_tag_seconds(&result.ssml).iter().sum::<f64>() - result.total_silence_added).abs() < 0.1);
+    
+    // ...and comes out of the target like the outro
+    let plain = MeditationPacer::new().calculate_pacing(text, 60.0);
+    let buffer = PacingConfig::default().silence_safety_buffer;
+    let between = result.total_silence_added - 5.0;
+    assert!((plain.total_silence_added - between - 5.0 * buffer).abs() < 1e-9);
+    assert!(result.to_plain_text().starts_with("[pause 5.0s] Breathe in."));
+}
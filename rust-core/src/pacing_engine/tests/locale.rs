@@ -0,0 +1,86 @@
+//! Locale Scripts Tests
+//! 
+//! Right-to-left and Spanish punctuation in the default atomizer, and
+//! locale punctuation alongside markers and custom patterns.
+
+use super::*;
+
+#[test]
+fn test_rtl_scripts() {
+    let pacer = MeditationPacer::new();
+    let atoms = pacer.atomize_text("تنفّس ببطء، واسترخِ؛ هل تشعر بالهدوء؟ نعم۔ שְׁאַף עָמֹק׃ וְנַשֵּׁף.");
+    let kinds: Vec<PunctuationType> = atoms.iter().map(|a| a.punctuation).collect();
+    assert_eq!(kinds, vec![
+        PunctuationType::Comma,
+        PunctuationType::Semicolon,
+        PunctuationType::SentenceEnd,
+        PunctuationType::SentenceEnd,
+        PunctuationType::SentenceEnd,
+        PunctuationType::SentenceEnd,
+    ]);
+    assert_eq!(atoms[0].punctuation_char, "،");
+    assert_eq!(atoms[2].punctuation_char, "؟");
+
+    // Harakat, niqqud, tatweel, and bidi marks are not spoken characters
+    assert_eq!(atoms[0].char_count(), "تنفسببطء".chars().count());
+    assert_eq!(atoms[4].char_count(), "שאףעמק".chars().count());
+    let marked = SpeechAtom::new("\u{200f}سـلام".to_string(), PunctuationType::None, String::new());
+    assert_eq!(marked.char_count(), 4);
+
+    let result = pacer.calculate_pacing("تنفّس ببطء، واسترخِ.".to_string(), 20.0);
+    assert!(result.ssml.starts_with("تنفّس ببطء،<break"));
+}
+
+#[test]
+fn test_spanish_inverted_marks() {
+    let pacer = MeditationPacer::new();
+    let atoms = pacer.atomize_text("Respira hondo.¿Sientes la calma? ¡ Muy bien !");
+    let texts: Vec<&str> = atoms.iter().map(|a| a.text.as_str()).collect();
+    assert_eq!(texts, vec!["Respira hondo", "¿Sientes la calma", "¡ Muy bien"]);
+    assert_eq!(atoms[1].punctuation, PunctuationType::SentenceEnd);
+    assert_eq!(atoms[1].punctuation_char, "?");
+
+    // The marks are neither spoken characters nor words
+    assert_eq!(atoms[1].char_count(), "Sienteslacalma".len());
+    assert_eq!(atoms[2].char_count(), "Muybien".len());
+    assert_eq!(atoms[2].word_count, 2);
+}
+
+#[test]
+fn test_locale_punctuation_with_markers() {
+    let text = "息を吸って。吐いて。休んで。";
+    let mut config = PacingConfig::for_locale(Locale::japanese());
+    config.set_markers(&[("[pause]", PunctuationType::Paragraph)]);
+    let atoms = MeditationPacer::with_config(config.clone()).atomize(text);
+    assert_eq!(atoms.len(), 3);
+    assert_eq!(atoms[0].punctuation_char, "。");
+    assert_eq!(MeditationPacer::with_config(config).atomize("息を吸って[pause]休んで。").len(), 2);
+    
+    // Markers set before the locale still split alongside it
+    let mut config = PacingConfig::default();
+    config.set_markers(&[("[pause]", PunctuationType::Paragraph)]);
+    config.locale = Some(Locale::japanese());
+    assert_eq!(MeditationPacer::with_config(config).atomize(text).len(), 3);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_custom_pattern_must_split_on_locale_punctuation() {
+    let config = PacingConfig {
+        atom_pattern: Some(r"([^.]+)(\.*)".to_string()),
+        ..PacingConfig::for_locale(Locale::japanese())
+    };
+    let mark = Locale::japanese().punctuation_chars()[0];
+    assert_eq!(config.validate(), Err(vec![ConfigError::PatternIgnoresLocale(mark)]));
+    
+    // Repaired back to the built-in splitter
+    let pacer = MeditationPacer::with_config(config);
+    assert_eq!(pacer.atomize("息を吸って。吐いて。休んで。").len(), 3);
+    
+    let marks: String = Locale::japanese().punctuation_chars().into_iter().collect();
+    let config = PacingConfig {
+        atom_pattern: Some(format!("([^.{0}]+)([.{0}]*)", marks)),
+        ..PacingConfig::for_locale(Locale::japanese())
+    };
+    assert!(config.validate().is_ok());
+}
@@ -0,0 +1,57 @@
+//! Overrides and Anchors Tests
+//! 
+//! Per-atom weight and pause overrides, and anchor timestamps.
+
+use super::*;
+
+#[test]
+fn test_pinned_pause_override() {
+    let pacer = MeditationPacer::new();
+    let text = "Settle in. Now scan your body. Notice your breath. Rest here.";
+    let overrides = PacingOverrides::new()
+        .pause_where(|a| a.text.contains("scan your body"), 15.0);
+    let result = pacer.calculate_pacing_with_overrides(text.to_string(), 60.0, &overrides);
+    
+    // The pinned 15s pause renders as 3s tags right after the sentence
+    assert!(result.ssml.contains(
+        "Now scan your body.<break time=\"3.0s\"/><break time=\"3.0s\"/><break time=\"3.0s\"/><break time=\"3.0s\"/><break time=\"3.0s\"/> Notice"
+    ));
+    
+    // The remainder is still fully distributed
+    assert!((result.total_silence_added - result.final_silence_budget).abs() < 0.01);
+}
+
+#[test]
+fn test_weight_override_by_index() {
+    let pacer = MeditationPacer::new();
+    let atoms = pacer.atomize("One. Two. Three.");
+    assert_eq!(atoms.len(), 3);
+    
+    // Zero weight on the first atom moves all silence to the second
+    let overrides = PacingOverrides::new().weight_at(0, 0);
+    let pauses = pacer.distribute_silence(&atoms, 12.0, &overrides);
+    assert_eq!(pauses, vec![0.0, 12.0, 0.0]);
+}
+
+#[test]
+fn test_anchor_timestamp() {
+    let pacer = MeditationPacer::new();
+    let text = "Settle in. Breathe. Listen for the bell. Rest in the sound. Return.";
+    let overrides = PacingOverrides::new()
+        .anchor_where(|a| a.text.contains("bell"), 30.0);
+    let atoms = pacer.atomize(text);
+    let anchors = overrides.resolve_anchors(&atoms);
+    assert_eq!(anchors, vec![(2, 30.0)]);
+    
+    let pauses = pacer.distribute_anchored(&atoms, &anchors, 90.0, &overrides);
+    
+    // Speech plus silence before the anchored atom adds up to the pin
+    let before: f64 = atoms[..2].iter()
+        .map(|a| a.char_count() as f64 / CHARS_PER_SECOND)
+        .sum::<f64>() + pauses[..2].iter().sum::<f64>();
+    assert!((before - 30.0).abs() < 1e-9);
+    assert_eq!(pauses[4], 0.0);
+    
+    let result = pacer.calculate_pacing_with_overrides(text.to_string(), 90.0, &overrides);
+    assert!(result.ssml.contains("<break"));
+}
@@ -0,0 +1,111 @@
+//! Punctuation Tests
+//! 
+//! Weight tables, keyword bonuses, repeated punctuation, and quoted
+//! spans.
+
+use super::*;
+
+#[test]
+fn test_custom_weight_table() {
+    let mut config = PacingConfig::default();
+    assert_eq!(config.weight_for(PunctuationType::Paragraph), WEIGHT_PARAGRAPH);
+    
+    // Silence commas entirely, keep sentence ends
+    config.set_weight(PunctuationType::Comma, 0);
+    let pacer = MeditationPacer::with_config(config);
+    let atoms = pacer.atomize_text("Breathe in, breathe out. Rest; relax... Now — let go.");
+    
+    assert_eq!(atoms[0].weight, 0);
+    assert_eq!(atoms[1].weight, WEIGHT_SENTENCE);
+    assert_eq!(atoms[2].punctuation, PunctuationType::Semicolon);
+    assert_eq!(atoms[3].punctuation, PunctuationType::Ellipsis);
+    assert_eq!(atoms[3].weight, WEIGHT_ELLIPSIS);
+    assert_eq!(atoms[4].punctuation, PunctuationType::Dash);
+    
+    let result = pacer.calculate_pacing("Breathe in, breathe out.".to_string(), 30.0);
+    assert!(result.ssml.starts_with("Breathe in, breathe out."));
+}
+
+#[test]
+fn test_dash_keeps_source_spacing() {
+    let pacer = MeditationPacer::new();
+    for text in ["Now relax — rest.", "Now relax \u{2013} rest."] {
+        let atoms = pacer.atomize(text);
+        assert_eq!(pacer.render_atoms(&atoms, &vec![0.0; atoms.len()]), text);
+    }
+    assert!(pacer.calculate_pacing("Now relax — rest.".to_string(), 30.0).ssml.starts_with("Now relax —<break"));
+}
+
+#[test]
+fn test_repeated_punctuation_collapses() {
+    let text = "Relax.... Really??! Wait!!!\n\nSoften. . . and rest.";
+    let atoms = MeditationPacer::new().atomize(text);
+    let marks: Vec<(PunctuationType, &str)> = atoms.iter()
+        .map(|a| (a.punctuation, a.punctuation_char.as_str()))
+        .collect();
+    assert_eq!(marks, vec![
+        (PunctuationType::Ellipsis, "..."),
+        (PunctuationType::SentenceEnd, "?"),
+        (PunctuationType::Paragraph, "!\n\n"),
+        (PunctuationType::Ellipsis, "..."),
+        (PunctuationType::SentenceEnd, "."),
+    ]);
+    let weights: Vec<u32> = atoms.iter().map(|a| a.weight).collect();
+    assert_eq!(weights, vec![4, 3, 5, 4, 3]);
+    
+    // Runs (but not a spelled-out ellipsis) are weighted up
+    let config = PacingConfig { repeated_punctuation_multiplier: 2.0, ..PacingConfig::default() };
+    let atoms = MeditationPacer::with_config(config).atomize(text);
+    let weights: Vec<u32> = atoms.iter().map(|a| a.weight).collect();
+    assert_eq!(weights, vec![8, 6, 10, 4, 3]);
+}
+
+#[test]
+fn test_quoted_spans_stay_intact() {
+    let text = "Say to yourself, 'I am calm. I am safe.' Then breathe. Don't rush, \"rest,\" she said.";
+    let atoms = MeditationPacer::new().atomize(text);
+    let texts: Vec<&str> = atoms.iter().map(|a| a.text.as_str()).collect();
+    assert_eq!(texts, vec!["Say to yourself", "'I am calm. I am safe.'", "Then breathe", "Don't rush", "\"rest,\"", "she said"]);
+    
+    // The span pauses as the punctuation inside its closing quote
+    assert_eq!(atoms[1].punctuation, PunctuationType::SentenceEnd);
+    assert_eq!(atoms[1].punctuation_char, "");
+    assert_eq!(atoms[4].punctuation, PunctuationType::Comma);
+    
+    // Soft pauses on both sides of the quote
+    let config = PacingConfig { soften_quote_pauses: true, ..PacingConfig::default() };
+    let atoms = MeditationPacer::with_config(config).atomize(text);
+    let kinds: Vec<PunctuationType> = atoms.iter().map(|a| a.punctuation).collect();
+    assert_eq!(kinds, vec![
+        PunctuationType::Quote,
+        PunctuationType::Quote,
+        PunctuationType::SentenceEnd,
+        PunctuationType::Quote,
+        PunctuationType::Quote,
+        PunctuationType::SentenceEnd,
+    ]);
+    
+    let config = PacingConfig { keep_quotes_intact: false, ..PacingConfig::default() };
+    assert_eq!(MeditationPacer::with_config(config).atomize(text).len(), 7);
+    
+    // An unclosed quote does not swallow the script
+    assert_eq!(MeditationPacer::new().atomize("An 'open quote. Rest.").len(), 2);
+}
+
+#[test]
+fn test_keyword_bonus_weighting() {
+    let mut config = PacingConfig::default();
+    config.keyword_bonuses.insert("relax".to_string(), 2);
+    config.keyword_bonuses.insert("let go".to_string(), 3);
+    let pacer = MeditationPacer::with_config(config);
+    
+    let atoms = pacer.atomize("Relax your shoulders. Let go of the day. Relaxation comes. End.");
+    assert_eq!(atoms[0].keywords, vec!["relax".to_string()]);
+    assert_eq!(atoms[0].effective_weight(), WEIGHT_SENTENCE + 2);
+    assert_eq!(atoms[1].keyword_bonus, 3);
+    // Whole words only
+    assert_eq!(atoms[2].keyword_bonus, 0);
+    
+    let pauses = pacer.distribute_silence(&atoms, 20.0, &PacingOverrides::new());
+    assert!(pauses[1] > pauses[0] && pauses[0] > pauses[2]);
+}
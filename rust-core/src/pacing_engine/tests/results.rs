@@ -0,0 +1,130 @@
+//! Results Tests
+//! 
+//! Timelines, word timings, warnings, billing characters, break
+//! statistics, and display formatting.
+
+use super::*;
+
+#[test]
+fn test_display_summary() {
+    assert_eq!(PunctuationType::SentenceEnd.to_string(), "sentence end");
+    
+    let mut result = MeditationPacer::new().calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+    result.estimated_speech_seconds = 1.5;
+    result.total_silence_added = 19.0;
+    result.estimated_total_seconds = 20.5;
+    result.warnings.clear();
+    assert_eq!(
+        result.summary(),
+        "2 atoms, 4 words: 1.5s speech + 19.0s silence = 20.5s (target 20.0s, +0.5s)",
+    );
+    result.warnings.push(PacingWarning::SpeechExceedsTarget { speech_seconds: 1.0, target_seconds: 0.5 });
+    assert!(result.to_string().ends_with("+0.5s), 1 warning"));
+}
+
+#[test]
+fn test_break_stats() {
+    let stats = BreakStats::from_pauses(&[0.0, 0.3, 1.5, 4.0, 12.0, 0.0], &[]);
+    assert_eq!(stats.count, 4);
+    assert_eq!((stats.min_seconds, stats.max_seconds), (0.3, 12.0));
+    assert_eq!(stats.mean_seconds, 4.45);
+    assert_eq!((stats.p50_seconds, stats.p90_seconds), (1.5, 12.0));
+    assert_eq!(stats.histogram, vec![1, 0, 1, 1, 0, 1]);
+    
+    let config = PacingConfig { max_break_seconds: 2.0, ..PacingConfig::default() };
+    let result = MeditationPacer::with_config(config).calculate_pacing("Rest. Let go.".to_string(), 30.0);
+    assert_eq!(result.break_stats.count, 1);
+    assert_eq!(result.break_stats.split_count, 1);
+    assert_eq!(result.break_stats.max_seconds, result.timeline[0].pause_seconds);
+}
+
+#[test]
+fn test_word_timings() {
+    let pacer = MeditationPacer::new();
+    let result = pacer.calculate_pacing("Breathe in. Hold it now.".to_string(), 20.0);
+    
+    let words: Vec<&str> = result.word_timings.iter().map(|w| w.word.as_str()).collect();
+    assert_eq!(words, vec!["Breathe", "in", "Hold", "it", "now"]);
+    assert_eq!(result.word_timings[0].start_seconds, 0.0);
+    
+    // "in" starts after the 7 chars of "Breathe"
+    assert!((result.word_timings[1].start_seconds - 7.0 / 12.0).abs() < 1e-9);
+    
+    // "Hold" starts after the first atom's speech and its pause
+    let first_atom = 9.0 / 12.0;
+    let expected = first_atom + result.total_silence_added;
+    assert!((result.word_timings[2].start_seconds - expected).abs() < 1e-9);
+    assert_eq!(result.word_timings[2].atom_index, 1);
+    
+    // The last word ends when speech ends
+    let last = result.word_timings.last().unwrap();
+    assert!((last.start_seconds + last.duration_seconds - result.estimated_total_seconds).abs() < 1e-9);
+
+    // Numbers and acronyms take as long as their spoken words, so the
+    // words of an atom fill it exactly
+    let result = pacer.calculate_pacing("Count from 1 to 10. Check your HRV now.".to_string(), 20.0);
+    for (i, atom) in result.timeline.iter().enumerate() {
+        let words: Vec<&WordTiming> = result.word_timings.iter().filter(|w| w.atom_index == i).collect();
+        let last = words.last().unwrap();
+        assert!((words[0].start_seconds - atom.start_seconds).abs() < 1e-9);
+        assert!((last.start_seconds + last.duration_seconds - atom.start_seconds - atom.speech_seconds).abs() < 1e-9);
+    }
+    let ten = &result.word_timings[4];
+    assert_eq!(ten.word, "10");
+    assert!((ten.duration_seconds / result.timeline[0].speech_seconds - 3.0 / 17.0).abs() < 1e-9);
+    let hrv = &result.word_timings[7];
+    assert_eq!(hrv.word, "HRV");
+    assert!((hrv.duration_seconds / result.timeline[1].speech_seconds - 10.0 / 22.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_timeline() {
+    let pacer = MeditationPacer::new();
+    let result = pacer.calculate_pacing("Breathe in. Hold it. Let go.".to_string(), 30.0);
+    
+    assert_eq!(result.timeline.len(), 3);
+    assert_eq!(result.timeline[1].text, "Hold it");
+    for pair in result.timeline.windows(2) {
+        assert!((pair[1].start_seconds - pair[0].end_seconds()).abs() < 1e-9);
+    }
+    
+    let last = result.timeline.last().unwrap();
+    assert_eq!(last.pause_seconds, 0.0);
+    assert!((last.end_seconds() - result.estimated_total_seconds).abs() < 1e-9);
+}
+
+#[test]
+fn test_render_warnings() {
+    let pacer = MeditationPacer::new();
+    
+    // Long pauses get split across several tags
+    let result = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+    assert!(result.warnings.iter().any(|w| matches!(
+        w,
+        PacingWarning::BreakSplit { atom_index: 0, tag_count, .. } if *tag_count > 1
+    )));
+    
+    // A target shorter than the speech itself
+    let result = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 0.5);
+    assert!(result.warnings.iter().any(|w| matches!(w, PacingWarning::SpeechExceedsTarget { .. })));
+    
+    // A pause pinned below the minimum break is dropped
+    let overrides = PacingOverrides::new().pause_at(0, 0.05);
+    let result = pacer.calculate_pacing_with_overrides("Breathe in. Let go. Rest.".to_string(), 10.0, &overrides);
+    assert!(result.warnings.contains(&PacingWarning::BreakDropped {
+        atom_index: 0,
+        pause_seconds: 0.05,
+        min_break_seconds: MIN_BREAK_SECONDS,
+    }));
+}
+
+#[test]
+fn test_billing_characters() {
+    let text = "Breathe in. Let go.";
+    let result = calculate_pacing_details(text.to_string(), 20.0);
+    
+    // Every break tag is billed on top of the spoken text
+    assert_eq!(result.billing_characters(), result.ssml.len());
+    assert!(result.billing_characters() > text.len());
+    assert_eq!(estimate_cost(text.to_string(), 20.0), result.billing_characters());
+}
@@ -0,0 +1,178 @@
+//! Targets Tests
+//! 
+//! The exact-duration solver, measured re-pacing, silence-ratio
+//! guardrails, and target-duration limits.
+
+use super::*;
+
+#[test]
+fn test_exact_duration_solver() {
+    let text = "Welcome. Close your eyes, and breathe. Feel the ground beneath you. \
+        Let your shoulders soften, your jaw release. Rest here.\n\
+        Notice the breath, in and out. Stay with it... Gently return.";
+    let pacer = MeditationPacer::new();
+    
+    let result = pacer.calculate_pacing_exact(text.to_string(), 300.0, 0.05);
+    assert!((result.estimated_total_seconds - 300.0).abs() <= 0.05);
+    
+    // Rendered tags add up to what the result reports
+    let rendered: f64 = result.ssml.split(r#"time=""#).skip(1)
+        .map(|rest| rest[..rest.find(r#"s""#).unwrap()].parse::<f64>().unwrap())
+        .sum();
+    assert!((rendered - result.total_silence_added).abs() < 1e-6);
+    
+    // Speech longer than the target cannot converge but still returns
+    let short = pacer.calculate_pacing_exact(text.to_string(), 5.0, 0.05);
+    assert_eq!(short.total_silence_added, 0.0);
+    
+    // Target limits apply as in `calculate_pacing`
+    let too_short = pacer.calculate_pacing_exact(text.to_string(), 2.0, 0.05);
+    assert!(too_short.warnings.iter().any(|w| matches!(w, PacingWarning::TargetOutOfRange { .. })));
+    let mut config = PacingConfig::default();
+    config.target_limits.clamp = true;
+    let clamped = MeditationPacer::with_config(config).calculate_pacing_exact("Breathe in. Let go.".to_string(), 2.0, 0.05);
+    assert_eq!(clamped.target_duration_seconds, MIN_TARGET_SECONDS);
+    assert!((clamped.estimated_total_seconds - MIN_TARGET_SECONDS).abs() <= 0.05);
+    assert!(clamped.warnings.iter().any(|w| matches!(
+        w,
+        PacingWarning::TargetOutOfRange { paced_seconds, .. } if *paced_seconds == MIN_TARGET_SECONDS
+    )));
+}
+
+#[test]
+fn test_silence_ratio_guardrails() {
+    // ~100 words stretched over 20 minutes is almost all silence
+    let text = "Breathe in slowly and notice the air. ".repeat(14);
+    let pacer = MeditationPacer::new();
+    let result = pacer.calculate_pacing(text.clone(), 1200.0);
+    
+    let ratio_warnings: Vec<_> = result.warnings.iter()
+        .filter(|w| matches!(w, PacingWarning::SilenceRatioOutOfRange { .. }))
+        .collect();
+    match &ratio_warnings[..] {
+        [PacingWarning::SilenceRatioOutOfRange { ratio, suggested_target_seconds, suggested_word_count, .. }] => {
+            assert!(*ratio > 0.9);
+            assert!(*suggested_target_seconds < 1200.0);
+            assert!(*suggested_word_count > result.total_words);
+        }
+        other => panic!("unexpected warnings: {:?}", other),
+    }
+    
+    // A balanced session is left alone
+    let balanced = pacer.calculate_pacing(text.clone(), 120.0);
+    assert!(!balanced.warnings.iter().any(|w| matches!(w, PacingWarning::SilenceRatioOutOfRange { .. })));
+    
+    // Strict mode turns the warning into an error
+    let mut config = PacingConfig::default();
+    config.silence_ratio_limits.strict = true;
+    let strict = MeditationPacer::with_config(config);
+    assert!(strict.calculate_pacing_checked(text.clone(), 1200.0).is_err());
+    assert!(strict.calculate_pacing_checked(text, 120.0).is_ok());
+}
+
+#[test]
+fn test_repace_with_measured() {
+    let text = "Welcome. Close your eyes. Breathe in, and out. Rest.";
+    let first = calculate_pacing_details(text.to_string(), 60.0);
+    
+    // The voice actually took 8 seconds
+    let second = repace_with_measured(text.to_string(), 8.0, 60.0);
+    assert_eq!(second.estimated_speech_seconds, 8.0);
+    assert!((second.final_silence_budget - 52.0).abs() < 1e-9);
+    assert!((second.estimated_total_seconds - 60.0).abs() < 1e-9);
+    assert_ne!(first.ssml, second.ssml);
+    
+    // Targets are limited as for the first pass
+    let mut config = PacingConfig::default();
+    config.target_limits.clamp = true;
+    let pacer = MeditationPacer::with_config(config);
+    for (target, paced) in [(2.0, MIN_TARGET_SECONDS), (f64::INFINITY, MAX_TARGET_SECONDS)] {
+        let result = pacer.repace_with_measured(text.to_string(), 8.0, target);
+        assert_eq!(result.target_duration_seconds, paced);
+        assert!(result.estimated_total_seconds.is_finite());
+        assert!(result.warnings.iter().any(|w| matches!(
+            w,
+            PacingWarning::TargetOutOfRange { paced_seconds, .. } if *paced_seconds == paced
+        )));
+    }
+}
+
+#[test]
+fn test_target_limits() {
+    let text = "Breathe in. Let go.".to_string();
+    let result = MeditationPacer::new().calculate_pacing(text.clone(), 2.0);
+    assert!(result.warnings.contains(&PacingWarning::TargetOutOfRange {
+        target_seconds: 2.0,
+        min_seconds: MIN_TARGET_SECONDS,
+        max_seconds: MAX_TARGET_SECONDS,
+        paced_seconds: 2.0,
+    }));
+    
+    let mut config = PacingConfig::default();
+    config.target_limits.clamp = true;
+    let clamped = MeditationPacer::with_config(config).calculate_pacing(text.clone(), 2.0);
+    assert!(clamped.warnings.iter().any(|w| matches!(
+        w,
+        PacingWarning::TargetOutOfRange { paced_seconds, .. } if *paced_seconds == MIN_TARGET_SECONDS
+    )));
+    assert!(clamped.total_silence_added > result.total_silence_added);
+    
+    // The overrun check uses the clamped target
+    let config = PacingConfig {
+        target_limits: TargetDurationLimits { min_seconds: 0.5, max_seconds: 1.0, clamp: true },
+        ..PacingConfig::default()
+    };
+    assert!(matches!(
+        MeditationPacer::with_config(config).try_calculate_pacing(text.clone(), 20.0),
+        Err(PacingError::TextTooLongForTarget { target_seconds, .. }) if target_seconds == 1.0
+    ));
+    
+    let pacer = MeditationPacer::new();
+    assert!(pacer.calculate_pacing_minutes(text.clone(), 1.0).is_ok());
+    assert_eq!(
+        pacer.calculate_pacing_minutes(text.clone(), 240.0).unwrap_err(),
+        PacingError::TargetOutOfRange {
+            target_seconds: 14_400.0,
+            min_seconds: MIN_TARGET_SECONDS,
+            max_seconds: MAX_TARGET_SECONDS,
+        }
+    );
+    assert_eq!(pacer.calculate_pacing_minutes(text, -1.0).unwrap_err(), PacingError::NegativeDuration(-60.0));
+    
+    let config = PacingConfig {
+        target_limits: TargetDurationLimits { min_seconds: 60.0, max_seconds: 30.0, clamp: false },
+        ..PacingConfig::default()
+    };
+    assert_eq!(
+        config.validate(),
+        Err(vec![ConfigError::InvalidTargetLimits { min_seconds: 60.0, max_seconds: 30.0 }])
+    );
+}
+
+#[test]
+fn test_non_finite_targets() {
+    let text = "Breathe in. Let go.".to_string();
+    let pacer = MeditationPacer::new();
+    for (target, paced) in [
+        (f64::INFINITY, MAX_TARGET_SECONDS),
+        (f64::NEG_INFINITY, MIN_TARGET_SECONDS),
+        (f64::NAN, MIN_TARGET_SECONDS),
+    ] {
+        let result = pacer.calculate_pacing(text.clone(), target);
+        assert!(result.warnings.iter().any(|w| matches!(
+            w,
+            PacingWarning::TargetOutOfRange { paced_seconds, .. } if *paced_seconds == paced
+        )), "{}", target);
+        assert!(result.estimated_total_seconds.is_finite());
+        assert!(result.estimated_total_seconds <= MAX_TARGET_SECONDS * 1.2);
+    }
+    
+    let config = PacingConfig {
+        target_limits: TargetDurationLimits { min_seconds: 5.0, max_seconds: f64::INFINITY, clamp: false },
+        ..PacingConfig::default()
+    };
+    assert_eq!(
+        config.validate(),
+        Err(vec![ConfigError::InvalidTargetLimits { min_seconds: 5.0, max_seconds: f64::INFINITY }])
+    );
+}
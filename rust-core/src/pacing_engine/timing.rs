@@ -0,0 +1,341 @@
+//! Speech and Silence Timing
+//! 
+//! Steps B-C of the pipeline: speech per atom under the duration model,
+//! then the silence budget distributed over atoms by weight, curve,
+//! anchors, jitter, and overrides.
+
+use super::*;
+
+// ============================================
+// Types
+// ============================================
+
+/// Speech duration of the same text under each duration model
+/// 
+/// Reported alongside every result so models can be compared against
+/// measured audio. Uses normal speaking rate (no prosody ramp).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SpeechEstimates {
+    /// Characters at `chars_per_second`
+    pub characters: f64,
+    /// Syllables at `syllables_per_second`
+    pub syllables: f64,
+    /// Words at the configured WPM (or 140 WPM if the model isn't WPM-based)
+    pub words: f64,
+}
+
+// ============================================
+// Speech and Silence
+// ============================================
+
+impl MeditationPacer {
+    /// Steps B-C: per-atom speech, per-atom pauses, and the raw and
+    /// buffered silence budgets
+    pub(crate) fn plan_pauses(
+        &self,
+        atoms: &[SpeechAtom],
+        target_duration_seconds: f64,
+        overrides: &PacingOverrides,
+    ) -> (Vec<f64>, Vec<f64>, f64, f64) {
+        // Estimate speech time using character-based formula
+        // (characters excluding whitespace; production data: 12 chars/sec)
+        let speech = self.speech_seconds(atoms);
+        let estimated_speech_seconds: f64 = speech.iter().sum();
+        
+        // Step B: Calculate silence budget with safety buffer (the intro
+        // and outro pauses come out of the target first)
+        let raw_silence_budget = (target_duration_seconds - estimated_speech_seconds - self.framing_pauses()).max(0.0);
+        let final_silence_budget = raw_silence_budget * self.config.silence_safety_buffer;
+        
+        // Step C: Distribute silence across atoms
+        let anchors = overrides.resolve_anchors(atoms);
+        let pauses = if anchors.is_empty() {
+            self.distribute_silence(atoms, final_silence_budget, overrides)
+        } else {
+            self.distribute_anchored(atoms, &anchors, target_duration_seconds - self.outro_pause(), overrides)
+        };
+        
+        (speech, pauses, raw_silence_budget, final_silence_budget)
+    }
+
+    /// Estimated speech duration of each atom in seconds
+    /// 
+    /// Uses the configured `DurationModel` (characters by default), slowed
+    /// by the prosody ramp if one is configured.
+    pub fn speech_seconds(&self, atoms: &[SpeechAtom]) -> Vec<f64> {
+        let progress = atom_start_progress(atoms);
+        atoms.iter()
+            .zip(&progress)
+            .map(|(atom, p)| {
+                let rate = self.config.prosody_ramp.map_or(1.0, |ramp| ramp.rate_at(*p));
+                self.base_speech_seconds(atom) / rate
+            })
+            .collect()
+    }
+
+    /// Speech duration of one atom at normal rate under the duration model
+    fn base_speech_seconds(&self, atom: &SpeechAtom) -> f64 {
+        match self.config.duration_model {
+            DurationModel::Characters => atom.char_count() as f64 / self.config.effective_chars_per_second(),
+            DurationModel::Syllables => atom.syllable_count() as f64 / self.config.syllables_per_second,
+            DurationModel::WordsPerMinute(wpm) => self.spoken_word_count(&atom.text) as f64 * 60.0 / wpm,
+        }
+    }
+
+    /// Estimate speech for atoms under every duration model
+    pub fn speech_estimates(&self, atoms: &[SpeechAtom]) -> SpeechEstimates {
+        let chars: usize = atoms.iter().map(|a| a.char_count()).sum();
+        let syllables: usize = atoms.iter().map(|a| a.syllable_count()).sum();
+        let words: usize = atoms.iter().map(|a| self.spoken_word_count(&a.text)).sum();
+        let wpm = match self.config.duration_model {
+            DurationModel::WordsPerMinute(wpm) => wpm,
+            _ => SPEECH_WORDS_PER_MINUTE,
+        };
+        SpeechEstimates {
+            characters: chars as f64 / self.config.effective_chars_per_second(),
+            syllables: syllables as f64 / self.config.syllables_per_second,
+            words: words as f64 * 60.0 / wpm,
+        }
+    }
+
+    /// Pause after each atom for a target duration (the last is always 0)
+    /// 
+    /// The distribution step of `calculate_pacing` on its own, for atoms
+    /// from `atomize`; pair with `render_atoms` to build the SSML.
+    pub fn distribute_pauses(&self, atoms: &[SpeechAtom], target_duration_seconds: f64) -> Vec<f64> {
+        self.plan_pauses(atoms, target_duration_seconds, &PacingOverrides::new()).1
+    }
+
+    /// Compute the pause (in seconds) that follows each atom
+    /// 
+    /// Pinned pauses from `overrides` are subtracted from the budget first,
+    /// and the remainder is shared by weight (scaled by the progressive
+    /// curve, if configured). The last atom never gets a pause.
+    pub(super) fn distribute_silence(
+        &self,
+        atoms: &[SpeechAtom],
+        silence_budget: f64,
+        overrides: &PacingOverrides,
+    ) -> Vec<f64> {
+        self.distribute_silence_over(atoms, silence_budget, overrides, atoms.len().saturating_sub(1))
+    }
+
+    /// Distribute silence over the first `breakable` atoms
+    /// 
+    /// Pass `atoms.len()` to also give the final atom a pause (used when
+    /// more content follows, e.g. at a section boundary).
+    pub(crate) fn distribute_silence_over(
+        &self,
+        atoms: &[SpeechAtom],
+        silence_budget: f64,
+        overrides: &PacingOverrides,
+        breakable: usize,
+    ) -> Vec<f64> {
+        let progress = atom_progress(atoms);
+        let resolved = resolve_overrides(atoms, overrides);
+        self.allocate_silence(atoms, &progress, &resolved, silence_budget, breakable)
+    }
+
+    /// Distribute silence so each anchored atom starts at its pinned time
+    /// 
+    /// The script is cut at every anchor. Each segment ending in an anchor
+    /// gets exactly the silence needed to reach it (no safety buffer, so the
+    /// estimate lands on the pin); the tail after the last anchor gets the
+    /// buffered remainder of the target. Anchor times count from the start
+    /// of playback, so the first segment starts after the intro pause.
+    pub(super) fn distribute_anchored(
+        &self,
+        atoms: &[SpeechAtom],
+        anchors: &[(usize, f64)],
+        target_duration_seconds: f64,
+        overrides: &PacingOverrides,
+    ) -> Vec<f64> {
+        let progress = atom_progress(atoms);
+        let resolved = resolve_overrides(atoms, overrides);
+        let speech = self.speech_seconds(atoms);
+        
+        let mut pauses = Vec::with_capacity(atoms.len());
+        let mut start = 0;
+        let mut start_time = self.intro_pause();
+        
+        for &(anchor, anchor_time) in anchors {
+            let segment_speech: f64 = speech[start..anchor].iter().sum();
+            let budget = (anchor_time - start_time - segment_speech).max(0.0);
+            pauses.extend(self.allocate_silence(
+                &atoms[start..anchor],
+                &progress[start..anchor],
+                &resolved[start..anchor],
+                budget,
+                anchor - start,
+            ));
+            start = anchor;
+            start_time = anchor_time;
+        }
+        
+        let tail_speech: f64 = speech[start..].iter().sum();
+        let tail_budget = (target_duration_seconds - start_time - tail_speech).max(0.0)
+            * self.config.silence_safety_buffer;
+        pauses.extend(self.allocate_silence(
+            &atoms[start..],
+            &progress[start..],
+            &resolved[start..],
+            tail_budget,
+            atoms.len() - start - 1,
+        ));
+        
+        pauses
+    }
+
+    /// Core allocation: pins first, then the remainder by (shaped) weight
+    /// 
+    /// Pauses that would fall short of their punctuation's floor are fixed
+    /// at the floor before the rest is shared out.
+    /// 
+    /// `progress` and `resolved` are parallel to `atoms`; only the first
+    /// `breakable` atoms can receive a pause.
+    fn allocate_silence(
+        &self,
+        atoms: &[SpeechAtom],
+        progress: &[f64],
+        resolved: &[Option<AtomOverride>],
+        silence_budget: f64,
+        breakable: usize,
+    ) -> Vec<f64> {
+        let mut pauses = vec![0.0; atoms.len()];
+        
+        let mut weights = vec![0.0; atoms.len()];
+        let mut floors = vec![0.0; atoms.len()];
+        let mut pinned_total = 0.0;
+        for (i, atom) in atoms.iter().enumerate().take(breakable) {
+            let base_weight = match resolved[i] {
+                Some(AtomOverride::Pause(seconds)) => {
+                    pauses[i] = seconds.max(0.0);
+                    pinned_total += pauses[i];
+                    continue;
+                }
+                Some(AtomOverride::Weight(weight)) => weight,
+                None => match self.config.allocation {
+                    AllocationStrategy::Weighted => atom.effective_weight(),
+                    AllocationStrategy::EqualPerSentence => u32::from(atom.punctuation.ends_sentence()),
+                },
+            };
+            let multiplier = self.config.progression.as_ref()
+                .map_or(1.0, |curve| curve.multiplier_at(progress[i]));
+            let jitter = self.config.jitter
+                .map_or(1.0, |jitter| jitter.factor(progress[i]));
+            weights[i] = base_weight as f64 * multiplier * jitter;
+            floors[i] = self.config.pause_floor_for(atom.punctuation);
+        }
+        
+        let heaviest = weights.iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .filter(|(_, w)| **w > 0.0)
+            .map(|(i, _)| i);
+        let mut floored_total = 0.0;
+        
+        loop {
+            // Calculate time per weight unit over whatever the pins and
+            // floors left over
+            let remaining = (silence_budget - pinned_total - floored_total).max(0.0);
+            let total_weight: f64 = weights.iter().sum();
+            if total_weight > 0.0 {
+                let time_per_unit = remaining / total_weight;
+                for (pause, weight) in pauses.iter_mut().zip(&weights) {
+                    if *weight > 0.0 {
+                        *pause = *weight * time_per_unit;
+                    }
+                }
+            }
+            
+            // Pauses short of their floor are fixed at it, and the rest
+            // shared again among the others
+            let mut floored_any = false;
+            for i in 0..atoms.len() {
+                if floors[i] > 0.0 && pauses[i] < floors[i] {
+                    pauses[i] = core::mem::take(&mut floors[i]);
+                    weights[i] = 0.0;
+                    floored_total += pauses[i];
+                    floored_any = true;
+                }
+            }
+            if floored_any {
+                continue;
+            }
+            if total_weight <= 0.0 || !self.config.redistribute_dropped_silence {
+                break;
+            }
+            
+            // Breaks below the minimum would be dropped at render time;
+            // take them out and share their time among the survivors.
+            // Survivors only grow, so this settles in a few passes.
+            let mut dropped_any = false;
+            for (pause, weight) in pauses.iter_mut().zip(weights.iter_mut()) {
+                if *weight > 0.0 && *pause < self.config.min_break_seconds {
+                    *pause = 0.0;
+                    *weight = 0.0;
+                    dropped_any = true;
+                }
+            }
+            if !dropped_any {
+                break;
+            }
+            
+            // Everything fell below the minimum: spend it all in one break
+            if weights.iter().all(|w| *w <= 0.0) {
+                if let Some(i) = heaviest {
+                    pauses[i] += remaining;
+                }
+                break;
+            }
+        }
+        
+        pauses
+    }
+}
+
+// ============================================
+// Helper Functions
+// ============================================
+
+/// Resolve the override (if any) for every atom
+fn resolve_overrides(atoms: &[SpeechAtom], overrides: &PacingOverrides) -> Vec<Option<AtomOverride>> {
+    atoms.iter()
+        .enumerate()
+        .map(|(i, atom)| overrides.resolve(i, atom))
+        .collect()
+}
+
+/// Fraction of the script's characters spoken before each atom starts
+pub(super) fn atom_start_progress(atoms: &[SpeechAtom]) -> Vec<f64> {
+    let ends = atom_progress(atoms);
+    let mut starts = Vec::with_capacity(ends.len());
+    if !ends.is_empty() {
+        starts.push(0.0);
+        starts.extend_from_slice(&ends[..ends.len() - 1]);
+    }
+    starts
+}
+
+/// SplitMix64 step: a small, well-distributed hash for seeded jitter
+pub(crate) fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Fraction of the script's characters spoken by the end of each atom
+fn atom_progress(atoms: &[SpeechAtom]) -> Vec<f64> {
+    let total: usize = atoms.iter().map(|a| a.char_count()).sum();
+    let mut spoken = 0;
+    atoms.iter()
+        .map(|a| {
+            spoken += a.char_count();
+            if total > 0 { spoken as f64 / total as f64 } else { 0.0 }
+        })
+        .collect()
+}
@@ -0,0 +1,69 @@
+//! Crate Prelude
+//! 
+//! What the modules of this crate would otherwise get from the `std`
+//! prelude, taken from `alloc` so the same code builds with `#![no_std]`.
+//! Without `std`, maps come from `hashbrown` and the `f64` methods `std`
+//! adds (`round`, `powf`, ...) from `libm`, through `Float`.
+
+pub use alloc::string::{String, ToString};
+pub use alloc::vec::Vec;
+pub use alloc::{format, vec};
+
+#[cfg(feature = "std")]
+pub use std::collections::HashMap;
+
+#[cfg(not(feature = "std"))]
+pub use hashbrown::HashMap;
+
+/// The `f64` methods `std` provides, computed with `libm`
+// Unused by `no_std` test builds, where the test harness links `std` and
+// its inherent `f64` methods take precedence
+#[cfg(not(feature = "std"))]
+#[cfg_attr(test, allow(dead_code))]
+pub trait Float {
+    /// Round half away from zero
+    fn round(self) -> Self;
+    /// Largest integer not above `self`
+    fn floor(self) -> Self;
+    /// Smallest integer not below `self`
+    fn ceil(self) -> Self;
+    /// Square root
+    fn sqrt(self) -> Self;
+    /// `e` raised to `self`
+    fn exp(self) -> Self;
+    /// `self` raised to a float power
+    fn powf(self, n: Self) -> Self;
+    /// `self` raised to an integer power
+    fn powi(self, n: i32) -> Self;
+}
+
+#[cfg(not(feature = "std"))]
+impl Float for f64 {
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+
+    fn ceil(self) -> f64 {
+        libm::ceil(self)
+    }
+
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    fn exp(self) -> f64 {
+        libm::exp(self)
+    }
+
+    fn powf(self, n: f64) -> f64 {
+        libm::pow(self, n)
+    }
+
+    fn powi(self, n: i32) -> f64 {
+        libm::pow(self, f64::from(n))
+    }
+}
@@ -0,0 +1,271 @@
+//! LLM Prompt Builder
+//! 
+//! Assembles the generation prompt for a meditation script so every
+//! consumer asks the LLM for the same thing: the right number of words for
+//! the target duration, enough punctuation for the pacer to place pauses,
+//! no formatting the pacer would read aloud, and an optional section
+//! structure with per-section word counts.
+//! 
+//! The word target is the same as `calculate_target_words_for_prompt`
+//! (70 words per minute by default, for a 50/50 speech-to-silence ratio).
+//! 
+//! ## Token Estimates
+//! 
+//! For setting `max_tokens` and budgeting API cost, the builder estimates
+//! tokens from words with a configurable factor (about 1.33 tokens per
+//! English word for GPT-style tokenizers; raise it for other languages).
+//! `max_tokens` adds headroom so a slightly long script is not cut off
+//! mid-sentence.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::prompt::PromptBuilder;
+//! 
+//! let prompt = PromptBuilder::new(300.0)
+//!     .topic("letting go of the workday")
+//!     .section("intro", 1.0, "Welcome the listener and invite them to settle.")
+//!     .section("body", 3.0, "Guide slow breathing with a body scan.")
+//!     .build();
+//! assert!(prompt.contains("about 350 words"));
+//! assert!(prompt.contains("intro (about 88 words)"));
+//! ```
+
+use crate::pacing_engine::{calculate_target_words_custom, TARGET_WORDS_PER_MINUTE};
+use crate::prelude::*;
+
+/// Longest sentence (in words) the prompt asks for by default
+const DEFAULT_MAX_WORDS_PER_SENTENCE: usize = 15;
+
+/// Tokens per English word for GPT-style tokenizers
+pub const DEFAULT_TOKENS_PER_WORD: f64 = 1.33;
+
+/// Headroom `max_tokens` leaves over the estimated script length
+const MAX_TOKENS_HEADROOM: f64 = 1.25;
+
+/// Characters per token, for estimating the prompt itself
+const CHARS_PER_TOKEN: f64 = 4.0;
+
+/// Patterns every script prompt forbids: anything the pacer or the TTS
+/// voice would read aloud or mis-time
+const DEFAULT_FORBIDDEN: [&str; 5] = [
+    "Markdown or other formatting (headings, bold, bullet or numbered lists)",
+    "SSML, HTML, or any other markup tags",
+    "Stage directions or pause notes such as [pause] or (breathe)",
+    "Emoji",
+    "A title, a sign-off, or any text that is not spoken to the listener",
+];
+
+// ============================================
+// Types
+// ============================================
+
+/// One part of the requested script structure
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptSection {
+    /// Section name shown to the LLM (e.g. "intro")
+    pub name: String,
+    /// Relative share of the total word count
+    pub share: f64,
+    /// What the section should do
+    pub guidance: String,
+}
+
+/// Builder for a script generation prompt
+#[derive(Debug, Clone)]
+pub struct PromptBuilder {
+    /// Target session length in seconds
+    pub target_duration_seconds: f64,
+    /// Word density (words per minute of session, silence included)
+    pub words_per_minute: f64,
+    /// What the meditation is about
+    pub topic: Option<String>,
+    /// Longest sentence to ask for, in words
+    pub max_words_per_sentence: usize,
+    /// Things the script must not contain
+    pub forbidden: Vec<String>,
+    /// Requested structure, in order (free-form when empty)
+    pub sections: Vec<PromptSection>,
+    /// Tokens per generated word, for token estimates
+    pub tokens_per_word: f64,
+}
+
+impl PromptBuilder {
+    /// A prompt for a session of `target_duration_seconds` with the default
+    /// density and forbidden patterns
+    pub fn new(target_duration_seconds: f64) -> Self {
+        Self {
+            target_duration_seconds,
+            words_per_minute: TARGET_WORDS_PER_MINUTE,
+            topic: None,
+            max_words_per_sentence: DEFAULT_MAX_WORDS_PER_SENTENCE,
+            forbidden: DEFAULT_FORBIDDEN.iter().map(|s| s.to_string()).collect(),
+            sections: Vec::new(),
+            tokens_per_word: DEFAULT_TOKENS_PER_WORD,
+        }
+    }
+
+    /// Set the topic of the meditation
+    pub fn topic(mut self, topic: &str) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+
+    /// Override the 70 words-per-minute density
+    pub fn words_per_minute(mut self, words_per_minute: f64) -> Self {
+        self.words_per_minute = words_per_minute;
+        self
+    }
+
+    /// Set the longest sentence to ask for
+    pub fn max_words_per_sentence(mut self, words: usize) -> Self {
+        self.max_words_per_sentence = words;
+        self
+    }
+
+    /// Forbid another pattern
+    pub fn forbid(mut self, pattern: &str) -> Self {
+        self.forbidden.push(pattern.to_string());
+        self
+    }
+
+    /// Override the tokens-per-word factor used for estimates
+    pub fn tokens_per_word(mut self, tokens_per_word: f64) -> Self {
+        self.tokens_per_word = tokens_per_word;
+        self
+    }
+
+    /// Append a section with a relative share of the words
+    pub fn section(mut self, name: &str, share: f64, guidance: &str) -> Self {
+        self.sections.push(PromptSection {
+            name: name.to_string(),
+            share,
+            guidance: guidance.to_string(),
+        });
+        self
+    }
+
+    /// Total words to request
+    pub fn target_words(&self) -> usize {
+        calculate_target_words_custom(self.target_duration_seconds, self.words_per_minute)
+    }
+
+    /// Estimated tokens in the generated script
+    pub fn estimated_output_tokens(&self) -> usize {
+        estimate_tokens(self.target_words(), self.tokens_per_word)
+    }
+
+    /// `max_tokens` to send with the request (estimate plus headroom)
+    pub fn max_tokens(&self) -> usize {
+        (self.estimated_output_tokens() as f64 * MAX_TOKENS_HEADROOM).ceil() as usize
+    }
+
+    /// Estimated tokens in the prompt itself
+    pub fn estimated_prompt_tokens(&self) -> usize {
+        (self.build().chars().count() as f64 / CHARS_PER_TOKEN).ceil() as usize
+    }
+
+    /// Words to request for each section, in order
+    pub fn section_words(&self) -> Vec<usize> {
+        let total_share: f64 = self.sections.iter().map(|s| s.share.max(0.0)).sum();
+        if total_share <= 0.0 {
+            return vec![0; self.sections.len()];
+        }
+        let total = self.target_words() as f64;
+        self.sections.iter()
+            .map(|s| (total * s.share.max(0.0) / total_share).round() as usize)
+            .collect()
+    }
+
+    /// The assembled prompt
+    pub fn build(&self) -> String {
+        let mut prompt = String::new();
+        
+        let minutes = self.target_duration_seconds / 60.0;
+        prompt.push_str(&format!(
+            "Write a guided meditation script of about {} words, to be read aloud \
+             slowly over {:.0} minutes with long silences between phrases.",
+            self.target_words(),
+            minutes.max(1.0),
+        ));
+        if let Some(topic) = &self.topic {
+            prompt.push_str(&format!(" The meditation is about {}.", topic));
+        }
+        
+        prompt.push_str("\n\nPacing:\n");
+        prompt.push_str(&format!(
+            "- Keep sentences under {} words; end every phrase with punctuation\n",
+            self.max_words_per_sentence,
+        ));
+        prompt.push_str("- Use commas for short rests, periods between thoughts, and a blank line between paragraphs for the longest silences\n");
+        prompt.push_str("- Use ellipses (...) sparingly, for a lingering pause\n");
+        prompt.push_str("- Speak directly to the listener as \"you\" throughout\n");
+        
+        if !self.sections.is_empty() {
+            prompt.push_str("\nStructure, in this order:\n");
+            for (section, words) in self.sections.iter().zip(self.section_words()) {
+                prompt.push_str(&format!("- {} (about {} words): {}\n", section.name, words, section.guidance));
+            }
+        }
+        
+        if !self.forbidden.is_empty() {
+            prompt.push_str("\nDo not include:\n");
+            for pattern in &self.forbidden {
+                prompt.push_str(&format!("- {}\n", pattern));
+            }
+        }
+        
+        prompt.push_str("\nReturn only the script text.");
+        prompt
+    }
+}
+
+/// Estimated LLM tokens for `words` words
+pub fn estimate_tokens(words: usize, tokens_per_word: f64) -> usize {
+    (words as f64 * tokens_per_word.max(0.0)).ceil() as usize
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculate_target_words_for_prompt;
+
+    #[test]
+    fn test_word_targets() {
+        let builder = PromptBuilder::new(600.0)
+            .section("intro", 1.0, "Welcome.")
+            .section("body", 2.0, "Breathe.")
+            .section("closing", 1.0, "Return.");
+        assert_eq!(builder.target_words(), calculate_target_words_for_prompt(600.0));
+        assert_eq!(builder.section_words(), vec![175, 350, 175]);
+        assert_eq!(PromptBuilder::new(600.0).words_per_minute(50.0).target_words(), 500);
+    }
+
+    #[test]
+    fn test_prompt_sections() {
+        let prompt = PromptBuilder::new(120.0)
+            .max_words_per_sentence(10)
+            .forbid("Mentions of sleep")
+            .build();
+        assert!(prompt.starts_with("Write a guided meditation script of about 140 words"));
+        assert!(prompt.contains("under 10 words"));
+        assert!(prompt.contains("- Mentions of sleep\n"));
+        assert!(!prompt.contains("Structure"));
+        assert!(prompt.ends_with("Return only the script text."));
+    }
+
+    #[test]
+    fn test_token_estimates() {
+        let builder = PromptBuilder::new(300.0);
+        assert_eq!(builder.target_words(), 350);
+        assert_eq!(builder.estimated_output_tokens(), 466);
+        assert_eq!(builder.max_tokens(), 583);
+        assert_eq!(builder.clone().tokens_per_word(2.0).estimated_output_tokens(), 700);
+        assert!(builder.estimated_prompt_tokens() > 50);
+        assert_eq!(estimate_tokens(3, 1.5), 5);
+    }
+}
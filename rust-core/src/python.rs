@@ -0,0 +1,260 @@
+//! Python Bindings
+//! 
+//! PyO3 classes so script-authoring notebooks can preview pacing without
+//! shelling out to a CLI. Available with the `python` feature; build the
+//! extension module with maturin from the `bindings` crate
+//! (`maturin build --release --features python`), which enables
+//! `pyo3/extension-module`.
+//! 
+//! ## Python Usage
+//! 
+//! ```text
+//! from zenpal_core import Config, Pacer
+//! 
+//! config = Config.preset("sleep")
+//! config.chars_per_second = 11.0
+//! result = Pacer(config).calculate_pacing(script, 600.0)
+//! print(result.report())
+//! ```
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::pacing_engine::{MeditationPacer, PacingConfig, PacingResult, Preset};
+
+// ============================================
+// Classes
+// ============================================
+
+/// Pacing configuration (`zenpal_core.Config`)
+#[pyclass(name = "Config")]
+#[derive(Clone)]
+pub struct PyConfig {
+    inner: PacingConfig,
+}
+
+#[pymethods]
+impl PyConfig {
+    /// Default configuration
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: PacingConfig::default(),
+        }
+    }
+
+    /// Built-in preset: "sleep", "focus", "breathwork", or "quick_reset"
+    #[staticmethod]
+    fn preset(name: &str) -> PyResult<Self> {
+        let preset = parse_preset(name)
+            .ok_or_else(|| PyValueError::new_err(format!("unknown preset `{}`", name)))?;
+        Ok(Self {
+            inner: PacingConfig::preset(preset),
+        })
+    }
+
+    #[getter]
+    fn chars_per_second(&self) -> f64 {
+        self.inner.effective_chars_per_second()
+    }
+
+    #[setter]
+    fn set_chars_per_second(&mut self, value: f64) {
+        self.inner.chars_per_second = value;
+        self.inner.language = None;
+    }
+
+    #[getter]
+    fn silence_safety_buffer(&self) -> f64 {
+        self.inner.silence_safety_buffer
+    }
+
+    #[setter]
+    fn set_silence_safety_buffer(&mut self, value: f64) {
+        self.inner.silence_safety_buffer = value;
+    }
+
+    #[getter]
+    fn max_break_seconds(&self) -> f64 {
+        self.inner.max_break_seconds
+    }
+
+    #[setter]
+    fn set_max_break_seconds(&mut self, value: f64) {
+        self.inner.max_break_seconds = value;
+    }
+
+    #[getter]
+    fn min_break_seconds(&self) -> f64 {
+        self.inner.min_break_seconds
+    }
+
+    #[setter]
+    fn set_min_break_seconds(&mut self, value: f64) {
+        self.inner.min_break_seconds = value;
+    }
+
+    /// Problems with the config, as messages (empty when valid)
+    fn validate(&self) -> Vec<String> {
+        self.inner.validate()
+            .err()
+            .unwrap_or_default()
+            .iter()
+            .map(|e| e.to_string())
+            .collect()
+    }
+}
+
+/// The pacing engine (`zenpal_core.Pacer`)
+#[pyclass(name = "Pacer")]
+pub struct PyPacer {
+    inner: MeditationPacer,
+}
+
+#[pymethods]
+impl PyPacer {
+    /// Create a pacer; raises `ValueError` for an invalid config
+    #[new]
+    #[pyo3(signature = (config = None))]
+    fn new(config: Option<PyConfig>) -> PyResult<Self> {
+        let config = config.map(|c| c.inner).unwrap_or_default();
+        MeditationPacer::try_with_config(config)
+            .map(|inner| Self { inner })
+            .map_err(|errors| {
+                let reasons: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                PyValueError::new_err(reasons.join("; "))
+            })
+    }
+
+    /// Format meditation text into SSML with calculated breaks
+    fn format_meditation_ssml(&self, text: String, target_duration_seconds: f64) -> String {
+        self.inner.format_meditation_ssml(text, target_duration_seconds)
+    }
+
+    /// Calculate pacing and return detailed results
+    fn calculate_pacing(&self, text: String, target_duration_seconds: f64) -> PyPacingResult {
+        PyPacingResult {
+            inner: self.inner.calculate_pacing(text, target_duration_seconds),
+        }
+    }
+}
+
+/// A pacing result (`zenpal_core.Result`)
+#[pyclass(name = "Result")]
+pub struct PyPacingResult {
+    inner: PacingResult,
+}
+
+#[pymethods]
+impl PyPacingResult {
+    #[getter]
+    fn ssml(&self) -> String {
+        self.inner.ssml.clone()
+    }
+
+    #[getter]
+    fn estimated_speech_seconds(&self) -> f64 {
+        self.inner.estimated_speech_seconds
+    }
+
+    #[getter]
+    fn total_silence_added(&self) -> f64 {
+        self.inner.total_silence_added
+    }
+
+    #[getter]
+    fn estimated_total_seconds(&self) -> f64 {
+        self.inner.estimated_total_seconds
+    }
+
+    #[getter]
+    fn target_duration_seconds(&self) -> f64 {
+        self.inner.target_duration_seconds
+    }
+
+    #[getter]
+    fn atom_count(&self) -> usize {
+        self.inner.atom_count
+    }
+
+    /// `(start_seconds, text, speech_seconds, pause_seconds)` per atom
+    #[getter]
+    fn timeline(&self) -> Vec<(f64, String, f64, f64)> {
+        self.inner.timeline.iter()
+            .map(|e| (e.start_seconds, e.text.clone(), e.speech_seconds, e.pause_seconds))
+            .collect()
+    }
+
+    /// Formatted per-atom breakdown with totals
+    fn report(&self) -> String {
+        self.inner.report()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Result(atoms={}, estimated={:.1}s, target={:.1}s)",
+            self.inner.atom_count, self.inner.estimated_total_seconds, self.inner.target_duration_seconds,
+        )
+    }
+}
+
+// ============================================
+// Module
+// ============================================
+
+/// The `zenpal_core` Python module
+#[pymodule]
+fn zenpal_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyConfig>()?;
+    m.add_class::<PyPacer>()?;
+    m.add_class::<PyPacingResult>()?;
+    Ok(())
+}
+
+/// Preset from its Python name
+fn parse_preset(name: &str) -> Option<Preset> {
+    match name {
+        "sleep" => Some(Preset::Sleep),
+        "focus" => Some(Preset::Focus),
+        "breathwork" => Some(Preset::Breathwork),
+        "quick_reset" => Some(Preset::QuickReset),
+        _ => None,
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_preset() {
+        assert_eq!(parse_preset("quick_reset"), Some(Preset::QuickReset));
+        assert_eq!(parse_preset("Sleep"), None);
+    }
+
+    #[test]
+    fn test_invalid_config_is_rejected() {
+        let mut config = PyConfig::new();
+        assert!(config.validate().is_empty());
+        assert!(PyPacer::new(Some(config.clone())).is_ok());
+
+        config.set_chars_per_second(-1.0);
+        assert_eq!(config.validate().len(), 1);
+        assert!(PyPacer::new(Some(config)).is_err());
+    }
+
+    #[test]
+    fn test_result_timeline() {
+        let pacer = PyPacer::new(None).unwrap();
+        let result = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+        let timeline = result.timeline();
+
+        assert_eq!(timeline.len(), result.atom_count());
+        assert_eq!(timeline[0].1, result.inner.timeline[0].text);
+        assert!(result.__repr__().starts_with("Result(atoms=2,"));
+    }
+}
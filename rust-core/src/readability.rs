@@ -0,0 +1,235 @@
+//! Calm-Language Readability
+//! 
+//! Scores how soothing a script reads, for gating LLM output before it is
+//! paced and synthesized. Four measures each contribute up to 25 points of
+//! a 0-100 score:
+//! 
+//! - **Sentence length**: short sentences (10 words or fewer on average)
+//!   score full marks, falling to nothing at 25 words
+//! - **Syllables per word**: plain words (1.4 or fewer) score full marks,
+//!   falling to nothing at 2.0
+//! - **Imperative density**: some instructions ("Breathe in.") are
+//!   expected, but a script where more than 60% of sentences are commands
+//!   starts to sound like a drill; nothing is left at 100%
+//! - **Punctuation rhythm**: 2 to 8 words between pause points scores full
+//!   marks, falling to nothing at 20 (no room to breathe) and to half at 1
+//!   (choppy)
+//! 
+//! Each measure that scores under 20 of its 25 points adds a suggestion.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::readability::soothing_profile;
+//! 
+//! let profile = soothing_profile("Breathe in. The air is cool, and soft. Let your shoulders rest.");
+//! assert!(profile.score >= 90);
+//! assert!(profile.suggestions.is_empty());
+//! ```
+
+use crate::lint::{split_inclusive_at, words};
+use crate::pacing_engine::count_syllables;
+use crate::prelude::*;
+
+/// Points each measure contributes
+const MEASURE_POINTS: f64 = 25.0;
+
+/// Measures under this many points add a suggestion
+const SUGGESTION_THRESHOLD: f64 = 20.0;
+
+/// Characters that end a sentence
+const SENTENCE_END_CHARS: [char; 4] = ['.', '!', '?', '…'];
+
+/// Characters the pacer can place a pause after
+const PAUSE_CHARS: [char; 9] = ['.', ',', ';', ':', '!', '?', '—', '–', '…'];
+
+/// Verbs that open an instruction
+const IMPERATIVE_VERBS: [&str; 29] = [
+    "allow", "bring", "breathe", "close", "drop", "exhale", "feel", "find", "focus",
+    "hold", "imagine", "inhale", "let", "lie", "listen", "notice", "observe",
+    "open", "picture", "relax", "release", "rest", "return", "settle", "sit",
+    "soften", "stay", "take", "turn",
+];
+
+/// Softeners that can come before an imperative verb ("Now gently breathe")
+const LEADING_SOFTENERS: [&str; 7] = ["and", "gently", "just", "now", "simply", "slowly", "softly"];
+
+// ============================================
+// Types
+// ============================================
+
+/// How soothing a script reads
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoothingProfile {
+    /// Overall score, 0 (jarring) to 100 (soothing)
+    pub score: u32,
+    /// Average words per sentence
+    pub average_sentence_words: f64,
+    /// Average syllables per word
+    pub syllables_per_word: f64,
+    /// Share of sentences that open with an instruction (0.0 to 1.0)
+    pub imperative_density: f64,
+    /// Average words between pause points
+    pub words_per_pause: f64,
+    /// What to change to raise the score
+    pub suggestions: Vec<String>,
+}
+
+// ============================================
+// Scoring
+// ============================================
+
+/// Score a script's soothing profile
+pub fn soothing_profile(text: &str) -> SoothingProfile {
+    let word_list = words(text, 0);
+    if word_list.is_empty() {
+        return SoothingProfile {
+            score: 0,
+            average_sentence_words: 0.0,
+            syllables_per_word: 0.0,
+            imperative_density: 0.0,
+            words_per_pause: 0.0,
+            suggestions: vec!["The script has no words.".to_string()],
+        };
+    }
+    let word_count = word_list.len() as f64;
+
+    let sentences: Vec<Vec<&str>> = split_inclusive_at(text, |c| SENTENCE_END_CHARS.contains(&c) || c == '\n')
+        .into_iter()
+        .map(|(_, s)| words(s, 0).into_iter().map(|w| w.text).collect::<Vec<&str>>())
+        .filter(|s| !s.is_empty())
+        .collect();
+    let pause_runs = split_inclusive_at(text, |c| PAUSE_CHARS.contains(&c) || c == '\n')
+        .into_iter()
+        .filter(|(_, s)| !words(s, 0).is_empty())
+        .count();
+
+    let average_sentence_words = word_count / sentences.len() as f64;
+    let syllables_per_word = word_list.iter().map(|w| count_syllables(w.text)).sum::<usize>() as f64 / word_count;
+    let imperative_density = sentences.iter().filter(|s| is_imperative(s)).count() as f64 / sentences.len() as f64;
+    let words_per_pause = word_count / pause_runs.max(1) as f64;
+
+    let measures = [
+        (
+            falloff(average_sentence_words, 10.0, 25.0),
+            format!(
+                "Shorten sentences: they average {:.1} words; aim for 10 or fewer.",
+                average_sentence_words
+            ),
+        ),
+        (
+            falloff(syllables_per_word, 1.4, 2.0),
+            format!(
+                "Use plainer words: they average {:.2} syllables; aim for 1.4 or fewer.",
+                syllables_per_word
+            ),
+        ),
+        (
+            falloff(imperative_density, 0.6, 1.0),
+            format!(
+                "Soften the instructions: {:.0}% of sentences are commands; mix in invitations and descriptions.",
+                imperative_density * 100.0
+            ),
+        ),
+        (
+            rhythm_points(words_per_pause),
+            if words_per_pause < 2.0 {
+                format!(
+                    "Let phrases run longer: a pause point every {:.1} words sounds choppy.",
+                    words_per_pause
+                )
+            } else {
+                format!(
+                    "Add commas or sentence breaks: {:.1} words between pause points; aim for 8 or fewer.",
+                    words_per_pause
+                )
+            },
+        ),
+    ];
+
+    let score = measures.iter().map(|(points, _)| points).sum::<f64>().round() as u32;
+    let suggestions = measures.into_iter()
+        .filter(|(points, _)| *points < SUGGESTION_THRESHOLD)
+        .map(|(_, suggestion)| suggestion)
+        .collect();
+
+    SoothingProfile {
+        score,
+        average_sentence_words,
+        syllables_per_word,
+        imperative_density,
+        words_per_pause,
+        suggestions,
+    }
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Full points up to `ideal`, falling linearly to none at `worst`
+fn falloff(value: f64, ideal: f64, worst: f64) -> f64 {
+    if value <= ideal {
+        MEASURE_POINTS
+    } else {
+        (MEASURE_POINTS * (worst - value) / (worst - ideal)).max(0.0)
+    }
+}
+
+/// Rhythm points: full from 2 to 8 words per pause, half at 1, none at 20
+fn rhythm_points(words_per_pause: f64) -> f64 {
+    if words_per_pause < 2.0 {
+        MEASURE_POINTS * (0.5 + 0.5 * (words_per_pause - 1.0).max(0.0))
+    } else {
+        falloff(words_per_pause, 8.0, 20.0)
+    }
+}
+
+/// Whether a sentence opens with an instruction, skipping softeners
+fn is_imperative(sentence: &[&str]) -> bool {
+    sentence.iter()
+        .map(|w| w.to_lowercase())
+        .find(|w| !LEADING_SOFTENERS.contains(&w.as_str()))
+        .is_some_and(|w| IMPERATIVE_VERBS.contains(&w.as_str()))
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calm_script_scores_high() {
+        let profile = soothing_profile(
+            "Welcome. Find a comfortable place to sit. Now gently breathe in. \
+             The air is cool, and soft. Let it go. There is nowhere else to be.",
+        );
+        assert!(profile.score >= 95, "{:?}", profile);
+        assert!((profile.imperative_density - 0.5).abs() < 1e-9);
+        assert!(profile.suggestions.is_empty());
+    }
+
+    #[test]
+    fn test_dense_clinical_script_scores_low() {
+        let profile = soothing_profile(
+            "Concentrate immediately on physiological respiratory sensations throughout \
+             the abdominal musculature while simultaneously evaluating cardiovascular \
+             indicators and documenting deviations systematically. Focus intensely on \
+             every individual respiratory sensation without interruption or distraction.",
+        );
+        assert!(profile.score < 60, "{:?}", profile);
+        assert_eq!(profile.suggestions.len(), 3, "{:?}", profile.suggestions);
+        assert!(profile.suggestions[0].starts_with("Shorten sentences"));
+    }
+
+    #[test]
+    fn test_empty_and_choppy() {
+        assert_eq!(soothing_profile("   ").score, 0);
+        assert_eq!(rhythm_points(1.0), 12.5);
+        assert_eq!(rhythm_points(5.0), 25.0);
+        assert_eq!(rhythm_points(20.0), 0.0);
+    }
+}
@@ -0,0 +1,129 @@
+//! Pacing Reports
+//! 
+//! A plain-text breakdown of a `PacingResult` for tuning scripts without
+//! listening to the rendered audio: one row per atom with its weight, the
+//! pause after it, and the running clock, followed by totals and how far
+//! the estimate lands from the target.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::MeditationPacer;
+//! 
+//! let result = MeditationPacer::new().calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+//! println!("{}", result.report());
+//! ```
+
+use crate::pacing_engine::PacingResult;
+use crate::prelude::*;
+
+/// Longest text snippet shown per atom before it is truncated
+const SNIPPET_CHARS: usize = 32;
+
+// ============================================
+// Report
+// ============================================
+
+impl PacingResult {
+    /// Render a formatted per-atom breakdown with totals
+    pub fn report(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str(&format!(
+            "{:>4}  {:<width$}  {:>6}  {:>7}  {:>8}\n",
+            "#",
+            "Text",
+            "Weight",
+            "Pause",
+            "Clock",
+            width = SNIPPET_CHARS,
+        ));
+
+        for entry in &self.timeline {
+            out.push_str(&format!(
+                "{:>4}  {:<width$}  {:>6}  {:>6.2}s  {:>8}\n",
+                entry.atom_index + 1,
+                snippet(&entry.text),
+                entry.weight,
+                entry.pause_seconds,
+                format_clock(entry.end_seconds()),
+                width = SNIPPET_CHARS,
+            ));
+        }
+
+        let offset = self.estimated_total_seconds - self.target_duration_seconds;
+        let offset_percent = if self.target_duration_seconds > 0.0 {
+            offset / self.target_duration_seconds * 100.0
+        } else {
+            0.0
+        };
+
+        out.push('\n');
+        out.push_str(&format!(
+            "Speech:   {:>8.2}s  ({} words, {} chars)\n",
+            self.estimated_speech_seconds, self.total_words, self.total_chars,
+        ));
+        out.push_str(&format!(
+            "Silence:  {:>8.2}s  (budget {:.2}s, dropped {:.2}s)\n",
+            self.total_silence_added, self.final_silence_budget, self.dropped_silence_seconds,
+        ));
+        out.push_str(&format!(
+            "Total:    {:>8.2}s  (target {:.2}s, {:+.2}s / {:+.1}%)\n",
+            self.estimated_total_seconds, self.target_duration_seconds, offset, offset_percent,
+        ));
+
+        out
+    }
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Truncate text to the snippet width, marking cuts with an ellipsis
+fn snippet(text: &str) -> String {
+    let text = text.trim();
+    if text.chars().count() <= SNIPPET_CHARS {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(SNIPPET_CHARS - 1).collect();
+    format!("{}…", cut.trim_end())
+}
+
+/// Format seconds as `M:SS.s`
+fn format_clock(seconds: f64) -> String {
+    let tenths = (seconds.max(0.0) * 10.0).round() as u64;
+    format!("{}:{:02}.{}", tenths / 600, (tenths / 10) % 60, tenths % 10)
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MeditationPacer;
+
+    #[test]
+    fn test_report_rows_and_totals() {
+        let pacer = MeditationPacer::new();
+        let text = "Breathe in. Let your shoulders soften and your jaw release completely. Rest.";
+        let result = pacer.calculate_pacing(text.to_string(), 30.0);
+        let report = result.report();
+
+        let lines: Vec<&str> = report.lines().collect();
+        assert!(lines[0].contains("Weight"));
+        assert!(lines[1].contains("Breathe in"));
+        assert!(lines[2].contains('…'));
+        assert!(lines[3].ends_with(&format_clock(result.estimated_total_seconds)));
+        assert!(report.contains("target 30.00s"));
+    }
+
+    #[test]
+    fn test_format_clock() {
+        assert_eq!(format_clock(0.0), "0:00.0");
+        assert_eq!(format_clock(75.36), "1:15.4");
+        assert_eq!(format_clock(59.97), "1:00.0");
+    }
+}
@@ -0,0 +1,220 @@
+//! SSML Round-Trip Check
+//! 
+//! Guards against the atomizer silently eating text: strips the markup from
+//! rendered SSML and confirms it still speaks every word of the script,
+//! once, in order.
+//! 
+//! Both sides are normalized the same way: tags (`<break .../>`,
+//! `<prosody ...>`, `<speak>`) are removed, the five XML entities are
+//! unescaped, and words are runs of letters, digits, and apostrophes, so
+//! whitespace changes and collapsed punctuation ("!!!" rendered as "!") are
+//! not reported.
+//! 
+//! The check does not know about the pacer's config: for configs with
+//! markers or banned words, pass the script with those already removed.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::roundtrip::{verify_roundtrip, RoundtripError};
+//! use zenpal_core::MeditationPacer;
+//! 
+//! let text = "Breathe in... and let go!!";
+//! let result = MeditationPacer::new().calculate_pacing(text.to_string(), 20.0);
+//! assert_eq!(verify_roundtrip(text, &result.ssml), Ok(()));
+//! 
+//! let err = verify_roundtrip(text, "Breathe in... <break time=\"2.0s\"/> let go!").unwrap_err();
+//! assert_eq!(err, RoundtripError::Dropped { index: 2, word: "and".to_string() });
+//! ```
+
+use core::fmt;
+
+use crate::lint::words;
+use crate::prelude::*;
+
+// ============================================
+// Types
+// ============================================
+
+/// The first difference between a script and its rendered SSML
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoundtripError {
+    /// A word of the script is missing from the SSML
+    Dropped {
+        /// Word index in the script
+        index: usize,
+        /// The missing word
+        word: String,
+    },
+    /// The SSML speaks a word the script does not have at this point
+    /// (typically a duplicate)
+    Extra {
+        /// Word index in the SSML
+        index: usize,
+        /// The extra word
+        word: String,
+    },
+    /// The SSML speaks a different word in place of the script's
+    Changed {
+        /// Word index in the script
+        index: usize,
+        /// The script's word
+        expected: String,
+        /// The SSML's word
+        found: String,
+    },
+}
+
+impl fmt::Display for RoundtripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundtripError::Dropped { index, word } => {
+                write!(f, "word {} (\"{}\") is missing from the SSML", index, word)
+            }
+            RoundtripError::Extra { index, word } => {
+                write!(f, "SSML word {} (\"{}\") is not in the script", index, word)
+            }
+            RoundtripError::Changed { index, expected, found } => {
+                write!(f, "word {} is \"{}\" in the script but \"{}\" in the SSML", index, expected, found)
+            }
+        }
+    }
+}
+
+impl core::error::Error for RoundtripError {}
+
+// ============================================
+// Verification
+// ============================================
+
+/// Confirm that `ssml` speaks exactly the words of `original`
+/// 
+/// Returns the first difference found, reading both in order.
+pub fn verify_roundtrip(original: &str, ssml: &str) -> Result<(), RoundtripError> {
+    let expected = spoken_words(original);
+    let found = spoken_words(ssml);
+
+    let Some(i) = (0..expected.len().max(found.len())).find(|i| expected.get(*i) != found.get(*i)) else {
+        return Ok(());
+    };
+    let err = match (expected.get(i), found.get(i)) {
+        (Some(word), None) => RoundtripError::Dropped { index: i, word: word.clone() },
+        (None, Some(word)) => RoundtripError::Extra { index: i, word: word.clone() },
+        (Some(word), Some(_)) if expected.get(i + 1) == found.get(i) => {
+            RoundtripError::Dropped { index: i, word: word.clone() }
+        }
+        (Some(_), Some(word)) if found.get(i + 1) == expected.get(i) => {
+            RoundtripError::Extra { index: i, word: word.clone() }
+        }
+        (expected, found) => RoundtripError::Changed {
+            index: i,
+            expected: expected.cloned().unwrap_or_default(),
+            found: found.cloned().unwrap_or_default(),
+        },
+    };
+    Err(err)
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Words of `text` with markup removed and entities unescaped
+fn spoken_words(text: &str) -> Vec<String> {
+    let plain = unescape_entities(&strip_tags(text));
+    words(&plain, 0).iter().map(|w| w.text.to_string()).collect()
+}
+
+/// Replace every tag (`<name ...>`, `</name>`, `<name/>`) with a space
+/// 
+/// A `<` not followed by a tag name ("a < b") is kept as text.
+fn strip_tags(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('<') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match tag_len(rest) {
+            Some(len) => {
+                out.push(' ');
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('<');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Length of the tag at the start of `s`, if it is one
+fn tag_len(s: &str) -> Option<usize> {
+    let name = s.strip_prefix("</").or_else(|| s.strip_prefix('<'))?;
+    let name_end = name.find(|c: char| !(c.is_ascii_alphanumeric() || c == ':' || c == '-'))?;
+    if name_end == 0 || !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    if !name[name_end..].starts_with(|c: char| c.is_whitespace() || c == '/' || c == '>') {
+        return None;
+    }
+    s.find('>').map(|end| end + 1)
+}
+
+/// Unescape the five predefined XML entities
+fn unescape_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pacing_engine::{MeditationPacer, PacingConfig, ProsodyRamp};
+
+    #[test]
+    fn test_paced_scripts_round_trip() {
+        let config = PacingConfig {
+            prosody_ramp: Some(ProsodyRamp::new(1.0, 0.7)),
+            ..PacingConfig::default()
+        };
+        let pacer = MeditationPacer::with_config(config);
+        let text = "Welcome…\n\nBreathe in; hold — and release!!! It's 3.5 breaths, a < b.";
+        let result = pacer.calculate_pacing(text.to_string(), 60.0);
+        assert!(result.ssml.contains("<prosody"));
+        assert_eq!(verify_roundtrip(text, &result.ssml), Ok(()));
+        assert_eq!(verify_roundtrip(text, &format!("<speak>{}</speak>", result.ssml)), Ok(()));
+    }
+
+    #[test]
+    fn test_differences_reported() {
+        let text = "Breathe in. Hold it. Let go.";
+        assert_eq!(
+            verify_roundtrip(text, "Breathe in in. <break time=\"1.0s\"/> Hold it. Let go."),
+            Err(RoundtripError::Extra { index: 2, word: "in".to_string() })
+        );
+        assert_eq!(
+            verify_roundtrip(text, "Breathe in. Hold it. Let"),
+            Err(RoundtripError::Dropped { index: 5, word: "go".to_string() })
+        );
+        assert_eq!(
+            verify_roundtrip(text, "Breathe out. Hold it. Let go."),
+            Err(RoundtripError::Changed { index: 1, expected: "in".to_string(), found: "out".to_string() })
+        );
+        assert_eq!(verify_roundtrip("Rest &amp; relax", "Rest &amp;amp; relax"), Err(RoundtripError::Extra {
+            index: 1,
+            word: "amp".to_string(),
+        }));
+    }
+}
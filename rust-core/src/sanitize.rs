@@ -0,0 +1,502 @@
+//! Input Sanitization
+//! 
+//! Cleans up script text before it is atomized, for content that did not
+//! come from a careful human writer.
+//! 
+//! ## Whitespace
+//! 
+//! Line endings and whitespace are always normalized first, so paragraph
+//! detection sees one kind of line break: `\r\n`, a lone `\r`, and the
+//! other Unicode line breaks become `\n` (a paragraph separator, `\n\n`),
+//! and every other whitespace character (tabs, no-break and typographic
+//! spaces) becomes a plain space.
+//! 
+//! ## HTML
+//! 
+//! With `PacingConfig::strip_html`, text pasted from a web CMS is reduced
+//! to plain text first: tags are removed (along with scripts, styles, and
+//! comments), entities such as `&amp;` and `&nbsp;` are decoded, `<br>`
+//! becomes a line break and block elements (`<p>`, `<div>`, headings, list
+//! items) become paragraph breaks. Line breaks in the HTML source itself
+//! are just spaces, as in a browser. A `<` that does not open a well-formed
+//! tag ("if a <b then") stays in the text, and text with no tags at all
+//! keeps its line breaks.
+//! 
+//! ## Emoji and Symbols
+//! 
+//! LLM output in particular sometimes decorates a script with emoji and
+//! symbols ("✨", "🧘‍♀️", "→"), which TTS voices read aloud ("sparkles
+//! emoji") or stumble over. `PacingConfig::symbols` chooses what happens
+//! to them:
+//! 
+//! - **Allow** (default): left in the text for TTS
+//! - **Strip**: removed, with any space they leave doubled up
+//! - **Replace**: each emoji sequence replaced with a fixed text
+//! 
+//! Whatever the setting, symbols never count as spoken characters, so they
+//! do not inflate the speech estimate.
+//! 
+//! ## Numbers
+//! 
+//! With `PacingConfig::spell_out_numbers`, numbers and times are written
+//! out in words last (see `numbers`).
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::{MeditationPacer, PacingConfig, SymbolHandling};
+//! 
+//! let config = PacingConfig { symbols: SymbolHandling::Strip, ..PacingConfig::default() };
+//! let pacer = MeditationPacer::with_config(config);
+//! assert_eq!(pacer.sanitize_text("✨ Breathe in 🧘🏽‍♀️. Let go."), " Breathe in. Let go.");
+//! ```
+
+use alloc::borrow::Cow;
+
+use crate::numbers::expand_numbers;
+use crate::pacing_engine::MeditationPacer;
+use crate::prelude::*;
+
+/// Elements whose start and end are paragraph breaks
+const BLOCK_TAGS: [&str; 20] = [
+    "address", "article", "blockquote", "dd", "div", "dt", "footer", "h1", "h2", "h3",
+    "h4", "h5", "h6", "header", "li", "ol", "p", "section", "tr", "ul",
+];
+
+/// Elements whose content is never text
+const SKIPPED_TAGS: [&str; 2] = ["script", "style"];
+
+// ============================================
+// Types
+// ============================================
+
+/// What to do with emoji and decorative symbols in a script
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum SymbolHandling {
+    /// Leave them in the text
+    #[default]
+    Allow,
+    /// Remove them
+    Strip,
+    /// Replace each emoji sequence with this text
+    Replace {
+        /// Replacement text (e.g. "," for a short pause)
+        with: String,
+    },
+}
+
+// ============================================
+// Sanitizing
+// ============================================
+
+impl MeditationPacer {
+    /// The script as it will be atomized, after whitespace normalization,
+    /// HTML stripping, symbol handling, and number spelling
+    /// 
+    /// Borrows `text` when nothing had to change.
+    pub fn sanitize_text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut text = match normalize_whitespace(text) {
+            Some(normalized) => Cow::Owned(normalized),
+            None => Cow::Borrowed(text),
+        };
+        if self.config().strip_html {
+            if let Some(plain) = html_to_text(&text) {
+                // Entities can decode to any whitespace ("&#160;", "&#13;")
+                text = Cow::Owned(normalize_whitespace(&plain).unwrap_or(plain));
+            }
+        }
+        let replacement = match &self.config().symbols {
+            SymbolHandling::Allow => None,
+            SymbolHandling::Strip => Some(""),
+            SymbolHandling::Replace { with } => Some(with.as_str()),
+        };
+        if let Some(replaced) = replacement.and_then(|r| replace_symbols(&text, r)) {
+            text = Cow::Owned(replaced);
+        }
+        if self.config().spell_out_numbers {
+            if let Cow::Owned(spelled) = expand_numbers(&text) {
+                text = Cow::Owned(spelled);
+            }
+        }
+        text
+    }
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// `text` with uniform line breaks and spaces (`None` if it already has)
+fn normalize_whitespace(text: &str) -> Option<String> {
+    if !text.contains(|c: char| c.is_whitespace() && c != ' ' && c != '\n') {
+        return None;
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                out.push('\n');
+            }
+            '\u{2029}' => out.push_str("\n\n"),
+            '\n' | '\u{000B}' | '\u{000C}' | '\u{0085}' | '\u{2028}' => out.push('\n'),
+            c if c.is_whitespace() => out.push(' '),
+            c => out.push(c),
+        }
+    }
+    Some(out)
+}
+
+/// Replace every emoji sequence in `text` (`None` if there is none)
+/// 
+/// An empty replacement also drops the space the sequence leaves doubled.
+fn replace_symbols(text: &str, replacement: &str) -> Option<String> {
+    if !text.chars().any(is_symbol_char) {
+        return None;
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut in_sequence = false;
+    for (i, c) in text.char_indices() {
+        // A joiner inside an emoji sequence belongs to it
+        if is_symbol_char(c) || (in_sequence && c == '\u{200D}') {
+            if !in_sequence {
+                out.push_str(replacement);
+            }
+            in_sequence = true;
+            continue;
+        }
+        if in_sequence && replacement.is_empty() && out.ends_with(' ') {
+            let rest = &text[i..];
+            if rest.starts_with(|c: char| c.is_whitespace() || c.is_ascii_punctuation()) {
+                out.pop();
+            }
+        }
+        in_sequence = false;
+        out.push(c);
+    }
+    Some(out)
+}
+
+/// Plain text of an HTML fragment (`None` if it has no markup or entities)
+/// 
+/// Source whitespace is only collapsed when there are tags or comments;
+/// text that merely contains a `&` or `<` keeps its line breaks.
+fn html_to_text(html: &str) -> Option<String> {
+    let has_markup = html.match_indices('<').any(|(i, _)| html[i..].starts_with("<!--") || parse_tag(&html[i..]).is_some());
+    if !has_markup {
+        return decode_entities(html);
+    }
+
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(i) = rest.find(['<', '&']) {
+        push_collapsed(&mut out, &rest[..i]);
+        rest = &rest[i..];
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if let Some((name, closing, len)) = parse_tag(rest) {
+            rest = &rest[len..];
+            if name == "br" {
+                out.push('\n');
+            } else if BLOCK_TAGS.contains(&name.as_str()) {
+                out.push_str("\n\n");
+            } else if SKIPPED_TAGS.contains(&name.as_str()) && !closing {
+                let end = rest.to_ascii_lowercase().find(&format!("</{}", name)).unwrap_or(rest.len());
+                rest = &rest[end..];
+            }
+        } else if let Some((c, len)) = decode_entity(rest) {
+            out.push(c);
+            rest = &rest[len..];
+        } else {
+            out.push_str(&rest[..1]);
+            rest = &rest[1..];
+        }
+    }
+    push_collapsed(&mut out, rest);
+
+    // Trim each line and keep at most one blank line between paragraphs
+    let mut text = String::with_capacity(out.len());
+    let mut blank_lines = 0;
+    for line in out.split('\n').map(str::trim) {
+        if line.is_empty() {
+            blank_lines += 1;
+            continue;
+        }
+        if !text.is_empty() {
+            text.push_str(if blank_lines > 1 { "\n\n" } else { "\n" });
+        }
+        text.push_str(line);
+        blank_lines = 1;
+    }
+    Some(text)
+}
+
+/// Append HTML source text, with whitespace runs (line breaks included)
+/// collapsed to single spaces
+fn push_collapsed(out: &mut String, text: &str) {
+    for c in text.chars() {
+        if !c.is_whitespace() {
+            out.push(c);
+        } else if !out.ends_with([' ', '\n']) {
+            out.push(' ');
+        }
+    }
+}
+
+/// `text` with its entities decoded (`None` if it has none)
+fn decode_entities(text: &str) -> Option<String> {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    let mut decoded = false;
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        if let Some((c, len)) = decode_entity(rest) {
+            out.push(c);
+            rest = &rest[len..];
+            decoded = true;
+        } else {
+            out.push('&');
+            rest = &rest[1..];
+        }
+    }
+    out.push_str(rest);
+    decoded.then_some(out)
+}
+
+/// Lowercase name, whether it is a closing tag, and byte length of the
+/// tag at the start of `text`
+/// 
+/// Only a well-formed tag on one line counts (`<name attr="value" ...>`);
+/// anything else, like the `<` of "if a <b then", is text.
+fn parse_tag(text: &str) -> Option<(String, bool, usize)> {
+    let inner = text.strip_prefix('<')?;
+    let (closing, inner) = match inner.strip_prefix('/') {
+        Some(inner) => (true, inner),
+        None => (false, inner),
+    };
+    let bang = usize::from(inner.starts_with('!'));
+    if !inner[bang..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let name_len = inner[bang..].find(|c: char| !c.is_ascii_alphanumeric()).map_or(inner.len(), |len| bang + len);
+    let attributes_len = tag_attributes_len(&inner[name_len..])?;
+    let len = text.len() - inner.len() + name_len + attributes_len;
+    Some((inner[..name_len].to_ascii_lowercase(), closing, len))
+}
+
+/// Byte length of a tag's attributes and closing `>` (`None` unless they
+/// are space-separated `name`, `name=value`, or `name="value"` on one line)
+fn tag_attributes_len(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let is_name_byte = |b: u8| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b':');
+    let mut i = 0;
+    loop {
+        let start = i;
+        while matches!(bytes.get(i), Some(b' ' | b'\t')) {
+            i += 1;
+        }
+        match bytes.get(i)? {
+            b'>' => return Some(i + 1),
+            b'/' if bytes.get(i + 1) == Some(&b'>') => return Some(i + 2),
+            _ if i == start => return None,
+            _ => {}
+        }
+
+        let name = i;
+        while bytes.get(i).is_some_and(|b| is_name_byte(*b)) {
+            i += 1;
+        }
+        if i == name {
+            return None;
+        }
+        if bytes.get(i) != Some(&b'=') {
+            continue;
+        }
+        i += 1;
+        match bytes.get(i)? {
+            quote @ (b'"' | b'\'') => {
+                let value_len = text[i + 1..].find(char::from(*quote))?;
+                if text[i + 1..i + 1 + value_len].contains(['<', '\n']) {
+                    return None;
+                }
+                i += value_len + 2;
+            }
+            _ => {
+                let value = i;
+                while bytes.get(i).is_some_and(|b| !b" \t\n<>\"'=`".contains(b)) {
+                    i += 1;
+                }
+                if i == value {
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+/// The character an entity at the start of `text` stands for, and the
+/// entity's byte length
+fn decode_entity(text: &str) -> Option<(char, usize)> {
+    let semicolon = text.char_indices().take(12).find(|(_, c)| *c == ';')?.0;
+    let name = &text[1..semicolon];
+    let c = match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "hellip" => '…',
+        "mdash" => '—',
+        "ndash" => '–',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        _ => {
+            let number = name.strip_prefix('#')?;
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            char::from_u32(code)?
+        }
+    };
+    Some((c, semicolon + 1))
+}
+
+/// Whether `c` is an emoji, an emoji modifier, or a decorative symbol
+pub(crate) fn is_symbol_char(c: char) -> bool {
+    matches!(c,
+        '\u{20E3}'
+        | '\u{2190}'..='\u{21FF}'
+        | '\u{2300}'..='\u{23FF}'
+        | '\u{25A0}'..='\u{27BF}'
+        | '\u{2900}'..='\u{297F}'
+        | '\u{2B00}'..='\u{2BFF}'
+        | '\u{3030}' | '\u{303D}' | '\u{3297}' | '\u{3299}'
+        | '\u{FE00}'..='\u{FE0F}'
+        | '\u{1F000}'..='\u{1FAFF}'
+        | '\u{E0020}'..='\u{E007F}'
+    )
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PacingConfig, PunctuationType};
+
+    fn pacer(symbols: SymbolHandling) -> MeditationPacer {
+        MeditationPacer::with_config(PacingConfig { symbols, ..PacingConfig::default() })
+    }
+
+    #[test]
+    fn test_symbol_handling() {
+        let text = "Breathe in ✨ and out 👨‍👩‍👧‍👦, rest → here.";
+        assert_eq!(pacer(SymbolHandling::Allow).sanitize_text(text), text);
+        assert_eq!(pacer(SymbolHandling::Strip).sanitize_text(text), "Breathe in and out, rest here.");
+        assert_eq!(
+            pacer(SymbolHandling::Replace { with: "...".to_string() }).sanitize_text(text),
+            "Breathe in ... and out ..., rest ... here."
+        );
+
+        // Joiners outside emoji (as in Indic scripts) are kept
+        assert_eq!(pacer(SymbolHandling::Strip).sanitize_text("क्\u{200D}ष"), "क्\u{200D}ष");
+    }
+
+    #[test]
+    fn test_whitespace_normalization() {
+        let pacer = MeditationPacer::new();
+        assert_eq!(
+            pacer.sanitize_text("Breathe in.\r\n\r\nRest\u{00A0}now.\rLet\tgo\u{2029}Relax"),
+            "Breathe in.\n\nRest now.\nLet go\n\nRelax"
+        );
+        assert!(matches!(pacer.sanitize_text("Breathe in.\n\nRest."), Cow::Borrowed(_)));
+
+        let unix = pacer.calculate_pacing("Breathe in.\n\nLet\u{202F}go.".to_string(), 20.0);
+        let windows = pacer.calculate_pacing("Breathe in.\r\n\r\nLet go.".to_string(), 20.0);
+        assert_eq!(windows.timeline[0].punctuation, unix.timeline[0].punctuation);
+        assert_eq!(windows.total_chars, unix.total_chars);
+        assert_eq!(windows.ssml, unix.ssml);
+    }
+
+    #[test]
+    fn test_numbers_are_estimated_as_words() {
+        let pacer = MeditationPacer::new();
+        let digits = pacer.calculate_pacing("Count from 1 to 10.".to_string(), 20.0);
+        let words = pacer.calculate_pacing("Count from one to ten.".to_string(), 20.0);
+        assert_eq!(digits.spoken_chars, words.spoken_chars);
+        assert!(digits.ssml.contains("from 1 to 10"));
+
+        // The character count stays the written one: "It costs 4.5 dollars
+        // at 3:30" (23) and "OK" (2)
+        let written = pacer.calculate_pacing("It costs 4.5 dollars at 3:30, OK?".to_string(), 20.0);
+        assert_eq!(written.total_chars, 25);
+        assert!(written.spoken_chars > written.total_chars);
+
+        // Decimal points and thousands separators do not end an atom
+        assert_eq!(pacer.calculate_pacing("Feel 4.5 breaths.".to_string(), 20.0).timeline.len(), 1);
+        assert_eq!(pacer.calculate_pacing("Walk 1,500 steps.".to_string(), 20.0).timeline.len(), 1);
+
+        let spelled = MeditationPacer::with_config(PacingConfig { spell_out_numbers: true, ..PacingConfig::default() });
+        let result = spelled.calculate_pacing("Rest until 3:30.".to_string(), 20.0);
+        assert!(result.ssml.contains("Rest until three thirty."));
+    }
+
+    #[test]
+    fn test_html_to_text() {
+        let html = "<h2>Welcome</h2>\n<p class=\"intro\">Breathe in &amp; out,\n   slowly.<br/>Let&nbsp;go&hellip;</p>\
+            <!-- cms --><script>track();</script><p>Rest&#8212;here.</p>";
+        let pacer = MeditationPacer::with_config(PacingConfig { strip_html: true, ..PacingConfig::default() });
+        let text = pacer.sanitize_text(html);
+        assert_eq!(text, "Welcome\n\nBreathe in & out, slowly.\nLet go…\n\nRest—here.");
+        assert_eq!(pacer.sanitize_text("<p>Let&#160;go.&#13;&#10;Rest.</p>"), "Let go.\nRest.");
+        assert_eq!(pacer.sanitize_text("Breathe in.&#8233;Rest."), "Breathe in.\n\nRest.");
+
+        let atoms = pacer.atomize(html);
+        assert_eq!(atoms[0].punctuation, PunctuationType::Paragraph);
+        assert_eq!(atoms.iter().filter(|a| a.punctuation == PunctuationType::Paragraph).count(), 3);
+
+        // Off by default: angle brackets are left alone
+        assert_eq!(MeditationPacer::new().sanitize_text("a<b, c>d"), "a<b, c>d");
+    }
+
+    #[test]
+    fn test_html_prose_is_kept() {
+        let pacer = MeditationPacer::with_config(PacingConfig { strip_html: true, ..PacingConfig::default() });
+
+        // A `<` that does not start a well-formed tag is text
+        let prose = "If a <b then rest. Later x > y. End.";
+        assert_eq!(pacer.sanitize_text(prose), prose);
+        assert_eq!(pacer.sanitize_text("<p>Count 3 <5 breaths.</p>"), "Count 3 <5 breaths.");
+        assert_eq!(pacer.sanitize_text("<p>If a <b then\nrest.</p>"), "If a <b then rest.");
+        assert_eq!(pacer.sanitize_text("<p title=\"a > b\">Rest</p>"), "Rest");
+
+        // Without markup, line breaks survive (entities are still decoded)
+        let plain = "Rest & relax.\n\nNew paragraph here.";
+        assert!(matches!(pacer.sanitize_text(plain), Cow::Borrowed(_)));
+        assert_eq!(pacer.sanitize_text("Rest &amp; relax.\n\nNew paragraph."), "Rest & relax.\n\nNew paragraph.");
+        let atoms = pacer.atomize(plain);
+        assert_eq!(atoms[0].punctuation, PunctuationType::Paragraph);
+    }
+
+    #[test]
+    fn test_symbols_are_not_spoken() {
+        let pacer = pacer(SymbolHandling::Allow);
+        let plain = pacer.calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+        let decorated = pacer.calculate_pacing("Breathe in 🌬️. Let go 🌊.".to_string(), 20.0);
+        assert_eq!(plain.spoken_chars, decorated.spoken_chars);
+        assert_eq!(plain.estimated_speech_seconds, decorated.estimated_speech_seconds);
+    }
+}
@@ -0,0 +1,53 @@
+//! JSON Schema Generation
+//! 
+//! Emits JSON Schemas for the types that cross the FFI/WASM boundary, so
+//! the Swift and TypeScript sides can generate matching models instead of
+//! maintaining them by hand. Available with the `schemars` feature.
+//! 
+//! The schemas describe the `serde` representation: field names as in
+//! Rust, unit enum variants as strings.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! let schema = zenpal_core::schema::pacing_config_schema();
+//! assert!(schema.contains("\"chars_per_second\""));
+//! ```
+
+use schemars::schema_for;
+
+use crate::pacing_engine::{PacingConfig, PacingResult};
+
+// ============================================
+// Schemas
+// ============================================
+
+/// Pretty-printed JSON Schema for `PacingConfig`
+pub fn pacing_config_schema() -> String {
+    format!("{:#}", schema_for!(PacingConfig).as_value())
+}
+
+/// Pretty-printed JSON Schema for `PacingResult`
+pub fn pacing_result_schema() -> String {
+    format!("{:#}", schema_for!(PacingResult).as_value())
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schemas_are_json() {
+        let config: serde_json::Value = serde_json::from_str(&pacing_config_schema()).unwrap();
+        assert_eq!(config["title"], "PacingConfig");
+        assert!(config["properties"]["weights"].is_object());
+
+        let result: serde_json::Value = serde_json::from_str(&pacing_result_schema()).unwrap();
+        assert_eq!(result["title"], "PacingResult");
+        assert!(result["properties"]["timeline"].is_object());
+    }
+}
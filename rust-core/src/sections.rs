@@ -0,0 +1,320 @@
+//! Section-Based Pacing Profiles
+//! 
+//! A script can be split into named sections (intro, body, closing) that
+//! share one target duration but each get their own speech rate, punctuation
+//! weights, and share of the silence budget.
+//! 
+//! ## Budget Allocation
+//! 
+//! 1. Speech time is estimated per section with that section's chars/sec
+//! 2. The global silence budget (target - speech, with safety buffer) is
+//!    split between sections in proportion to `silence_share`
+//! 3. Each section distributes its share over its own atoms by weight;
+//!    every section except the last also pauses after its final atom
+//! 4. The break budget is fitted over the whole script, so each section's
+//!    reported silence and start are read back from the rendered timeline;
+//!    the intro pause opens the first section, so section silences add up
+//!    to `total_silence_added`
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::{MeditationPacer, ScriptSection, SectionProfile};
+//! 
+//! let sections = vec![
+//!     ScriptSection::new("intro", "Welcome. Settle in.", SectionProfile::with_share(1.0)),
+//!     ScriptSection::new("body", "Breathe in. Breathe out. Rest.", SectionProfile::with_share(3.0)),
+//! ];
+//! let result = MeditationPacer::new().calculate_sectioned(&sections, 120.0);
+//! assert_eq!(result.sections.len(), 2);
+//! ```
+
+use crate::pacing_engine::{
+    MeditationPacer, PacingOverrides, PacingResult, PunctuationType, SectionTiming, SpeechAtom,
+};
+use crate::prelude::*;
+
+// ============================================
+// Types
+// ============================================
+
+/// Per-section overrides on top of the pacer's configuration
+#[derive(Debug, Clone)]
+pub struct SectionProfile {
+    /// Speech rate for this section (falls back to the pacer's)
+    pub chars_per_second: Option<f64>,
+    /// Punctuation weights for this section (falls back to the pacer's)
+    pub weights: Option<HashMap<PunctuationType, u32>>,
+    /// Relative share of the global silence budget
+    pub silence_share: f64,
+}
+
+impl SectionProfile {
+    /// A profile that only sets the silence share
+    pub fn with_share(silence_share: f64) -> Self {
+        Self {
+            silence_share,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for SectionProfile {
+    fn default() -> Self {
+        Self {
+            chars_per_second: None,
+            weights: None,
+            silence_share: 1.0,
+        }
+    }
+}
+
+/// A named piece of a sectioned script
+#[derive(Debug, Clone)]
+pub struct ScriptSection {
+    /// Section name reported back in `SectionTiming`
+    pub name: String,
+    /// Raw text of the section
+    pub text: String,
+    /// Pacing profile for the section
+    pub profile: SectionProfile,
+}
+
+impl ScriptSection {
+    /// Create a new section
+    pub fn new(name: &str, text: &str, profile: SectionProfile) -> Self {
+        Self {
+            name: name.to_string(),
+            text: text.to_string(),
+            profile,
+        }
+    }
+}
+
+// ============================================
+// Sectioned Pacing
+// ============================================
+
+impl MeditationPacer {
+    /// Pace a script made of named sections against one target duration
+    /// 
+    /// The returned result covers the whole script; `sections` reports
+    /// where each section lands and how much silence it received.
+    pub fn calculate_sectioned(
+        &self,
+        sections: &[ScriptSection],
+        target_duration_seconds: f64,
+    ) -> PacingResult {
+        // Step A: Atomize and estimate speech per section
+        let mut section_atoms: Vec<Vec<SpeechAtom>> = Vec::with_capacity(sections.len());
+        let mut section_pacers: Vec<MeditationPacer> = Vec::with_capacity(sections.len());
+        let mut speech_seconds: Vec<f64> = Vec::with_capacity(sections.len());
+        let mut replacements = Vec::new();
+        
+        for section in sections {
+            let mut config = self.config().clone();
+            if let Some(cps) = section.profile.chars_per_second {
+                config.chars_per_second = cps;
+                config.language = None;
+            }
+            if let Some(weights) = &section.profile.weights {
+                config.weights = weights.clone();
+            }
+            let pacer = MeditationPacer::with_config(config);
+            let (atoms, section_replacements) = pacer.atomize_filtered(&section.text);
+            replacements.extend(section_replacements);
+            speech_seconds.push(pacer.speech_seconds(&atoms).iter().sum());
+            section_atoms.push(atoms);
+            section_pacers.push(pacer);
+        }
+        
+        // Step B: Global silence budget with safety buffer
+        let estimated_speech_seconds: f64 = speech_seconds.iter().sum();
+        let raw_silence_budget = (target_duration_seconds - estimated_speech_seconds - self.framing_pauses()).max(0.0);
+        let final_silence_budget = raw_silence_budget * self.config().silence_safety_buffer;
+        
+        // Step C: Split the budget by share, then distribute within sections
+        let non_empty: Vec<usize> = (0..sections.len())
+            .filter(|i| !section_atoms[*i].is_empty())
+            .collect();
+        let total_share: f64 = non_empty.iter()
+            .map(|i| sections[*i].profile.silence_share.max(0.0))
+            .sum();
+        let last_section = non_empty.last().copied();
+        
+        let mut atoms: Vec<SpeechAtom> = Vec::new();
+        let mut pauses: Vec<f64> = Vec::new();
+        let mut speech: Vec<f64> = Vec::new();
+        
+        for i in non_empty.iter().copied() {
+            let section_atoms = &section_atoms[i];
+            let budget = if total_share > 0.0 {
+                final_silence_budget * sections[i].profile.silence_share.max(0.0) / total_share
+            } else {
+                0.0
+            };
+            
+            // Sections before the last also pause after their final atom
+            let breakable = if Some(i) == last_section {
+                section_atoms.len() - 1
+            } else {
+                section_atoms.len()
+            };
+            let section_pauses = section_pacers[i].distribute_silence_over(
+                section_atoms,
+                budget,
+                &PacingOverrides::new(),
+                breakable,
+            );
+            
+            atoms.extend(section_atoms.iter().cloned());
+            pauses.extend(section_pauses);
+            speech.extend(section_pacers[i].speech_seconds(section_atoms));
+        }
+        
+        // Step D: Render, then report sections from the rendered timeline
+        // (after break-budget fitting)
+        let mut result = self.assemble_result(
+            &atoms,
+            &pauses,
+            &speech,
+            target_duration_seconds,
+            raw_silence_budget,
+            final_silence_budget,
+        );
+        
+        let mut timings = Vec::with_capacity(sections.len());
+        let mut offset = 0.0;
+        let mut intro = result.intro_pause_seconds;
+        let mut first_atom = 0;
+        for (i, section) in sections.iter().enumerate() {
+            let entries = &result.timeline[first_atom..first_atom + section_atoms[i].len()];
+            first_atom += entries.len();
+            // The intro pause belongs to the first section with speech
+            let intro = if entries.is_empty() { 0.0 } else { core::mem::take(&mut intro) };
+            let timing = SectionTiming {
+                name: section.name.clone(),
+                start_seconds: entries.first().map_or(offset, |entry| entry.start_seconds - intro),
+                speech_seconds: speech_seconds[i],
+                silence_seconds: intro + entries.iter().map(|entry| entry.pause_seconds).sum::<f64>(),
+                atom_count: entries.len(),
+            };
+            offset = timing.start_seconds + timing.duration_seconds();
+            timings.push(timing);
+        }
+        
+        result.sections = timings;
+        result.replacements = replacements;
+        result.warnings.extend(self.check_silence_ratio(
+            result.total_words,
+            estimated_speech_seconds,
+            target_duration_seconds,
+        ));
+        result
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pacing_engine::BreakBudget;
+    use crate::PacingConfig;
+
+    #[test]
+    fn test_silence_split_by_share() {
+        let pacer = MeditationPacer::new();
+        let sections = vec![
+            ScriptSection::new("intro", "Welcome. Settle in.", SectionProfile::with_share(1.0)),
+            ScriptSection::new("body", "Breathe in. Breathe out.", SectionProfile::with_share(3.0)),
+        ];
+        let result = pacer.calculate_sectioned(&sections, 120.0);
+        
+        assert_eq!(result.atom_count, 4);
+        let intro = &result.sections[0];
+        let body = &result.sections[1];
+        assert_eq!(intro.start_seconds, 0.0);
+        assert!((body.start_seconds - intro.duration_seconds()).abs() < 1e-9);
+        
+        // Intro gets a quarter of the budget, including the boundary pause
+        assert!((intro.silence_seconds - result.final_silence_budget * 0.25).abs() < 0.01);
+        assert!((body.silence_seconds - result.final_silence_budget * 0.75).abs() < 0.01);
+        assert!((result.total_silence_added - result.final_silence_budget).abs() < 0.01);
+        assert!(!result.ssml.ends_with("/>"));
+    }
+
+    #[test]
+    fn test_section_speech_rate() {
+        let pacer = MeditationPacer::new();
+        let slow = SectionProfile {
+            chars_per_second: Some(6.0),
+            ..SectionProfile::default()
+        };
+        let sections = vec![
+            ScriptSection::new("intro", "Welcome here.", SectionProfile::default()),
+            ScriptSection::new("closing", "Welcome here.", slow),
+        ];
+        let result = pacer.calculate_sectioned(&sections, 60.0);
+        
+        // Same text at half the rate takes twice as long
+        let ratio = result.sections[1].speech_seconds / result.sections[0].speech_seconds;
+        assert!((ratio - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_intro_pause_opens_first_section() {
+        let pacer = MeditationPacer::with_config(PacingConfig { intro_pause_seconds: 5.0, ..PacingConfig::default() });
+        let sections = vec![
+            ScriptSection::new("empty", "", SectionProfile::default()),
+            ScriptSection::new("intro", "Welcome. Settle in.", SectionProfile::default()),
+            ScriptSection::new("body", "Breathe in. Breathe out.", SectionProfile::default()),
+        ];
+        let result = pacer.calculate_sectioned(&sections, 90.0);
+
+        let intro = &result.sections[1];
+        let body = &result.sections[2];
+        assert_eq!(intro.start_seconds, 0.0);
+        assert_eq!(result.timeline[0].start_seconds, 5.0);
+        assert!((intro.silence_seconds - 5.0 - result.timeline[0].pause_seconds - result.timeline[1].pause_seconds).abs() < 1e-9);
+        assert!((body.start_seconds - result.timeline[2].start_seconds).abs() < 1e-9);
+        assert!((body.start_seconds - intro.duration_seconds()).abs() < 1e-9);
+
+        let silence: f64 = result.sections.iter().map(|section| section.silence_seconds).sum();
+        assert!((silence - result.total_silence_added).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_section_silence_follows_break_budget() {
+        let config = PacingConfig {
+            break_budget: BreakBudget { max_total_seconds: None, max_tags: Some(3) },
+            ..PacingConfig::default()
+        };
+        let pacer = MeditationPacer::with_config(config);
+        let sections = vec![
+            ScriptSection::new("intro", "Welcome. Settle in. Soften.", SectionProfile::with_share(1.0)),
+            ScriptSection::new("body", "Breathe in. Breathe out. Rest. Let go.", SectionProfile::with_share(1.0)),
+        ];
+        let result = pacer.calculate_sectioned(&sections, 120.0);
+
+        // Sections report the silence the timeline renders, after merging
+        let mut first_atom = 0;
+        for section in &result.sections {
+            let entries = &result.timeline[first_atom..first_atom + section.atom_count];
+            let rendered: f64 = entries.iter().map(|entry| entry.pause_seconds).sum();
+            assert!((section.silence_seconds - rendered).abs() < 1e-9);
+            assert!((section.start_seconds - entries[0].start_seconds).abs() < 1e-9);
+            first_atom += section.atom_count;
+        }
+        let silence: f64 = result.sections.iter().map(|section| section.silence_seconds).sum();
+        assert!((silence - result.total_silence_added).abs() < 1e-6);
+
+        // ...which merged pauses across the section boundary
+        let uncapped = MeditationPacer::new().calculate_sectioned(&sections, 120.0);
+        let paused = |result: &PacingResult| result.timeline.iter().filter(|entry| entry.pause_seconds > 0.0).count();
+        assert!(paused(&result) < paused(&uncapped));
+        assert!((result.sections[0].silence_seconds - uncapped.sections[0].silence_seconds).abs() > 0.1);
+    }
+}
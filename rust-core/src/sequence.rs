@@ -0,0 +1,225 @@
+//! Guided Visualization Sequences
+//! 
+//! A sequence is an ordered list of scenes, each with its own text and a
+//! dwell time: how long the listener stays with that scene before the next
+//! one begins. Unlike sectioned pacing, where sections share one global
+//! silence budget, every scene's silence is allocated inside its own dwell
+//! window, so a visualization beat lasts as long as the author intended no
+//! matter how much text the other scenes have.
+//! 
+//! Each scene's first atom is anchored to the start of its window (see
+//! `PacingOverrides::anchor_at`), which makes the silence before it exactly
+//! fill the previous window. The pause after a scene's last atom is part of
+//! that scene's dwell. Scenes reported in `PacingResult::sections` carry
+//! their window's timing.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::{MeditationPacer, Scene, Sequence};
+//! 
+//! let sequence = Sequence::new()
+//!     .scene(Scene::new("path", "Picture a path through a forest.", 20.0))
+//!     .scene(Scene::new("lake", "The path opens onto a still lake. Rest here.", 40.0));
+//! let result = MeditationPacer::new().calculate_sequence(&sequence);
+//! assert_eq!(result.sections.len(), 2);
+//! assert!((result.sections[1].start_seconds - 20.0).abs() < 1e-9);
+//! ```
+
+use crate::pacing_engine::{
+    MeditationPacer, PacingOverrides, PacingResult, PacingWarning, SectionTiming, SpeechAtom,
+};
+use crate::prelude::*;
+
+// ============================================
+// Types
+// ============================================
+
+/// One beat of a visualization
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scene {
+    /// Scene name reported back in `SectionTiming`
+    pub name: String,
+    /// Raw text of the scene
+    pub text: String,
+    /// Time from the start of this scene to the start of the next, in seconds
+    pub dwell_seconds: f64,
+}
+
+impl Scene {
+    /// Create a scene
+    pub fn new(name: &str, text: &str, dwell_seconds: f64) -> Self {
+        Self {
+            name: name.to_string(),
+            text: text.to_string(),
+            dwell_seconds,
+        }
+    }
+}
+
+/// Ordered scenes played back to back
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Sequence {
+    /// Scenes in playback order
+    pub scenes: Vec<Scene>,
+}
+
+impl Sequence {
+    /// An empty sequence
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a scene
+    pub fn scene(mut self, scene: Scene) -> Self {
+        self.scenes.push(scene);
+        self
+    }
+
+    /// Sum of every scene's dwell time
+    pub fn total_duration_seconds(&self) -> f64 {
+        self.scenes.iter().map(|scene| scene.dwell_seconds.max(0.0)).sum()
+    }
+}
+
+// ============================================
+// Pacing
+// ============================================
+
+impl MeditationPacer {
+    /// Pace a sequence, keeping each scene's silence within its dwell window
+    /// 
+    /// The target duration is the sum of the dwell times. A scene whose
+    /// speech alone outlasts its dwell gets no pauses, pushes the scenes
+    /// after it later, and is reported as `PacingWarning::SceneOverrun`.
+    pub fn calculate_sequence(&self, sequence: &Sequence) -> PacingResult {
+        let target_duration_seconds = sequence.total_duration_seconds();
+        
+        // Step A: Atomize every scene and anchor its first atom to its window
+        let mut atoms: Vec<SpeechAtom> = Vec::new();
+        let mut first_atoms: Vec<usize> = Vec::with_capacity(sequence.scenes.len());
+        let mut windows: Vec<f64> = Vec::with_capacity(sequence.scenes.len());
+        let mut overrides = PacingOverrides::new();
+        let mut replacements = Vec::new();
+        let mut window_start = 0.0;
+        for scene in &sequence.scenes {
+            let (scene_atoms, scene_replacements) = self.atomize_filtered(&scene.text);
+            replacements.extend(scene_replacements);
+            if !scene_atoms.is_empty() {
+                overrides = overrides.anchor_at(atoms.len(), window_start);
+            }
+            first_atoms.push(atoms.len());
+            windows.push(window_start);
+            atoms.extend(scene_atoms);
+            window_start += scene.dwell_seconds.max(0.0);
+        }
+        
+        // Step B: Pace and render with the anchors
+        let (speech, pauses, raw_silence_budget, final_silence_budget) =
+            self.plan_pauses(&atoms, target_duration_seconds, &overrides);
+        let mut result = self.assemble_result(
+            &atoms,
+            &pauses,
+            &speech,
+            target_duration_seconds,
+            raw_silence_budget,
+            final_silence_budget,
+        );
+        result.replacements = replacements;
+        
+        // Step C: Report scene timings and overruns
+        for (i, scene) in sequence.scenes.iter().enumerate() {
+            let start = first_atoms[i];
+            let end = first_atoms.get(i + 1).copied().unwrap_or(atoms.len());
+            let speech_seconds: f64 = speech[start..end].iter().sum();
+            let timing = SectionTiming {
+                name: scene.name.clone(),
+                start_seconds: result.timeline.get(start)
+                    .filter(|_| end > start)
+                    .map_or(windows[i], |entry| entry.start_seconds),
+                speech_seconds,
+                silence_seconds: result.timeline[start..end].iter().map(|entry| entry.pause_seconds).sum(),
+                atom_count: end - start,
+            };
+            if speech_seconds > scene.dwell_seconds {
+                result.warnings.push(PacingWarning::SceneOverrun {
+                    scene: scene.name.clone(),
+                    speech_seconds,
+                    dwell_seconds: scene.dwell_seconds,
+                });
+            }
+            result.sections.push(timing);
+        }
+        result.warnings.extend(self.check_silence_ratio(
+            result.total_words,
+            result.estimated_speech_seconds,
+            target_duration_seconds,
+        ));
+        
+        result
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_silence_stays_in_dwell_window() {
+        let pacer = MeditationPacer::new();
+        let sequence = Sequence::new()
+            .scene(Scene::new("path", "Picture a path. Walk slowly. Notice the trees.", 30.0))
+            .scene(Scene::new("lake", "A lake.", 60.0))
+            .scene(Scene::new("return", "Come back to the room. Open your eyes.", 20.0));
+        let result = pacer.calculate_sequence(&sequence);
+        
+        assert_eq!(result.target_duration_seconds, 110.0);
+        let starts: Vec<f64> = result.sections.iter().map(|s| s.start_seconds).collect();
+        assert!((starts[1] - 30.0).abs() < 1e-9);
+        assert!((starts[2] - 90.0).abs() < 1e-9);
+        
+        // The short lake scene still holds its full minute
+        let lake = &result.sections[1];
+        assert_eq!(lake.atom_count, 1);
+        assert!((lake.duration_seconds() - 60.0).abs() < 1e-9);
+        assert!(result.warnings.iter().all(|w| !matches!(w, PacingWarning::SceneOverrun { .. })));
+    }
+
+    #[test]
+    fn test_scene_overrun_warns() {
+        let pacer = MeditationPacer::new();
+        let sequence = Sequence::new()
+            .scene(Scene::new("rushed", "This scene has far too many words to say in a single second.", 1.0))
+            .scene(Scene::new("", "", 5.0))
+            .scene(Scene::new("rest", "Rest.", 10.0));
+        let result = pacer.calculate_sequence(&sequence);
+        
+        assert!(result.warnings.iter().any(|w| matches!(
+            w,
+            PacingWarning::SceneOverrun { scene, .. } if scene == "rushed"
+        )));
+        assert_eq!(result.sections.len(), 3);
+        assert_eq!(result.sections[1].atom_count, 0);
+        assert!((result.sections[2].start_seconds - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_negative_dwell_counts_as_zero() {
+        let sequence = Sequence::new()
+            .scene(Scene::new("skipped", "", -5.0))
+            .scene(Scene::new("rest", "Rest here.", 10.0));
+        assert_eq!(sequence.total_duration_seconds(), 10.0);
+
+        let result = MeditationPacer::new().calculate_sequence(&sequence);
+        assert_eq!(result.sections[0].start_seconds, 0.0);
+        assert!(result.sections[1].start_seconds.abs() < 1e-9);
+
+        let empty = MeditationPacer::new().calculate_sequence(&Sequence::new());
+        assert!(empty.sections.is_empty());
+        assert_eq!(empty.atom_count, 0);
+    }
+}
@@ -0,0 +1,310 @@
+//! Session Composer
+//! 
+//! A session is an ordered list of segments (intro, body, closing) that
+//! are paced and synthesized separately but played back to back. Each
+//! segment has its own text, target duration, and optionally its own
+//! config; composing paces every segment and lines them up on one
+//! timeline.
+//! 
+//! Unlike sectioned pacing (`calculate_sectioned`), which splits one
+//! target's silence budget between sections of one SSML document, every
+//! segment here is a separate SSML document that meets its own target.
+//! 
+//! ## Voices
+//! 
+//! Segments can name a voice, e.g. a guide voice alternating with an
+//! affirmation voice for call-and-response. `ComposedSession::to_ssml`
+//! wraps each voiced segment in `<voice name="...">`; for providers without
+//! the tag, synthesize segment by segment using `ComposedSegment::voice_id`.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::{Session, SessionSegment};
+//! 
+//! let session = Session::new()
+//!     .segment(SessionSegment::new("intro", "Welcome. Settle in.", 30.0))
+//!     .segment(SessionSegment::new("body", "Breathe in. Breathe out. Rest.", 90.0));
+//! let composed = session.compose();
+//! assert_eq!(composed.segments.len(), 2);
+//! assert_eq!(composed.segments[1].start_seconds, composed.segments[0].duration_seconds());
+//! ```
+
+use crate::pacing_engine::{MeditationPacer, PacingConfig, PacingResult, TimelineEntry};
+use crate::prelude::*;
+
+// ============================================
+// Types
+// ============================================
+
+/// One separately paced piece of a session
+#[derive(Debug, Clone)]
+pub struct SessionSegment {
+    /// Segment name reported back in `ComposedSegment`
+    pub name: String,
+    /// Raw text of the segment
+    pub text: String,
+    /// Target duration of the segment on its own
+    pub target_duration_seconds: f64,
+    /// Config for this segment (falls back to the session's)
+    pub config: Option<PacingConfig>,
+    /// Voice that speaks this segment (the provider's default when unset)
+    pub voice_id: Option<String>,
+}
+
+impl SessionSegment {
+    /// Create a segment paced with the session's config
+    pub fn new(name: &str, text: &str, target_duration_seconds: f64) -> Self {
+        Self {
+            name: name.to_string(),
+            text: text.to_string(),
+            target_duration_seconds,
+            config: None,
+            voice_id: None,
+        }
+    }
+
+    /// Pace this segment with its own config
+    pub fn with_config(mut self, config: PacingConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Have this segment spoken by a specific voice
+    pub fn with_voice(mut self, voice_id: &str) -> Self {
+        self.voice_id = Some(voice_id.to_string());
+        self
+    }
+}
+
+/// An ordered list of segments sharing a base config
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    /// Config for segments that do not set their own
+    pub config: PacingConfig,
+    /// Segments in playback order
+    pub segments: Vec<SessionSegment>,
+}
+
+/// A paced segment and where it lands in the session
+#[derive(Debug, Clone)]
+pub struct ComposedSegment {
+    /// Segment name
+    pub name: String,
+    /// Estimated offset where the segment starts, in seconds
+    pub start_seconds: f64,
+    /// Voice that speaks the segment, if one was assigned
+    pub voice_id: Option<String>,
+    /// Pacing for the segment on its own (SSML, local timeline, warnings)
+    pub result: PacingResult,
+}
+
+impl ComposedSegment {
+    /// Estimated duration of the segment
+    pub fn duration_seconds(&self) -> f64 {
+        self.result.estimated_total_seconds
+    }
+
+    /// Estimated offset where the segment ends
+    pub fn end_seconds(&self) -> f64 {
+        self.start_seconds + self.duration_seconds()
+    }
+
+    /// The segment's SSML, wrapped in a `<voice>` tag if it has a voice
+    pub fn voiced_ssml(&self) -> String {
+        match &self.voice_id {
+            Some(voice_id) => format!(
+                "<voice name=\"{}\">{}</voice>",
+                escape_attribute(voice_id),
+                self.result.ssml,
+            ),
+            None => self.result.ssml.clone(),
+        }
+    }
+}
+
+/// Every segment of a session, paced and placed on one timeline
+#[derive(Debug, Clone)]
+pub struct ComposedSession {
+    /// Segments in playback order
+    pub segments: Vec<ComposedSegment>,
+    /// Every atom of the session with session-wide offsets; `atom_index`
+    /// counts across segments
+    pub timeline: Vec<TimelineEntry>,
+}
+
+impl ComposedSession {
+    /// Estimated duration of the whole session
+    pub fn total_duration_seconds(&self) -> f64 {
+        self.segments.last().map_or(0.0, ComposedSegment::end_seconds)
+    }
+
+    /// Requested duration of the whole session
+    pub fn target_duration_seconds(&self) -> f64 {
+        self.segments.iter().map(|s| s.result.target_duration_seconds).sum()
+    }
+
+    /// All segments as one SSML document, with `<voice>` tags for voiced
+    /// segments
+    pub fn to_ssml(&self) -> String {
+        self.segments.iter()
+            .map(ComposedSegment::voiced_ssml)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+// ============================================
+// Composition
+// ============================================
+
+impl Session {
+    /// Create an empty session with the default config
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty session with a base config
+    pub fn with_config(config: PacingConfig) -> Self {
+        Self {
+            config,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Append a segment
+    pub fn segment(mut self, segment: SessionSegment) -> Self {
+        self.segments.push(segment);
+        self
+    }
+
+    /// Pace every segment and line them up back to back
+    /// 
+    /// Each segment starts where the previous one's estimated audio ends,
+    /// so a segment that overruns its target pushes the rest back.
+    pub fn compose(&self) -> ComposedSession {
+        let session_pacer = MeditationPacer::with_config(self.config.clone());
+        
+        let mut segments = Vec::with_capacity(self.segments.len());
+        let mut timeline = Vec::new();
+        let mut offset = 0.0;
+        
+        for segment in &self.segments {
+            let result = match &segment.config {
+                Some(config) => MeditationPacer::with_config(config.clone())
+                    .calculate_pacing(segment.text.clone(), segment.target_duration_seconds),
+                None => session_pacer
+                    .calculate_pacing(segment.text.clone(), segment.target_duration_seconds),
+            };
+            
+            let first_atom = timeline.len();
+            timeline.extend(result.timeline.iter().map(|entry| TimelineEntry {
+                atom_index: first_atom + entry.atom_index,
+                start_seconds: offset + entry.start_seconds,
+                ..entry.clone()
+            }));
+            
+            let composed = ComposedSegment {
+                name: segment.name.clone(),
+                start_seconds: offset,
+                voice_id: segment.voice_id.clone(),
+                result,
+            };
+            offset = composed.end_seconds();
+            segments.push(composed);
+        }
+        
+        ComposedSession { segments, timeline }
+    }
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Escape a value for an SSML attribute (either quote style)
+pub(crate) fn escape_attribute(value: &str) -> String {
+    value.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compose_places_segments_back_to_back() {
+        let slow = PacingConfig {
+            chars_per_second: 6.0,
+            ..PacingConfig::default()
+        };
+        let session = Session::new()
+            .segment(SessionSegment::new("intro", "Welcome. Settle in.", 30.0))
+            .segment(SessionSegment::new("body", "Breathe in. Breathe out.", 60.0).with_config(slow.clone()));
+        let composed = session.compose();
+        
+        let intro = &composed.segments[0];
+        let body = &composed.segments[1];
+        assert_eq!(intro.start_seconds, 0.0);
+        assert_eq!(body.start_seconds, intro.end_seconds());
+        assert!((composed.total_duration_seconds() - body.end_seconds()).abs() < 1e-9);
+        assert_eq!(composed.target_duration_seconds(), 90.0);
+        
+        // Segments are paced exactly as they would be on their own
+        let standalone = MeditationPacer::with_config(slow)
+            .calculate_pacing("Breathe in. Breathe out.".to_string(), 60.0);
+        assert_eq!(body.result.ssml, standalone.ssml);
+        
+        // The aggregate timeline continues across the boundary
+        assert_eq!(composed.timeline.len(), 4);
+        assert_eq!(composed.timeline[2].atom_index, 2);
+        assert_eq!(composed.timeline[2].text, "Breathe in");
+        assert!((composed.timeline[2].start_seconds - body.start_seconds).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_voiced_segments() {
+        let session = Session::new()
+            .segment(SessionSegment::new("call", "I am calm.", 5.0).with_voice("guide"))
+            .segment(SessionSegment::new("response", "I am calm.", 5.0).with_voice("echo \"2\""))
+            .segment(SessionSegment::new("closing", "Rest.", 5.0));
+        let composed = session.compose();
+        
+        assert_eq!(composed.segments[0].voice_id.as_deref(), Some("guide"));
+        assert_eq!(
+            composed.to_ssml(),
+            "<voice name=\"guide\">I am calm.</voice> \
+             <voice name=\"echo &quot;2&quot;\">I am calm.</voice> Rest.",
+        );
+        assert_eq!(escape_attribute("<it's & \"this\">"), "&lt;it&apos;s &amp; &quot;this&quot;&gt;");
+    }
+
+    #[test]
+    fn test_segments_fall_back_to_session_config() {
+        let slow = PacingConfig {
+            chars_per_second: 6.0,
+            ..PacingConfig::default()
+        };
+        let session = Session::with_config(slow.clone())
+            .segment(SessionSegment::new("body", "Breathe in. Breathe out.", 60.0))
+            .segment(SessionSegment::new("closing", "Rest.", 10.0).with_config(PacingConfig::default()));
+        let composed = session.compose();
+        
+        let slow_result = MeditationPacer::with_config(slow).calculate_pacing("Breathe in. Breathe out.".to_string(), 60.0);
+        let default_result = MeditationPacer::new().calculate_pacing("Rest.".to_string(), 10.0);
+        assert_eq!(composed.segments[0].result.estimated_speech_seconds, slow_result.estimated_speech_seconds);
+        assert_eq!(composed.segments[1].result.estimated_speech_seconds, default_result.estimated_speech_seconds);
+        
+        let empty = Session::new().compose();
+        assert_eq!(empty.total_duration_seconds(), 0.0);
+        assert_eq!(empty.target_duration_seconds(), 0.0);
+        assert_eq!(empty.to_ssml(), "");
+    }
+}
@@ -0,0 +1,258 @@
+//! PCM Splicing
+//! 
+//! Converts the pause plan of a `PacingResult` into exact sample counts,
+//! for clients that synthesize each atom as its own clip and splice raw
+//! PCM silence between them.
+//! 
+//! Rounding every pause to whole samples on its own lets the error pile
+//! up over a long session. Instead, pause boundaries are rounded on the
+//! running total of silence, so the samples of all pauses add up to the
+//! total silence rounded once, and no single pause is off by more than
+//! one sample. An intro pause is the first entry of both plans, spliced in
+//! before the first clip.
+//! 
+//! ## Stitching Measured Clips
+//! 
+//! Once the clips are synthesized their real lengths are known, and
+//! `stitch_plan` recomputes the silence between them so the concatenated
+//! file lands on the target exactly: the budget is `target - clips`, shared
+//! between the gaps in proportion to the planned pauses (equally if none
+//! were planned). The final gap absorbs float rounding.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::MeditationPacer;
+//! 
+//! let result = MeditationPacer::new().calculate_pacing("Breathe in. Hold it. Let go.".to_string(), 30.0);
+//! let plan = result.silence_plan(48_000);
+//! assert_eq!(plan.len(), 2);
+//! let samples: u64 = plan.iter().map(|pause| pause.samples).sum();
+//! assert_eq!(samples, (result.total_silence_added * 48_000.0).round() as u64);
+//! 
+//! let clips = [1.2, 0.9, 1.1];
+//! let gaps = result.stitch_plan(&clips, 30.0).unwrap();
+//! assert_eq!(gaps.len(), 3);
+//! assert!((clips.iter().sum::<f64>() + gaps.iter().sum::<f64>() - 30.0).abs() < 1e-9);
+//! ```
+
+use core::fmt;
+
+use crate::pacing_engine::PacingResult;
+use crate::prelude::*;
+
+// ============================================
+// Types
+// ============================================
+
+/// Silence to splice in after one atom's clip
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SilenceSamples {
+    /// Atom the silence follows (`None` for the intro pause, before the
+    /// first atom)
+    pub atom_index: Option<usize>,
+    /// Length of the silence in samples (per channel)
+    pub samples: u64,
+}
+
+/// Why measured clips cannot be stitched to the target
+#[derive(Debug, Clone, PartialEq)]
+pub enum StitchError {
+    /// There is not exactly one clip per atom
+    ClipCountMismatch {
+        /// Atoms in the result
+        expected: usize,
+        /// Clips given
+        found: usize,
+    },
+    /// A clip duration is negative or not finite
+    InvalidClip {
+        /// Atom the clip speaks
+        atom_index: usize,
+        /// The duration
+        seconds: f64,
+    },
+    /// The clips alone are longer than the target
+    ClipsTooLong {
+        /// Total clip duration
+        clip_seconds: f64,
+        /// Requested session length
+        target_seconds: f64,
+    },
+}
+
+impl fmt::Display for StitchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StitchError::ClipCountMismatch { expected, found } => {
+                write!(f, "expected {} clips (one per atom), got {}", expected, found)
+            }
+            StitchError::InvalidClip { atom_index, seconds } => {
+                write!(f, "clip for atom {} has invalid duration {}s", atom_index, seconds)
+            }
+            StitchError::ClipsTooLong { clip_seconds, target_seconds } => write!(
+                f,
+                "clips total {:.1}s, longer than the {:.1}s target",
+                clip_seconds, target_seconds
+            ),
+        }
+    }
+}
+
+impl core::error::Error for StitchError {}
+
+// ============================================
+// Silence Plan
+// ============================================
+
+impl PacingResult {
+    /// Sample count of every rendered pause at `sample_rate`
+    /// 
+    /// Atoms without a pause are left out; an intro pause comes first.
+    pub fn silence_plan(&self, sample_rate: u32) -> Vec<SilenceSamples> {
+        let rate = f64::from(sample_rate);
+        let mut elapsed_seconds = 0.0;
+        let mut elapsed_samples = 0;
+
+        let intro = (self.intro_pause_seconds > 0.0).then_some((None, self.intro_pause_seconds));
+        intro.into_iter()
+            .chain(self.timeline.iter().map(|entry| (Some(entry.atom_index), entry.pause_seconds)))
+            .filter(|(_, pause_seconds)| *pause_seconds > 0.0)
+            .map(|(atom_index, pause_seconds)| {
+                elapsed_seconds += pause_seconds;
+                let boundary = (elapsed_seconds * rate).round() as u64;
+                let samples = boundary - elapsed_samples;
+                elapsed_samples = boundary;
+                SilenceSamples { atom_index, samples }
+            })
+            .collect()
+    }
+
+    /// Silence to insert after each measured clip so the stitched audio is
+    /// exactly `target_duration_seconds` long
+    /// 
+    /// `clip_seconds` holds the measured duration of every atom's clip, in
+    /// atom order. The returned gaps line up with the clips; the last one
+    /// is zero unless the script has a single atom, whose clip is then
+    /// padded at the end. If the result has an intro pause, it is kept as
+    /// is and comes first, before the first clip, so there is one more gap
+    /// than clips.
+    pub fn stitch_plan(&self, clip_seconds: &[f64], target_duration_seconds: f64) -> Result<Vec<f64>, StitchError> {
+        if clip_seconds.len() != self.timeline.len() {
+            return Err(StitchError::ClipCountMismatch {
+                expected: self.timeline.len(),
+                found: clip_seconds.len(),
+            });
+        }
+        if let Some((atom_index, seconds)) = clip_seconds.iter()
+            .enumerate()
+            .find(|(_, s)| !(s.is_finite() && **s >= 0.0))
+        {
+            return Err(StitchError::InvalidClip { atom_index, seconds: *seconds });
+        }
+        let clip_total: f64 = clip_seconds.iter().sum();
+        let intro = self.intro_pause_seconds.max(0.0);
+        let budget = target_duration_seconds - clip_total - intro;
+        if budget < 0.0 {
+            return Err(StitchError::ClipsTooLong {
+                clip_seconds: clip_total,
+                target_seconds: target_duration_seconds,
+            });
+        }
+
+        let mut gaps = vec![0.0; clip_seconds.len()];
+        // Silence goes between clips, or after the only one
+        let gap_count = if gaps.len() == 1 { 1 } else { gaps.len().saturating_sub(1) };
+        let planned: Vec<f64> = self.timeline.iter().take(gap_count).map(|e| e.pause_seconds.max(0.0)).collect();
+        let planned_total: f64 = planned.iter().sum();
+        for (gap, pause) in gaps.iter_mut().zip(&planned) {
+            *gap = if planned_total > 0.0 {
+                budget * pause / planned_total
+            } else {
+                budget / gap_count as f64
+            };
+        }
+        if let Some(last) = gap_count.checked_sub(1) {
+            gaps[last] = budget - gaps[..last].iter().sum::<f64>();
+        }
+        if intro > 0.0 {
+            gaps.insert(0, intro);
+        }
+        Ok(gaps)
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MeditationPacer, PacingConfig};
+
+    #[test]
+    fn test_rounding_does_not_accumulate() {
+        let mut result = MeditationPacer::new()
+            .calculate_pacing("Breathe in. Hold it. Let go. Rest.".to_string(), 40.0);
+        for entry in result.timeline.iter_mut().take(3) {
+            entry.pause_seconds = 1.00001;
+        }
+
+        // Rounded one by one, each pause would be 22_050 samples
+        let plan = result.silence_plan(22_050);
+        let samples: Vec<u64> = plan.iter().map(|pause| pause.samples).collect();
+        assert_eq!(samples, vec![22_050, 22_050, 22_051]);
+        assert_eq!(samples.iter().sum::<u64>(), (3.00003f64 * 22_050.0).round() as u64);
+        assert_eq!(plan.iter().map(|pause| pause.atom_index).collect::<Vec<_>>(), vec![Some(0), Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn test_intro_pause_is_spliced_first() {
+        let config = PacingConfig { intro_pause_seconds: 5.0, ..PacingConfig::default() };
+        let result = MeditationPacer::with_config(config)
+            .calculate_pacing("Breathe in. Hold it. Let go.".to_string(), 40.0);
+
+        let plan = result.silence_plan(48_000);
+        assert_eq!(plan[0], SilenceSamples { atom_index: None, samples: 240_000 });
+        let samples: u64 = plan.iter().map(|pause| pause.samples).sum();
+        assert_eq!(samples, (result.total_silence_added * 48_000.0).round() as u64);
+
+        let clips = [1.2, 0.9, 1.1];
+        let gaps = result.stitch_plan(&clips, 40.0).unwrap();
+        assert_eq!(gaps.len(), 4);
+        assert_eq!(gaps[0], 5.0);
+        assert!((clips.iter().chain(&gaps).sum::<f64>() - 40.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stitch_plan_hits_target() {
+        let pacer = MeditationPacer::new();
+        let result = pacer.calculate_pacing("Breathe in. Hold it... Let go.".to_string(), 30.0);
+        let clips = [1.4, 1.0, 0.8];
+        let gaps = result.stitch_plan(&clips, 30.0).unwrap();
+
+        assert_eq!(gaps[2], 0.0);
+        assert_eq!(clips.iter().chain(&gaps).sum::<f64>(), 30.0);
+        let planned = result.timeline[1].pause_seconds / result.timeline[0].pause_seconds;
+        assert!((gaps[1] / gaps[0] - planned).abs() < 1e-9);
+
+        let single = pacer.calculate_pacing("Rest.".to_string(), 10.0);
+        assert_eq!(single.stitch_plan(&[2.5], 10.0), Ok(vec![7.5]));
+    }
+
+    #[test]
+    fn test_stitch_errors() {
+        let result = MeditationPacer::new().calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+        assert_eq!(
+            result.stitch_plan(&[1.0], 20.0),
+            Err(StitchError::ClipCountMismatch { expected: 2, found: 1 })
+        );
+        assert_eq!(
+            result.stitch_plan(&[1.0, -0.5], 20.0),
+            Err(StitchError::InvalidClip { atom_index: 1, seconds: -0.5 })
+        );
+        assert!(matches!(result.stitch_plan(&[15.0, 6.0], 20.0), Err(StitchError::ClipsTooLong { .. })));
+    }
+}
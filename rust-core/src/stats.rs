@@ -0,0 +1,143 @@
+//! Script Statistics
+//! 
+//! Fast counts for content tooling: word, sentence, and paragraph counts,
+//! a histogram of pause classes, and the estimated speech time under the
+//! pacer's config. No silence is planned and no SSML is rendered, so this
+//! is cheap enough to run on every keystroke in an editor.
+//! 
+//! Sentences are atoms ending in a sentence end, ellipsis, or paragraph
+//! break (plus a trailing atom with no punctuation). Paragraphs are runs of
+//! text separated by blank lines.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::{analyze, PunctuationType};
+//! 
+//! let stats = analyze("Breathe in, slowly. Let go.\n\nRest here.");
+//! assert_eq!(stats.word_count, 7);
+//! assert_eq!(stats.sentence_count, 3);
+//! assert_eq!(stats.paragraph_count, 2);
+//! assert_eq!(stats.punctuation[&PunctuationType::Comma], 1);
+//! ```
+
+use crate::pacing_engine::{MeditationPacer, PunctuationType};
+use crate::prelude::*;
+
+// ============================================
+// Types
+// ============================================
+
+/// Counts and speech estimate for a script
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ScriptStats {
+    /// Whitespace-separated words (markers and markup excluded)
+    pub word_count: usize,
+    /// Spoken characters, excluding whitespace
+    pub char_count: usize,
+    /// Sentences (see the module docs)
+    pub sentence_count: usize,
+    /// Paragraphs separated by blank lines
+    pub paragraph_count: usize,
+    /// Number of speech atoms
+    pub atom_count: usize,
+    /// Atoms per pause class
+    pub punctuation: HashMap<PunctuationType, usize>,
+    /// Estimated speech time under the pacer's duration model
+    pub estimated_speech_seconds: f64,
+}
+
+// ============================================
+// Analysis
+// ============================================
+
+impl MeditationPacer {
+    /// Count a script without pacing it
+    pub fn analyze(&self, text: &str) -> ScriptStats {
+        let atoms = self.atomize_text(text);
+
+        let mut punctuation: HashMap<PunctuationType, usize> = HashMap::new();
+        for atom in &atoms {
+            *punctuation.entry(atom.punctuation).or_insert(0) += 1;
+        }
+        let sentence_count = atoms.iter()
+            .enumerate()
+            .filter(|(i, atom)| match atom.punctuation {
+                PunctuationType::SentenceEnd | PunctuationType::Ellipsis | PunctuationType::Paragraph => true,
+                PunctuationType::None => *i == atoms.len() - 1,
+                _ => false,
+            })
+            .count();
+
+        ScriptStats {
+            word_count: atoms.iter().map(|atom| atom.word_count).sum(),
+            char_count: atoms.iter().map(|atom| atom.char_count()).sum(),
+            sentence_count,
+            paragraph_count: count_paragraphs(text),
+            atom_count: atoms.len(),
+            punctuation,
+            estimated_speech_seconds: self.speech_seconds(&atoms).iter().sum(),
+        }
+    }
+}
+
+/// Count a script with the default config
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+pub fn analyze(text: &str) -> ScriptStats {
+    MeditationPacer::new().analyze(text)
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Runs of non-blank lines
+fn count_paragraphs(text: &str) -> usize {
+    let mut count = 0;
+    let mut in_paragraph = false;
+    for line in text.lines() {
+        let blank = line.trim().is_empty();
+        if !blank && !in_paragraph {
+            count += 1;
+        }
+        in_paragraph = !blank;
+    }
+    count
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_match_pacing() {
+        let pacer = MeditationPacer::new();
+        let text = "Welcome. Settle in, and rest...\n\n\n  \nBreathe in; breathe out. Stay";
+        let stats = pacer.analyze(text);
+        let result = pacer.calculate_pacing(text.to_string(), 60.0);
+
+        assert_eq!(stats.word_count, result.total_words);
+        assert_eq!(stats.atom_count, result.atom_count);
+        assert_eq!(stats.estimated_speech_seconds, result.estimated_speech_seconds);
+        assert_eq!(stats.sentence_count, 4);
+        assert_eq!(stats.paragraph_count, 2);
+        assert_eq!(stats.punctuation[&PunctuationType::Semicolon], 1);
+        assert_eq!(stats.punctuation.values().sum::<usize>(), stats.atom_count);
+    }
+
+    #[test]
+    fn test_empty_script() {
+        let stats = analyze("  \n ");
+        assert_eq!(stats.word_count, 0);
+        assert_eq!(stats.sentence_count, 0);
+        assert_eq!(stats.paragraph_count, 0);
+        assert!(stats.punctuation.is_empty());
+    }
+}
@@ -0,0 +1,309 @@
+//! Script Templates
+//! 
+//! Parameterized meditation scripts that expand offline, without an LLM
+//! call: breath focus, body scan, and gratitude. Each template has a fixed
+//! opening and closing, and a core of guidance lines that cycle through
+//! its subjects (breath anchors, body regions, or things to be grateful
+//! for) until the word target for the session is reached.
+//! 
+//! The word target is the same as `calculate_target_words_for_prompt`
+//! (70 words per minute by default), so the expanded script paces to the
+//! target duration with the usual 50/50 speech-to-silence ratio. The core
+//! stops at the last whole sentence that fits, so scripts come out at or
+//! slightly under the target.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::templates::ScriptTemplate;
+//! use zenpal_core::MeditationPacer;
+//! 
+//! let template = ScriptTemplate::body_scan(300.0).subjects(&["your feet", "your hands"]);
+//! let script = template.expand();
+//! assert!(script.contains("your feet"));
+//! 
+//! let result = MeditationPacer::new().calculate_pacing(script, 300.0);
+//! assert!(result.total_words <= template.target_words());
+//! ```
+
+use crate::pacing_engine::{calculate_target_words_custom, TARGET_WORDS_PER_MINUTE};
+use crate::prelude::*;
+
+// ============================================
+// Template Text
+// ============================================
+
+/// Placeholder replaced by the current subject in core lines
+const SUBJECT: &str = "{subject}";
+
+const BREATH_OPENING: [&str; 3] = [
+    "Find a comfortable position and let your eyes close.",
+    "Let your body be still.",
+    "There is nothing you need to do right now.",
+];
+
+const BREATH_CORE: [&str; 4] = [
+    "Bring your attention to {subject}.",
+    "Breathe in slowly.",
+    "And breathe out.",
+    "If your mind wanders, gently return to {subject}.",
+];
+
+const BREATH_SUBJECTS: [&str; 3] = [
+    "the breath at your nostrils",
+    "the rise and fall of your chest",
+    "the movement of your belly",
+];
+
+const BREATH_CLOSING: [&str; 2] = [
+    "Take one deeper breath.",
+    "When you are ready, open your eyes.",
+];
+
+const BODY_SCAN_OPENING: [&str; 3] = [
+    "Lie down or sit comfortably.",
+    "Let your breathing find its own rhythm.",
+    "We will move slowly through the body.",
+];
+
+const BODY_SCAN_CORE: [&str; 3] = [
+    "Bring your attention to {subject}.",
+    "Notice any sensation there, without changing it.",
+    "Let {subject} soften and release.",
+];
+
+const BODY_SCAN_SUBJECTS: [&str; 6] = [
+    "your feet",
+    "your legs",
+    "your belly",
+    "your chest",
+    "your shoulders",
+    "your face",
+];
+
+const BODY_SCAN_CLOSING: [&str; 2] = [
+    "Now feel your whole body at once, resting and at ease.",
+    "Gently return to the room.",
+];
+
+const GRATITUDE_OPENING: [&str; 3] = [
+    "Settle in and take a slow breath.",
+    "Let your shoulders drop.",
+    "Allow a sense of warmth to gather in your chest.",
+];
+
+const GRATITUDE_CORE: [&str; 3] = [
+    "Bring to mind {subject}.",
+    "Notice how it feels to be thankful for {subject}.",
+    "Breathe that feeling in.",
+];
+
+const GRATITUDE_SUBJECTS: [&str; 4] = [
+    "someone who cares for you",
+    "a place where you feel safe",
+    "a small comfort from today",
+    "your own body and breath",
+];
+
+const GRATITUDE_CLOSING: [&str; 2] = [
+    "Carry this gratitude with you.",
+    "When you are ready, open your eyes.",
+];
+
+// ============================================
+// Types
+// ============================================
+
+/// Built-in meditation template
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateKind {
+    /// Attention on the breath, cycling through breath anchors
+    BreathFocus,
+    /// Attention moving through body regions
+    BodyScan,
+    /// Reflection on things to be grateful for
+    Gratitude,
+}
+
+impl TemplateKind {
+    /// Opening lines, spoken once
+    fn opening(self) -> &'static [&'static str] {
+        match self {
+            TemplateKind::BreathFocus => &BREATH_OPENING,
+            TemplateKind::BodyScan => &BODY_SCAN_OPENING,
+            TemplateKind::Gratitude => &GRATITUDE_OPENING,
+        }
+    }
+
+    /// Core lines, repeated once per subject
+    fn core(self) -> &'static [&'static str] {
+        match self {
+            TemplateKind::BreathFocus => &BREATH_CORE,
+            TemplateKind::BodyScan => &BODY_SCAN_CORE,
+            TemplateKind::Gratitude => &GRATITUDE_CORE,
+        }
+    }
+
+    /// Subjects used when none are given
+    fn default_subjects(self) -> &'static [&'static str] {
+        match self {
+            TemplateKind::BreathFocus => &BREATH_SUBJECTS,
+            TemplateKind::BodyScan => &BODY_SCAN_SUBJECTS,
+            TemplateKind::Gratitude => &GRATITUDE_SUBJECTS,
+        }
+    }
+
+    /// Closing lines, spoken once
+    fn closing(self) -> &'static [&'static str] {
+        match self {
+            TemplateKind::BreathFocus => &BREATH_CLOSING,
+            TemplateKind::BodyScan => &BODY_SCAN_CLOSING,
+            TemplateKind::Gratitude => &GRATITUDE_CLOSING,
+        }
+    }
+}
+
+/// A template plus the parameters it expands with
+#[derive(Debug, Clone)]
+pub struct ScriptTemplate {
+    /// Which template to expand
+    pub kind: TemplateKind,
+    /// Target session length in seconds
+    pub target_duration_seconds: f64,
+    /// Word density (words per minute of session, silence included)
+    pub words_per_minute: f64,
+    /// Subjects the core cycles through (the template's defaults when empty)
+    pub subjects: Vec<String>,
+}
+
+impl ScriptTemplate {
+    /// A template with the default density and subjects
+    pub fn new(kind: TemplateKind, target_duration_seconds: f64) -> Self {
+        Self {
+            kind,
+            target_duration_seconds,
+            words_per_minute: TARGET_WORDS_PER_MINUTE,
+            subjects: Vec::new(),
+        }
+    }
+
+    /// A breath focus session
+    pub fn breath_focus(target_duration_seconds: f64) -> Self {
+        Self::new(TemplateKind::BreathFocus, target_duration_seconds)
+    }
+
+    /// A body scan session
+    pub fn body_scan(target_duration_seconds: f64) -> Self {
+        Self::new(TemplateKind::BodyScan, target_duration_seconds)
+    }
+
+    /// A gratitude session
+    pub fn gratitude(target_duration_seconds: f64) -> Self {
+        Self::new(TemplateKind::Gratitude, target_duration_seconds)
+    }
+
+    /// Override the 70 words-per-minute density
+    pub fn words_per_minute(mut self, words_per_minute: f64) -> Self {
+        self.words_per_minute = words_per_minute;
+        self
+    }
+
+    /// Replace the subjects the core cycles through
+    pub fn subjects(mut self, subjects: &[&str]) -> Self {
+        self.subjects = subjects.iter()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        self
+    }
+
+    /// Words the expanded script aims for
+    pub fn target_words(&self) -> usize {
+        calculate_target_words_custom(self.target_duration_seconds, self.words_per_minute)
+    }
+
+    /// Expand into a script, one paragraph per subject
+    /// 
+    /// The opening and closing are always included, even when they alone
+    /// exceed the word target.
+    pub fn expand(&self) -> String {
+        let opening = self.kind.opening().join(" ");
+        let closing = self.kind.closing().join(" ");
+        let mut budget = self.target_words()
+            .saturating_sub(word_count(&opening) + word_count(&closing));
+
+        let subjects: Vec<&str> = if self.subjects.is_empty() {
+            self.kind.default_subjects().to_vec()
+        } else {
+            self.subjects.iter().map(String::as_str).collect()
+        };
+
+        let mut paragraphs = vec![opening];
+        'rounds: loop {
+            for subject in &subjects {
+                let mut paragraph = Vec::new();
+                for line in self.kind.core() {
+                    let sentence = line.replace(SUBJECT, subject);
+                    let words = word_count(&sentence);
+                    if words > budget {
+                        if !paragraph.is_empty() {
+                            paragraphs.push(paragraph.join(" "));
+                        }
+                        break 'rounds;
+                    }
+                    budget -= words;
+                    paragraph.push(sentence);
+                }
+                paragraphs.push(paragraph.join(" "));
+            }
+        }
+        paragraphs.push(closing);
+
+        paragraphs.join("\n\n")
+    }
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Whitespace-separated word count
+fn word_count(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_matches_word_target() {
+        for template in [
+            ScriptTemplate::breath_focus(300.0),
+            ScriptTemplate::body_scan(600.0),
+            ScriptTemplate::gratitude(180.0),
+        ] {
+            let target = template.target_words();
+            let words = word_count(&template.expand());
+            assert!(words <= target, "{} > {}", words, target);
+            assert!(words + 12 >= target, "{} too far under {}", words, target);
+        }
+    }
+
+    #[test]
+    fn test_subjects_and_short_sessions() {
+        let script = ScriptTemplate::gratitude(240.0).subjects(&["my sister", " "]).expand();
+        assert!(script.contains("Bring to mind my sister."));
+        assert!(!script.contains("someone who cares for you"));
+
+        // Too short for any core lines: opening and closing only
+        let short = ScriptTemplate::breath_focus(10.0).expand();
+        assert_eq!(short.split("\n\n").count(), 2);
+        assert!(short.starts_with("Find a comfortable position"));
+        assert!(short.ends_with("open your eyes."));
+    }
+}
@@ -0,0 +1,141 @@
+//! Plain-Text Transcripts
+//! 
+//! Renders a `PacingResult` as readable text with the planned silences
+//! written inline, for review documents, the app's text-only meditation
+//! mode, and accessibility transcripts:
+//! 
+//! ```text
+//! Breathe in. [pause 4.5s] Let go.
+//! ```
+//! 
+//! Every rendered pause gets one marker, however many break tags it was
+//! split into. Paragraph breaks in the script are kept as blank lines.
+//! 
+//! Clients that insert silence into their own audio buffer can take the
+//! same text without markers plus the byte offset of each pause instead
+//! (`to_clean_text`), rather than parsing the SSML back apart.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::MeditationPacer;
+//! 
+//! let result = MeditationPacer::new().calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+//! let text = result.to_plain_text();
+//! assert!(text.starts_with("Breathe in. [pause "));
+//! assert!(text.ends_with("s] Let go."));
+//! 
+//! let (clean, pauses) = result.to_clean_text();
+//! assert_eq!(clean, "Breathe in. Let go.");
+//! assert_eq!(pauses.len(), 1);
+//! assert_eq!(&clean[..pauses[0].0], "Breathe in.");
+//! ```
+
+use crate::pacing_engine::{PacingResult, TimelineEntry};
+use crate::prelude::*;
+
+// ============================================
+// Export
+// ============================================
+
+impl PacingResult {
+    /// Render the script as plain text with `[pause N.Ns]` markers
+    pub fn to_plain_text(&self) -> String {
+        let (text, pauses) = self.to_clean_text();
+        let mut out = String::with_capacity(text.len() + pauses.len() * 16);
+        let mut copied = 0;
+
+        for (offset, seconds) in pauses {
+            out.push_str(&text[copied..offset]);
+            if offset == 0 {
+                out.push_str(&format!("[pause {:.1}s] ", seconds));
+            } else {
+                out.push_str(&format!(" [pause {:.1}s]", seconds));
+            }
+            copied = offset;
+        }
+        out.push_str(&text[copied..]);
+
+        out
+    }
+
+    /// Render the script as untagged text plus the pauses to insert into it
+    /// 
+    /// Each pause is `(byte_offset, pause_seconds)`: the silence starts
+    /// right after the atom's punctuation, at that byte offset into the
+    /// text. An intro pause is at offset 0. Offsets are in ascending order.
+    pub fn to_clean_text(&self) -> (String, Vec<(usize, f64)>) {
+        let mut text = String::new();
+        let mut pauses = Vec::new();
+        if self.intro_pause_seconds > 0.0 {
+            pauses.push((0, self.intro_pause_seconds));
+        }
+
+        for (i, entry) in self.timeline.iter().enumerate() {
+            text.push_str(entry.text.trim());
+            text.push_str(entry.punctuation.trim());
+            if entry.pause_seconds > 0.0 {
+                pauses.push((text.len(), entry.pause_seconds));
+            }
+            if i + 1 < self.timeline.len() {
+                text.push_str(separator(entry));
+            }
+        }
+
+        (text, pauses)
+    }
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Text between an atom and the next: a blank line after a paragraph
+/// break, a space otherwise
+fn separator(entry: &TimelineEntry) -> &'static str {
+    if entry.punctuation.contains('\n') {
+        "\n\n"
+    } else {
+        " "
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::MeditationPacer;
+
+    #[test]
+    fn test_plain_text_markers() {
+        let pacer = MeditationPacer::new();
+        let result = pacer.calculate_pacing("Welcome.\n\nBreathe in, slowly... and let go.".to_string(), 60.0);
+        let text = result.to_plain_text();
+
+        let markers = text.matches("[pause ").count();
+        assert_eq!(markers, result.timeline.iter().filter(|e| e.pause_seconds > 0.0).count());
+        assert!(text.starts_with("Welcome. [pause "));
+        assert!(text.contains("s]\n\nBreathe in,"));
+        assert!(text.ends_with("and let go."));
+        assert!(!text.contains('<'));
+    }
+
+    #[test]
+    fn test_clean_text_offsets() {
+        let pacer = MeditationPacer::new();
+        let result = pacer.calculate_pacing("Spüre den Atem... lass los.\n\nRuhe.".to_string(), 60.0);
+        let (text, pauses) = result.to_clean_text();
+
+        assert_eq!(text, "Spüre den Atem... lass los.\n\nRuhe.");
+        let offsets: Vec<usize> = pauses.iter().map(|(offset, _)| *offset).collect();
+        assert_eq!(offsets, vec!["Spüre den Atem...".len(), "Spüre den Atem... lass los.".len()]);
+        let rendered: Vec<f64> = result.timeline.iter()
+            .map(|e| e.pause_seconds)
+            .filter(|p| *p > 0.0)
+            .collect();
+        assert_eq!(pauses.iter().map(|(_, s)| *s).collect::<Vec<_>>(), rendered);
+    }
+}
@@ -0,0 +1,255 @@
+//! UniFFI Bindings
+//! 
+//! Proc-macro UniFFI definitions for the core, so Swift and Kotlin
+//! bindings are generated straight from this crate with the same surface.
+//! Available with the `uniffi` feature.
+//! 
+//! ## Generating Bindings
+//! 
+//! Build the shared library through the `bindings` crate, then run the
+//! bundled generator (the `uniffi-cli` feature) against it; `uniffi.toml`
+//! sets the Swift module name and the Kotlin package:
+//! 
+//! ```text
+//! cargo build --release -p zenpal-core-bindings --features uniffi
+//! cargo run --features uniffi-cli --bin uniffi-bindgen -- generate \
+//!     --library target/release/libzenpal_core.so --language kotlin --out-dir generated/kotlin
+//! ```
+//! 
+//! For Android, build `libzenpal_core.so` per ABI with the NDK (e.g. via
+//! `cargo ndk -t arm64-v8a build --release -p zenpal-core-bindings --features uniffi`)
+//! and ship it in `jniLibs`; the Kotlin code loads it through JNA.
+//! 
+//! ## What Is Exported
+//! 
+//! - `MeditationPacer` as an object with its constructors and pacing methods
+//! - `PacingConfig`, `PacingResult`, and the types they contain as records
+//!   and enums (derived next to each type)
+//! - The convenience functions (`format_meditation_ssml`,
+//!   `calculate_pacing_details`, ...)
+//! - `pacing_summary`, the one-line log summary that `Display` gives Rust
+//! - `pacing_plain_text` and `pacing_clean_text`, the text-only renderings
+//!   of a result (`CleanText` carries the `(byte_offset, pause_seconds)`
+//!   pairs as `TextPause` records)
+//! 
+//! Two types need custom conversions:
+//! 
+//! - `usize` crosses as `u64`
+//! - `PauseCurve` crosses as a string: `linear`, `ease_in`, `ease_out`,
+//!   `sigmoid`, or `power:<exponent>`. `Custom` curves hold a closure and
+//!   cannot cross; they are sent as `linear`.
+
+use std::sync::Arc;
+
+use crate::pacing_engine::{MeditationPacer, PacingConfig, PacingResult, PauseCurve, Preset};
+use crate::UniffiCustomTypeConverter;
+
+// ============================================
+// Records
+// ============================================
+
+/// Untagged script text and the pauses to insert into it
+/// (`PacingResult::to_clean_text`)
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct CleanText {
+    /// The script without markup
+    pub text: String,
+    /// Pauses in ascending offset order
+    pub pauses: Vec<TextPause>,
+}
+
+/// One pause of a `CleanText`
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct TextPause {
+    /// UTF-8 byte offset into the text where the silence starts
+    pub byte_offset: usize,
+    /// Length of the silence
+    pub pause_seconds: f64,
+}
+
+// ============================================
+// Custom Types
+// ============================================
+
+uniffi::custom_type!(usize, u64);
+
+impl UniffiCustomTypeConverter for usize {
+    type Builtin = u64;
+
+    fn into_custom(val: u64) -> uniffi::Result<Self> {
+        Ok(usize::try_from(val)?)
+    }
+
+    fn from_custom(obj: Self) -> u64 {
+        obj as u64
+    }
+}
+
+uniffi::custom_type!(PauseCurve, String);
+
+impl UniffiCustomTypeConverter for PauseCurve {
+    type Builtin = String;
+
+    fn into_custom(val: String) -> uniffi::Result<Self> {
+        match val.as_str() {
+            "linear" => Ok(PauseCurve::Linear),
+            "ease_in" => Ok(PauseCurve::EaseIn),
+            "ease_out" => Ok(PauseCurve::EaseOut),
+            "sigmoid" => Ok(PauseCurve::Sigmoid),
+            other => other.strip_prefix("power:")
+                .and_then(|exponent| exponent.parse().ok())
+                .map(PauseCurve::Power)
+                .ok_or_else(|| conversion_error(format!("unknown pause curve `{}`", other))),
+        }
+    }
+
+    fn from_custom(obj: Self) -> String {
+        match obj {
+            PauseCurve::Linear | PauseCurve::Custom(_) => "linear".to_string(),
+            PauseCurve::EaseIn => "ease_in".to_string(),
+            PauseCurve::EaseOut => "ease_out".to_string(),
+            PauseCurve::Sigmoid => "sigmoid".to_string(),
+            PauseCurve::Power(exponent) => format!("power:{}", exponent),
+        }
+    }
+}
+
+/// Error for a failed custom type conversion
+fn conversion_error(message: String) -> uniffi::deps::anyhow::Error {
+    uniffi::deps::anyhow::Error::msg(message)
+}
+
+// ============================================
+// Pacer Object
+// ============================================
+
+#[uniffi::export]
+impl MeditationPacer {
+    /// Create a new pacer with default configuration
+    #[uniffi::constructor(name = "new")]
+    pub fn ffi_new() -> Arc<Self> {
+        Arc::new(Self::new())
+    }
+
+    /// Create a new pacer with custom configuration
+    #[uniffi::constructor(name = "with_config")]
+    pub fn ffi_with_config(config: PacingConfig) -> Arc<Self> {
+        Arc::new(Self::with_config(config))
+    }
+
+    /// Create a new pacer from a built-in preset
+    #[uniffi::constructor(name = "with_preset")]
+    pub fn ffi_with_preset(preset: Preset) -> Arc<Self> {
+        Arc::new(Self::with_config(PacingConfig::preset(preset)))
+    }
+
+    /// The configuration this pacer uses
+    #[uniffi::method(name = "config")]
+    pub fn ffi_config(&self) -> PacingConfig {
+        self.config().clone()
+    }
+
+    /// Format meditation text into SSML with calculated breaks
+    #[uniffi::method(name = "format_meditation_ssml")]
+    pub fn ffi_format_meditation_ssml(&self, text: String, target_duration_seconds: f64) -> String {
+        self.format_meditation_ssml(text, target_duration_seconds)
+    }
+
+    /// Calculate pacing and return detailed results
+    #[uniffi::method(name = "calculate_pacing")]
+    pub fn ffi_calculate_pacing(&self, text: String, target_duration_seconds: f64) -> PacingResult {
+        self.calculate_pacing(text, target_duration_seconds)
+    }
+
+    /// Calculate pacing that lands within `tolerance_seconds` of the target
+    #[uniffi::method(name = "calculate_pacing_exact")]
+    pub fn ffi_calculate_pacing_exact(
+        &self,
+        text: String,
+        target_duration_seconds: f64,
+        tolerance_seconds: f64,
+    ) -> PacingResult {
+        self.calculate_pacing_exact(text, target_duration_seconds, tolerance_seconds)
+    }
+
+    /// Re-pace a script using its measured speech duration
+    #[uniffi::method(name = "repace_with_measured")]
+    pub fn ffi_repace_with_measured(
+        &self,
+        text: String,
+        measured_speech_seconds: f64,
+        target_duration_seconds: f64,
+    ) -> PacingResult {
+        self.repace_with_measured(text, measured_speech_seconds, target_duration_seconds)
+    }
+}
+
+// ============================================
+// Formatting
+// ============================================
+
+/// One-line summary of a pacing result for logs (`PacingResult::summary`)
+#[uniffi::export]
+pub fn pacing_summary(result: PacingResult) -> String {
+    result.summary()
+}
+
+/// Script text with inline `[pause N.Ns]` markers (`PacingResult::to_plain_text`)
+#[uniffi::export]
+pub fn pacing_plain_text(result: PacingResult) -> String {
+    result.to_plain_text()
+}
+
+/// Script text without markup plus the byte offset of each pause
+/// (`PacingResult::to_clean_text`)
+#[uniffi::export]
+pub fn pacing_clean_text(result: PacingResult) -> CleanText {
+    let (text, pauses) = result.to_clean_text();
+    CleanText {
+        text,
+        pauses: pauses.into_iter()
+            .map(|(byte_offset, pause_seconds)| TextPause { byte_offset, pause_seconds })
+            .collect(),
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_curve_conversion() {
+        for curve in [PauseCurve::Linear, PauseCurve::EaseIn, PauseCurve::Sigmoid, PauseCurve::Power(2.5)] {
+            let name = PauseCurve::from_custom(curve.clone());
+            let back = PauseCurve::into_custom(name.clone()).unwrap();
+            assert_eq!(PauseCurve::from_custom(back), name);
+        }
+        assert_eq!(PauseCurve::from_custom(PauseCurve::Power(2.5)), "power:2.5");
+        assert!(PauseCurve::into_custom("wobble".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_usize_conversion() {
+        assert_eq!(usize::into_custom(42).unwrap(), 42);
+        assert_eq!(usize::from_custom(42), 42);
+        #[cfg(target_pointer_width = "32")]
+        assert!(usize::into_custom(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_clean_text_records() {
+        let result = MeditationPacer::new().calculate_pacing("Breathe in. Let go.".to_string(), 20.0);
+        let (text, pauses) = result.to_clean_text();
+        let clean = pacing_clean_text(result);
+
+        assert_eq!(clean.text, text);
+        assert_eq!(clean.pauses.len(), pauses.len());
+        for (record, (byte_offset, pause_seconds)) in clean.pauses.iter().zip(pauses) {
+            assert_eq!(record, &TextPause { byte_offset, pause_seconds });
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Named Voice Calibration Profiles
+//! 
+//! Different TTS voices speak at different rates and tolerate different
+//! break lengths. A `VoiceRegistry` maps voice ids (e.g. ElevenLabs voice
+//! ids) to their calibration so apps can pick the right `PacingConfig` by
+//! name instead of building configs by hand.
+//! 
+//! ## Example
+//! 
+//! ```rust
+//! use zenpal_core::voices::{VoiceProfile, VoiceRegistry};
+//! 
+//! let mut registry = VoiceRegistry::new();
+//! registry.register(VoiceProfile::new("calm-female", 11.0, 1.15, 3.0));
+//! 
+//! let pacer = registry.pacer_for("calm-female").unwrap();
+//! let ssml = pacer.format_meditation_ssml("Welcome. Breathe.".to_string(), 30.0);
+//! assert!(ssml.contains("<break"));
+//! ```
+
+use crate::pacing_engine::{MeditationPacer, PacingConfig};
+use crate::prelude::*;
+
+// ============================================
+// Types
+// ============================================
+
+/// Calibration for a single TTS voice
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoiceProfile {
+    /// Provider voice id this calibration belongs to
+    pub voice_id: String,
+    /// Measured speech rate (chars per second, excluding whitespace)
+    pub chars_per_second: f64,
+    /// Safety buffer multiplier for silence with this voice
+    pub silence_safety_buffer: f64,
+    /// Maximum seconds per break tag this voice handles cleanly
+    pub max_break_seconds: f64,
+}
+
+impl VoiceProfile {
+    /// Create a voice profile
+    pub fn new(
+        voice_id: &str,
+        chars_per_second: f64,
+        silence_safety_buffer: f64,
+        max_break_seconds: f64,
+    ) -> Self {
+        Self {
+            voice_id: voice_id.to_string(),
+            chars_per_second,
+            silence_safety_buffer,
+            max_break_seconds,
+        }
+    }
+
+    /// Apply this calibration on top of `base`
+    /// 
+    /// Clears `language` so the voice's measured rate is used.
+    pub fn apply_to(&self, base: PacingConfig) -> PacingConfig {
+        PacingConfig {
+            chars_per_second: self.chars_per_second,
+            language: None,
+            silence_safety_buffer: self.silence_safety_buffer,
+            max_break_seconds: self.max_break_seconds,
+            ..base
+        }
+    }
+
+    /// Default configuration with this calibration applied
+    pub fn config(&self) -> PacingConfig {
+        self.apply_to(PacingConfig::default())
+    }
+}
+
+/// Registry of voice calibrations keyed by voice id
+#[derive(Debug, Clone, Default)]
+pub struct VoiceRegistry {
+    profiles: HashMap<String, VoiceProfile>,
+}
+
+impl VoiceRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace a profile (keyed by its voice id)
+    pub fn register(&mut self, profile: VoiceProfile) {
+        self.profiles.insert(profile.voice_id.clone(), profile);
+    }
+
+    /// Remove a profile, returning it if present
+    pub fn remove(&mut self, voice_id: &str) -> Option<VoiceProfile> {
+        self.profiles.remove(voice_id)
+    }
+
+    /// Look up a profile by voice id
+    pub fn get(&self, voice_id: &str) -> Option<&VoiceProfile> {
+        self.profiles.get(voice_id)
+    }
+
+    /// Registered voice ids, sorted
+    pub fn voice_ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.profiles.keys().map(|k| k.as_str()).collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Number of registered profiles
+    pub fn len(&self) -> usize {
+        self.profiles.len()
+    }
+
+    /// Whether the registry is empty
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty()
+    }
+
+    /// Default configuration calibrated for `voice_id`
+    pub fn config_for(&self, voice_id: &str) -> Option<PacingConfig> {
+        self.get(voice_id).map(|p| p.config())
+    }
+
+    /// A pacer calibrated for `voice_id`
+    pub fn pacer_for(&self, voice_id: &str) -> Option<MeditationPacer> {
+        self.config_for(voice_id).map(MeditationPacer::with_config)
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_lookup() {
+        let mut registry = VoiceRegistry::new();
+        registry.register(VoiceProfile::new("guide", 10.0, 1.2, 2.5));
+        registry.register(VoiceProfile::new("affirm", 14.0, 1.05, 3.0));
+        
+        assert_eq!(registry.voice_ids(), vec!["affirm", "guide"]);
+        assert!(registry.config_for("unknown").is_none());
+        
+        let config = registry.config_for("guide").unwrap();
+        assert_eq!(config.chars_per_second, 10.0);
+        assert_eq!(config.silence_safety_buffer, 1.2);
+        assert_eq!(config.max_break_seconds, 2.5);
+        
+        // Re-registering replaces the calibration
+        registry.register(VoiceProfile::new("guide", 9.0, 1.2, 2.5));
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.get("guide").unwrap().chars_per_second, 9.0);
+    }
+
+    #[test]
+    fn test_profile_drives_pacing() {
+        let mut registry = VoiceRegistry::new();
+        registry.register(VoiceProfile::new("slow", 6.0, 1.0, 2.0));
+        let result = registry.pacer_for("slow").unwrap()
+            .calculate_pacing("Welcome. Take a deep breath.".to_string(), 60.0);
+        
+        // 22 chars at 6 cps, no buffer, breaks capped at 2s
+        assert!((result.estimated_speech_seconds - 22.0 / 6.0).abs() < 1e-9);
+        assert!((result.final_silence_budget - result.raw_silence_budget).abs() < 1e-9);
+        assert!(!result.ssml.contains("3.0s"));
+    }
+}
@@ -0,0 +1,129 @@
+//! WebAssembly API
+//! 
+//! `#[wasm_bindgen]` wrappers for the browser build. Numbers and strings
+//! cross as-is; configs and results cross as plain JS objects with the
+//! same field names as the Rust types (through their `serde`
+//! representation), so no hand-written TypeScript marshaling is needed.
+//! Available with the `wasm` feature; build the module through the
+//! `bindings` crate (a `cdylib`) and run `wasm-bindgen` on it:
+//! 
+//! ```text
+//! cargo build --release -p zenpal-core-bindings --target wasm32-unknown-unknown --features wasm
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/zenpal_core.wasm
+//! ```
+//! 
+//! ## JavaScript Usage
+//! 
+//! ```text
+//! import init, { MeditationPacer, calculatePacing } from "zenpal_core";
+//! 
+//! await init();
+//! const result = calculatePacing("Breathe in. Let go.", 60);
+//! console.log(result.ssml, result.timeline);
+//! 
+//! const pacer = new MeditationPacer({ chars_per_second: 11.0 });
+//! const ssml = pacer.formatMeditationSsml(script, 300);
+//! ```
+
+use wasm_bindgen::prelude::*;
+
+use crate::pacing_engine::{self, MeditationPacer, PacingConfig, PacingResult};
+
+// ============================================
+// Pacer Class
+// ============================================
+
+/// A pacer held on the JS side (exported as `MeditationPacer`)
+#[wasm_bindgen(js_name = MeditationPacer)]
+pub struct WasmPacer {
+    inner: MeditationPacer,
+}
+
+#[wasm_bindgen(js_class = MeditationPacer)]
+impl WasmPacer {
+    /// Create a pacer from an optional config object (missing fields use
+    /// defaults)
+    #[wasm_bindgen(constructor)]
+    pub fn new(config: JsValue) -> Result<WasmPacer, JsError> {
+        let config = if config.is_undefined() || config.is_null() {
+            PacingConfig::default()
+        } else {
+            config_from_js(config)?
+        };
+        Ok(WasmPacer {
+            inner: MeditationPacer::with_config(config),
+        })
+    }
+
+    /// Format meditation text into SSML with calculated breaks
+    #[wasm_bindgen(js_name = formatMeditationSsml)]
+    pub fn format_meditation_ssml(&self, text: String, target_duration_seconds: f64) -> String {
+        self.inner.format_meditation_ssml(text, target_duration_seconds)
+    }
+
+    /// Calculate pacing; returns the result as a JS object
+    #[wasm_bindgen(js_name = calculatePacing)]
+    pub fn calculate_pacing(&self, text: String, target_duration_seconds: f64) -> Result<JsValue, JsError> {
+        result_to_js(&self.inner.calculate_pacing(text, target_duration_seconds))
+    }
+
+    /// Re-pace from measured speech duration; returns a JS object
+    #[wasm_bindgen(js_name = repaceWithMeasured)]
+    pub fn repace_with_measured(
+        &self,
+        text: String,
+        measured_speech_seconds: f64,
+        target_duration_seconds: f64,
+    ) -> Result<JsValue, JsError> {
+        let result = self.inner.repace_with_measured(text, measured_speech_seconds, target_duration_seconds);
+        result_to_js(&result)
+    }
+
+    /// The pacer's config as a JS object
+    pub fn config(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(self.inner.config()).map_err(|e| JsError::new(&e.to_string()))
+    }
+}
+
+// ============================================
+// Free Functions
+// ============================================
+
+/// Format meditation text into SSML with the default config
+#[wasm_bindgen(js_name = formatMeditationSsml)]
+pub fn format_meditation_ssml(text: String, target_duration_seconds: f64) -> String {
+    pacing_engine::format_meditation_ssml(text, target_duration_seconds)
+}
+
+/// Calculate pacing with the default config; returns a JS object
+#[wasm_bindgen(js_name = calculatePacing)]
+pub fn calculate_pacing(text: String, target_duration_seconds: f64) -> Result<JsValue, JsError> {
+    result_to_js(&pacing_engine::calculate_pacing_details(text, target_duration_seconds))
+}
+
+/// Target word count for an LLM prompt
+#[wasm_bindgen(js_name = targetWordsForPrompt)]
+pub fn target_words_for_prompt(target_duration_seconds: f64) -> u32 {
+    pacing_engine::calculate_target_words_for_prompt(target_duration_seconds) as u32
+}
+
+// ============================================
+// Helpers
+// ============================================
+
+/// Read a config object, rejecting invalid values
+fn config_from_js(value: JsValue) -> Result<PacingConfig, JsError> {
+    let config: PacingConfig = serde_wasm_bindgen::from_value(value)
+        .map_err(|e| JsError::new(&e.to_string()))?;
+    if let Err(errors) = config.validate() {
+        let reasons: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+        return Err(JsError::new(&reasons.join("; ")));
+    }
+    Ok(config)
+}
+
+/// Convert a result into a plain JS object
+fn result_to_js(result: &PacingResult) -> Result<JsValue, JsError> {
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    serde::Serialize::serialize(result, &serializer).map_err(|e| JsError::new(&e.to_string()))
+}